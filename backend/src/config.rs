@@ -0,0 +1,87 @@
+use std::{fmt, path::PathBuf};
+
+use rocket::{figment::value::magic::RelativePathBuf, serde::{Deserialize, Serialize}};
+
+// read from Rocket.toml and the ROCKET_ environment variables, like ROCKET_DATA_DIR
+// the paths in Rocket.toml are relative to the file, the other ones to the working directory
+// the limits and the secret_key are Rocket's own keys, there is no database, everything is in the data directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct AppConfig
+{
+    // the built frontend
+    pub static_dir: RelativePathBuf,
+    // the crosswords, the dictionaries and the stats
+    pub data_dir: RelativePathBuf,
+}
+
+impl Default for AppConfig
+{
+    fn default() -> Self
+    {
+        AppConfig
+        {
+            static_dir: RelativePathBuf::from("../frontend/dist"),
+            data_dir: RelativePathBuf::from("data"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError
+{
+    MissingDir { name: &'static str, path: PathBuf },
+}
+
+impl fmt::Display for ConfigError
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        match self
+        {
+            ConfigError::MissingDir { name, path } => write!(f, "the {} directory {} doesn't exist", name, path.display()),
+        }
+    }
+}
+
+impl AppConfig
+{
+    pub fn static_dir(&self) -> PathBuf
+    {
+        self.static_dir.relative()
+    }
+
+    pub fn data_dir(&self) -> PathBuf
+    {
+        self.data_dir.relative()
+    }
+
+    pub fn crosswords_dir(&self) -> PathBuf
+    {
+        self.data_dir().join("crosswords")
+    }
+
+    pub fn dictionaries_dir(&self) -> PathBuf
+    {
+        self.data_dir().join("dictionaries")
+    }
+
+    pub fn images_dir(&self) -> PathBuf
+    {
+        self.data_dir().join("images")
+    }
+
+    pub fn stats_path(&self) -> PathBuf
+    {
+        self.data_dir().join("stats/attempts.jsonl")
+    }
+
+    // the crosswords and the stats directories are made when something is written to them
+    pub fn validate(&self) -> Vec<ConfigError>
+    {
+        [("static", self.static_dir()), ("data", self.data_dir()), ("dictionaries", self.dictionaries_dir()), ("images", self.images_dir())].into_iter()
+            .filter(|(_, path)| !path.is_dir())
+            .map(|(name, path)| ConfigError::MissingDir { name, path })
+            .collect()
+    }
+}
@@ -0,0 +1,54 @@
+use std::{collections::HashMap, io, path::PathBuf, sync::Arc};
+
+use common::{alphabet::Locale, dictionary::{dictionary_file_name, parse_dictionary}, difficulty::{estimate_difficulty, WordFrequencies}, document::CrosswordDocument};
+use rocket::tokio::{fs, sync::Mutex};
+
+// the word lists of the locales, read the first time a crossword of the locale needs them
+pub struct Dictionaries
+{
+    dir: PathBuf,
+    frequencies: Mutex<HashMap<Locale, Arc<WordFrequencies>>>,
+}
+
+impl Dictionaries
+{
+    pub fn new(dir: impl Into<PathBuf>) -> Dictionaries
+    {
+        Dictionaries { dir: dir.into(), frequencies: Mutex::default() }
+    }
+
+    // none when there is no dictionary for the locale, like load_dictionary of the editor
+    pub async fn read(&self, locale: Locale) -> io::Result<Option<Vec<String>>>
+    {
+        match fs::read_to_string(self.dir.join(dictionary_file_name(locale))).await
+        {
+            Ok(text) => Ok(Some(parse_dictionary(&text))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    // a locale without a word list gets empty frequencies, a list that can't be read is tried again the next time
+    pub async fn frequencies(&self, locale: Locale) -> Arc<WordFrequencies>
+    {
+        let mut frequencies = self.frequencies.lock().await;
+        if let Some(f) = frequencies.get(&locale) { return f.clone(); }
+
+        let f = match self.read(locale).await
+        {
+            Ok(words) => Arc::new(WordFrequencies::new(locale, &words.unwrap_or_default())),
+            Err(e) =>
+            {
+                warn!("couldn't read the {} dictionary: {}", locale.code(), e);
+                return Arc::default();
+            }
+        };
+        frequencies.insert(locale, f.clone());
+        f
+    }
+
+    pub async fn difficulty_score(&self, document: &CrosswordDocument) -> u8
+    {
+        estimate_difficulty(document, &*self.frequencies(document.alphabet.locale).await).score
+    }
+}
@@ -0,0 +1,26 @@
+use std::str::FromStr;
+
+use common::{import::ImportDiagnostic, validation::ValidationError};
+use rocket::{serde::json::Json, Responder};
+
+#[derive(Debug, Responder)]
+pub enum ApiError
+{
+    #[response(status = 400)]
+    BadRequest(String),
+    #[response(status = 422)]
+    Invalid(Json<Vec<ValidationError>>),
+    #[response(status = 422)]
+    Unparsable(Json<Vec<ImportDiagnostic>>),
+    #[response(status = 403)]
+    Forbidden(String),
+    #[response(status = 404)]
+    NotFound(String),
+    #[response(status = 500)]
+    Internal(String),
+}
+
+pub fn parse_id<T: FromStr>(id: &str) -> Result<T, ApiError>
+{
+    id.parse().map_err(|_| ApiError::BadRequest(format!("\"{}\" is not a valid id", id)))
+}
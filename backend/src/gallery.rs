@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use common::{document::{CrosswordId, Difficulty}, gallery::{paginate, parse_tags, search_text, GalleryCursor, GalleryFilter, GalleryItem, GalleryPage, DEFAULT_PAGE_SIZE}, print::PrintLayout, version::Version};
+use rocket::{http::ContentType, serde::json::Json, tokio::sync::Mutex, Route, State};
+
+use crate::{dictionaries::Dictionaries, error::{parse_id, ApiError}, store::{crossword_not_found, CrosswordStore}};
+
+const THUMBNAIL_CELL_SIZE: i16 = 10;
+
+struct GalleryEntry
+{
+    item: GalleryItem,
+    text: String,
+}
+
+// the latest published version of every crossword, so the listing doesn't read the records
+#[derive(Default)]
+pub struct GalleryIndex(Mutex<HashMap<CrosswordId, GalleryEntry>>);
+
+impl GalleryIndex
+{
+    // the records are read once at the start and not kept in the memory
+    pub async fn build(store: &CrosswordStore, dictionaries: &Dictionaries) -> GalleryIndex
+    {
+        let index = GalleryIndex::default();
+        for id in store.ids().await
+        {
+            let Some(record) = store.read(&id).await else { continue; };
+            if let Some(version) = record.latest_published() { index.publish(id, version, dictionaries).await; }
+        }
+        index
+    }
+
+    pub async fn publish(&self, crossword_id: CrosswordId, version: &Version, dictionaries: &Dictionaries)
+    {
+        let mut item = GalleryItem::new(crossword_id, version);
+        // the versions published before the estimates get one here
+        if item.difficulty_score.is_none() { item.difficulty_score = Some(dictionaries.difficulty_score(&version.document).await); }
+        let text = search_text(&version.document);
+        self.0.lock().await.insert(crossword_id, GalleryEntry { item, text });
+    }
+}
+
+// the shape of the grid without the letters, so the thumbnails don't give the answers away
+pub fn render_thumbnail(layout: &PrintLayout) -> String
+{
+    let (width, height) = (layout.width.max(1) * THUMBNAIL_CELL_SIZE, layout.height.max(1) * THUMBNAIL_CELL_SIZE);
+    let mut svg = format!("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"-1 -1 {} {}\" width=\"{}\" height=\"{}\">", width + 2, height + 2, width + 2, height + 2);
+    for cell in layout.cells.iter()
+    {
+        svg += &format!("<rect x=\"{}\" y=\"{}\" width=\"{s}\" height=\"{s}\" fill=\"white\" stroke=\"black\" stroke-width=\"1\"/>", cell.position.x * THUMBNAIL_CELL_SIZE, cell.position.y * THUMBNAIL_CELL_SIZE, s = THUMBNAIL_CELL_SIZE);
+    }
+    svg += "</svg>";
+    svg
+}
+
+// only the published crosswords are in the gallery, each with its latest published version
+#[allow(clippy::too_many_arguments)]
+#[get("/gallery?<q>&<tags>&<difficulty>&<min_size>&<max_size>&<min_score>&<max_score>&<cursor>&<limit>")]
+async fn get_gallery(q: Option<&str>, tags: Option<&str>, difficulty: Option<&str>, min_size: Option<i16>, max_size: Option<i16>, min_score: Option<u8>, max_score: Option<u8>, cursor: Option<&str>, limit: Option<usize>, index: &State<GalleryIndex>) -> Result<Json<GalleryPage>, ApiError>
+{
+    let difficulty = match difficulty
+    {
+        Some(name) => Some(Difficulty::from_name(name).ok_or_else(|| ApiError::BadRequest(format!("unknown difficulty \"{}\"", name)))?),
+        None => None,
+    };
+    let cursor = match cursor
+    {
+        Some(cursor) => Some(cursor.parse::<GalleryCursor>().map_err(|_| ApiError::BadRequest(format!("\"{}\" is not a valid cursor", cursor)))?),
+        None => None,
+    };
+    let filter = GalleryFilter { query: q.unwrap_or_default().to_owned(), tags: tags.map(parse_tags).unwrap_or_default(), difficulty, min_size, max_size, min_score, max_score };
+
+    let items = index.0.lock().await.values().filter(|entry| filter.matches(&entry.item, &entry.text)).map(|entry| entry.item.clone()).collect();
+    Ok(Json(paginate(items, cursor, limit.unwrap_or(DEFAULT_PAGE_SIZE))))
+}
+
+// of the latest published version, the drafts stay private
+#[get("/crosswords/<id>/thumbnail.svg")]
+async fn get_thumbnail(id: &str, store: &State<CrosswordStore>) -> Result<(ContentType, String), ApiError>
+{
+    let id = parse_id::<CrosswordId>(id)?;
+    let record = store.get(&id).await.ok_or_else(|| crossword_not_found(&id))?;
+    let version = record.latest_published().ok_or_else(|| crossword_not_found(&id))?;
+    Ok((ContentType::SVG, render_thumbnail(&PrintLayout::new(&version.document))))
+}
+
+pub fn routes() -> Vec<Route>
+{
+    routes![get_gallery, get_thumbnail]
+}
@@ -0,0 +1,17 @@
+use common::import::{import_text, ImportFormat, ImportRequest, ImportedWord};
+use rocket::{serde::json::Json, Route};
+
+use crate::error::ApiError;
+
+// the words are only parsed, they go to a crossword with the usual save
+#[post("/import", data = "<request>")]
+fn import(request: Json<ImportRequest>) -> Result<Json<Vec<ImportedWord>>, ApiError>
+{
+    let format = request.format.unwrap_or_else(|| ImportFormat::detect(&request.text));
+    import_text(&request.text, format, &request.alphabet).map(Json).map_err(|diagnostics| ApiError::Unparsable(Json(diagnostics)))
+}
+
+pub fn routes() -> Vec<Route>
+{
+    routes![import]
+}
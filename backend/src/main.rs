@@ -1,64 +1,117 @@
-use std::{collections::HashMap, net::IpAddr, path::PathBuf};
+use std::path::PathBuf;
 
-use rocket::{fs::NamedFile, response::status::NotFound, tokio::sync::Mutex, State};
+use config::AppConfig;
+use rocket::{fairing::AdHoc, fs::NamedFile, http::Header, response::status::NotFound, State};
 
 #[macro_use]
 extern crate rocket;
 
-#[derive(Default)]
-struct UserData(Mutex<HashMap<IpAddr, u32>>);
-impl UserData
+mod config;
+mod dictionaries;
+mod error;
+mod gallery;
+mod import;
+mod pdf;
+mod puzzle;
+mod rooms;
+mod stats;
+mod store;
+
+async fn get_index(config: &AppConfig) -> Result<NamedFile, NotFound<String>>
 {
-    async fn register_new_user(&self, new_user_ip_addr: &IpAddr) -> bool
-    {
-        let mut l = self.0.lock().await;
-        if l.contains_key(new_user_ip_addr) { false }
-        else { l.insert(*new_user_ip_addr,  0); true }
-    }
+    NamedFile::open(config.static_dir().join("index.html")).await
+        .map_err(|e| NotFound(e.to_string()))
+}
 
-    async fn login(&self, user_ip_addr: &IpAddr)
+#[get("/<path..>")]
+async fn index(path: PathBuf, config: &State<AppConfig>) -> Result<NamedFile, NotFound<String>>
+{
+    let path = config.static_dir().join(path);
+    match NamedFile::open(path.as_path()).await
     {
-        let mut l = self.0.lock().await;
-        if !l.contains_key(user_ip_addr) { drop(l); self.register_new_user(user_ip_addr).await; }
-        else { *l.get_mut(user_ip_addr).expect("impossible") += 1; }
+        // the directories open too, but can't be sent
+        Ok(file) if path.is_file() => Ok(file),
+        _ => get_index(config).await,
     }
 }
 
-async fn get_index() -> Result<NamedFile, NotFound<String>>
+// the player is shown in iframes on other sites, the rest of the app keeps the frame policy of the shield
+// frame-ancestors takes the place of the X-Frame-Options header in the browsers
+#[derive(Responder)]
+struct Embeddable
 {
-    NamedFile::open("../frontend/dist/index.html").await
-        .map_err(|e| NotFound(e.to_string()))
+    page: NamedFile,
+    frame_ancestors: Header<'static>,
 }
 
-#[get("/<path..>")]
-async fn index(path: PathBuf, ip_addr: IpAddr, user_data: &State<UserData>) -> Result<NamedFile, NotFound<String>>
+#[get("/embed/<_id>")]
+async fn embed(_id: &str, config: &State<AppConfig>) -> Result<Embeddable, NotFound<String>>
 {
-    user_data.login(&ip_addr).await;
-    let path = PathBuf::from("../frontend/dist").join(path);
+    Ok(Embeddable { page: get_index(config).await?, frame_ancestors: Header::new("Content-Security-Policy", "frame-ancestors *") })
+}
+
+// only the dictionaries and the images are public, the crosswords and the stats in the data directory aren't
+#[get("/data/dictionaries/<path..>")]
+async fn dictionary(path: PathBuf, config: &State<AppConfig>) -> Result<NamedFile, NotFound<String>>
+{
+    // a missing dictionary is a 404, the editor tells the alphabets without one apart by it
+    let path = config.dictionaries_dir().join(path);
     match NamedFile::open(path.as_path()).await
     {
-        Ok(file) => Ok(file),
-        Err(_) => get_index().await,
+        Ok(file) if path.is_file() => Ok(file),
+        _ => Err(NotFound(format!("there is no dictionary at {}", path.display()))),
     }
 }
 
-#[get("/data/<path..>")]
-async fn data(path: PathBuf) -> Result<NamedFile, NotFound<String>>
+#[get("/data/images/<path..>")]
+async fn image(path: PathBuf, config: &State<AppConfig>) -> Result<NamedFile, NotFound<String>>
 {
-    let path = PathBuf::from("data/").join(path);
+    let path = config.images_dir().join(path);
     match NamedFile::open(path.as_path()).await
     {
-        Ok(file) => Ok(file),
-        Err(_) => get_index().await,
+        Ok(file) if path.is_file() => Ok(file),
+        _ => Err(NotFound(format!("there is no image at {}", path.display()))),
     }
 }
 
 #[launch]
 fn rocket() -> _
 {
-    let user_data = UserData::default();
-
     rocket::build()
-        .mount("/", routes![index, data])
-        .manage(user_data)
+        .mount("/", routes![index, embed, dictionary, image])
+        .mount("/api", store::routes())
+        .mount("/api", rooms::routes())
+        .mount("/api", stats::routes())
+        .mount("/api", pdf::routes())
+        .mount("/api", import::routes())
+        .mount("/api", puzzle::routes())
+        .mount("/api", gallery::routes())
+        .manage(rooms::Rooms::default())
+        // the states that need the paths are made once the config is read
+        .attach(AdHoc::try_on_ignite("App config", |rocket| async move
+        {
+            let config = match rocket.figment().extract::<AppConfig>()
+            {
+                Ok(config) => config,
+                Err(e) =>
+                {
+                    rocket::config::pretty_print_error(e);
+                    return Err(rocket);
+                }
+            };
+            // a server without its directories would only answer 404
+            let errors = config.validate();
+            for e in errors.iter() { error!("config: {}", e); }
+            if !errors.is_empty() { return Err(rocket); }
+
+            let store = store::CrosswordStore::new(config.crosswords_dir());
+            let dictionaries = dictionaries::Dictionaries::new(config.dictionaries_dir());
+            let gallery = gallery::GalleryIndex::build(&store, &dictionaries).await;
+            Ok(rocket
+                .manage(store)
+                .manage(dictionaries)
+                .manage(gallery)
+                .manage(stats::SolveStats::load(config.stats_path()))
+                .manage(config))
+        }))
 }
\ No newline at end of file
@@ -0,0 +1,218 @@
+use std::io::Cursor;
+
+use common::{document::{CrosswordDocument, CrosswordId}, print::{clue_text, wrap_text, ClueLine, PageSize, PrintLayout, COLUMN_GAP, HEADER_HEIGHT, LINE_HEIGHT, MARGIN}};
+use crossword_generator::word::Direction;
+use printpdf::{path::PaintMode, IndirectFontRef, Mm, PdfDocument, PdfDocumentReference, PdfLayerReference, Rect};
+use rocket::{http::ContentType, tokio::task::spawn_blocking, Route, State};
+use ttf_parser::Face;
+
+use crate::{error::{parse_id, ApiError}, store::{crossword_not_found, CrosswordStore}};
+
+// dejavu sans has the letters of all the predefined alphabets, it is put in the pdf as a whole,
+// the custom alphabets are checked against it
+const FONT: &[u8] = include_bytes!("../assets/fonts/DejaVuSans.ttf");
+const PT_PER_MM: f32 = 2.835;
+// every crossword of a booklet is rendered in one request
+const MAX_BOOKLET_CROSSWORDS: usize = 50;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PdfOptions
+{
+    pub page_size: PageSize,
+    // a page with the filled grid after every puzzle
+    pub answers: bool,
+    pub grid_only: bool,
+}
+
+// a cursor on the pages of the document, the positions are from the top left corner like on the screen
+struct PdfWriter
+{
+    document: PdfDocumentReference,
+    font: IndirectFontRef,
+    page_size: PageSize,
+    layer: Option<PdfLayerReference>,
+}
+
+impl PdfWriter
+{
+    fn new(title: &str, page_size: PageSize) -> Result<PdfWriter, ApiError>
+    {
+        let (width, height) = page_size.size();
+        let (document, page, layer) = PdfDocument::new(title, Mm(width), Mm(height), "puzzle");
+        let font = document.add_external_font(Cursor::new(FONT)).map_err(|e| ApiError::Internal(e.to_string()))?;
+        let layer = document.get_page(page).get_layer(layer);
+        Ok(PdfWriter { document, font, page_size, layer: Some(layer) })
+    }
+
+    // the first page is made together with the document
+    fn page(&mut self) -> PdfLayerReference
+    {
+        if let Some(layer) = self.layer.take() { return layer; }
+        let (width, height) = self.page_size.size();
+        let (page, layer) = self.document.add_page(Mm(width), Mm(height), "puzzle");
+        self.document.get_page(page).get_layer(layer)
+    }
+
+    fn text(&self, layer: &PdfLayerReference, text: &str, size_mm: f32, x: f32, top: f32)
+    {
+        // the text is placed by its baseline
+        let baseline = self.page_size.size().1 - top - size_mm * 0.8;
+        layer.use_text(text, size_mm * PT_PER_MM, Mm(x), Mm(baseline), &self.font);
+    }
+
+    fn header(&self, layer: &PdfLayerReference, layout: &PrintLayout, suffix: &str)
+    {
+        let title = if layout.title.is_empty() { "Untitled" } else { &layout.title };
+        self.text(layer, &format!("{}{}", title, suffix), 7.0, MARGIN, MARGIN);
+        if !layout.author.is_empty() { self.text(layer, &format!("by {}", layout.author), 4.0, MARGIN, MARGIN + 9.0); }
+    }
+
+    fn grid(&self, layer: &PdfLayerReference, layout: &PrintLayout, with_answers: bool)
+    {
+        let cell_size = layout.cell_size(self.page_size);
+        let page_height = self.page_size.size().1;
+        let left = MARGIN + (self.page_size.content_width() - cell_size * layout.width as f32) / 2.0;
+        let top = MARGIN + HEADER_HEIGHT;
+
+        layer.set_outline_thickness(0.8);
+        for cell in layout.cells.iter()
+        {
+            let x = left + cell.position.x as f32 * cell_size;
+            let y = top + cell.position.y as f32 * cell_size;
+            layer.add_rect(Rect::new(Mm(x), Mm(page_height - y - cell_size), Mm(x + cell_size), Mm(page_height - y)).with_mode(PaintMode::Stroke));
+            if let Some(number) = cell.number { self.text(layer, &number.to_string(), cell_size * 0.28, x + 0.6, y + 0.4); }
+            if with_answers
+            {
+                let tile = cell.tile.to_string();
+                let size = cell_size * 0.55;
+                // the letters are about 0.6 of their height wide
+                let width = size * 0.6 * tile.chars().count() as f32;
+                self.text(layer, &tile, size, x + (cell_size - width) / 2.0, y + (cell_size - size) / 2.0);
+            }
+        }
+    }
+
+    fn puzzle(&mut self, layout: &PrintLayout, grid_only: bool)
+    {
+        let layer = self.page();
+        self.header(&layer, layout, "");
+        self.grid(&layer, layout, false);
+        if grid_only { return; }
+
+        // the same pages as the print page of the editor
+        let mut top = MARGIN + HEADER_HEIGHT + layout.grid_height(self.page_size) + LINE_HEIGHT;
+        let mut layer = layer;
+        for (i, page) in layout.clue_pages(self.page_size).iter().enumerate()
+        {
+            if i > 0
+            {
+                layer = self.page();
+                top = MARGIN;
+            }
+            for (column, lines) in page.columns.iter().enumerate()
+            {
+                let x = MARGIN + column as f32 * (self.page_size.column_width() + COLUMN_GAP);
+                let mut y = top;
+                for line in lines
+                {
+                    match line
+                    {
+                        ClueLine::Heading(direction) =>
+                        {
+                            self.text(&layer, if *direction == Direction::Right { "Across" } else { "Down" }, LINE_HEIGHT, x, y + LINE_HEIGHT * 0.5);
+                            y += LINE_HEIGHT * 2.0;
+                        }
+                        ClueLine::Clue(c) => for text in wrap_text(&clue_text(c), self.page_size.chars_per_line())
+                        {
+                            self.text(&layer, &text, LINE_HEIGHT * 0.7, x, y);
+                            y += LINE_HEIGHT;
+                        },
+                    }
+                }
+            }
+        }
+    }
+
+    fn answers(&mut self, layout: &PrintLayout)
+    {
+        let layer = self.page();
+        self.header(&layer, layout, " - answers");
+        self.grid(&layer, layout, true);
+    }
+}
+
+// the tiles of the alphabet and of the answers, a missing glyph would be an empty box in the pdf
+fn check_glyphs(face: &Face, document: &CrosswordDocument) -> Result<(), ApiError>
+{
+    let tiles = document.alphabet.tiles.iter().chain(document.words.iter().flat_map(|w| w.value.iter()));
+    match tiles.flat_map(|tile| tile.as_str().chars()).find(|ch| !ch.is_whitespace() && face.glyph_index(*ch).is_none())
+    {
+        Some(ch) => Err(ApiError::BadRequest(format!("the font of the pdf has no \"{}\", which the {} alphabet of \"{}\" uses", ch, document.alphabet.name, document.title))),
+        None => Ok(()),
+    }
+}
+
+// the puzzles go one after the other, the answers are at the end of the booklet
+pub fn render_pdf(documents: &[CrosswordDocument], options: PdfOptions) -> Result<Vec<u8>, ApiError>
+{
+    let face = Face::parse(FONT, 0).map_err(|e| ApiError::Internal(e.to_string()))?;
+    for document in documents { check_glyphs(&face, document)?; }
+
+    let layouts = documents.iter().map(PrintLayout::new).collect::<Vec<_>>();
+    let title = match layouts.as_slice()
+    {
+        [layout] => layout.title.clone(),
+        _ => "Crossword booklet".to_owned(),
+    };
+
+    let mut writer = PdfWriter::new(&title, options.page_size)?;
+    for layout in layouts.iter() { writer.puzzle(layout, options.grid_only); }
+    if options.answers
+    {
+        for layout in layouts.iter() { writer.answers(layout); }
+    }
+    writer.document.save_to_bytes().map_err(|e| ApiError::Internal(e.to_string()))
+}
+
+fn pdf_options(size: Option<&str>, answers: Option<bool>, grid_only: Option<bool>) -> Result<PdfOptions, ApiError>
+{
+    let page_size = match size
+    {
+        Some(size) => PageSize::from_name(size).ok_or_else(|| ApiError::BadRequest(format!("unknown page size \"{}\"", size)))?,
+        None => PageSize::default(),
+    };
+    Ok(PdfOptions { page_size, answers: answers.unwrap_or_default(), grid_only: grid_only.unwrap_or_default() })
+}
+
+async fn export(ids: &[&str], options: PdfOptions, store: &CrosswordStore) -> Result<(ContentType, Vec<u8>), ApiError>
+{
+    let mut documents = Vec::new();
+    for id in ids
+    {
+        let id = parse_id::<CrosswordId>(id)?;
+        documents.push(store.get(&id).await.ok_or_else(|| crossword_not_found(&id))?.draft);
+    }
+    // the rendering takes long enough to hold up the other requests on the async workers
+    let pdf = spawn_blocking(move || render_pdf(&documents, options)).await.map_err(|e| ApiError::Internal(e.to_string()))??;
+    Ok((ContentType::PDF, pdf))
+}
+
+#[get("/crosswords/<id>/export.pdf?<size>&<answers>&<grid_only>")]
+async fn export_pdf(id: &str, size: Option<&str>, answers: Option<bool>, grid_only: Option<bool>, store: &State<CrosswordStore>) -> Result<(ContentType, Vec<u8>), ApiError>
+{
+    export(&[id], pdf_options(size, answers, grid_only)?, store).await
+}
+
+// several crosswords in one file, like ?ids=a&ids=b
+#[get("/booklets/export.pdf?<ids>&<size>&<answers>&<grid_only>")]
+async fn export_booklet_pdf(ids: Vec<&str>, size: Option<&str>, answers: Option<bool>, grid_only: Option<bool>, store: &State<CrosswordStore>) -> Result<(ContentType, Vec<u8>), ApiError>
+{
+    if ids.is_empty() { return Err(ApiError::BadRequest("the booklet has no crosswords".to_owned())); }
+    if ids.len() > MAX_BOOKLET_CROSSWORDS { return Err(ApiError::BadRequest(format!("a booklet can have at most {} crosswords", MAX_BOOKLET_CROSSWORDS))); }
+    export(&ids, pdf_options(size, answers, grid_only)?, store).await
+}
+
+pub fn routes() -> Vec<Route>
+{
+    routes![export_pdf, export_booklet_pdf]
+}
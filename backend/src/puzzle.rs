@@ -0,0 +1,30 @@
+use common::solve::{entries_from_cells, is_solved, wrong_entries, CheckRequest, CheckResponse, Puzzle};
+use rocket::{serde::json::Json, Route, State};
+
+use crate::{error::{parse_id, ApiError}, store::{crossword_not_found, version_not_found, CrosswordStore}};
+
+// the latest published version without the answers, for the players outside of the rooms
+#[get("/crosswords/<id>/puzzle")]
+async fn get_puzzle(id: &str, store: &State<CrosswordStore>) -> Result<Json<Puzzle>, ApiError>
+{
+    let id = parse_id(id)?;
+    let record = store.get(&id).await.ok_or_else(|| crossword_not_found(&id))?;
+    let version = record.latest_published().ok_or_else(|| ApiError::NotFound(format!("crossword {} isn't published", id)))?;
+    Ok(Json(Puzzle::new(id, version.info.number, &version.document)))
+}
+
+#[post("/crosswords/<id>/puzzle/check", data = "<request>")]
+async fn check_puzzle(id: &str, request: Json<CheckRequest>, store: &State<CrosswordStore>) -> Result<Json<CheckResponse>, ApiError>
+{
+    let id = parse_id(id)?;
+    let CheckRequest { revision, entries } = request.into_inner();
+    let record = store.get(&id).await.ok_or_else(|| crossword_not_found(&id))?;
+    let version = record.version(revision).filter(|v| v.info.published).ok_or_else(|| version_not_found(&id, revision))?;
+    let entries = entries_from_cells(entries);
+    Ok(Json(CheckResponse { wrong: wrong_entries(&version.document, &entries), solved: is_solved(&version.document, &entries) }))
+}
+
+pub fn routes() -> Vec<Route>
+{
+    routes![get_puzzle, check_puzzle]
+}
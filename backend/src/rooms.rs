@@ -0,0 +1,434 @@
+use std::{collections::HashMap, time::{Duration, Instant}};
+
+use common::{alphabet::Tile, document::CrosswordDocument, grid::cell_letters, room::{ActionOutcome, CreateRoomRequest, CreateRoomResponse, SolveRoomRequest, JoinRoomRequest, JoinRoomResponse, Player, PlayerAction, PlayerActionRequest, PlayerId, PlayerToken, RoomEvent, RoomMode, RoomSnapshot, PLAYER_COLORS}, solve::{entries_to_cells, is_solved, progress, Entries, Puzzle}, stats::{SolveAttempt, UserId}};
+use crossword_generator::word::Position;
+use rocket::{response::stream::{Event, EventStream}, serde::json::Json, tokio::{select, sync::{broadcast::{self, error::RecvError}, Mutex}}, Route, Shutdown, State};
+use uuid::Uuid;
+
+use crate::{error::{parse_id, ApiError}, stats::{now_ms, SolveStats}, store::{crossword_not_found, version_not_found, CrosswordStore}};
+
+// no 0/O and 1/I, the codes are read aloud and typed by hand
+const CODE_CHARS: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+const CODE_LENGTH: usize = 6;
+// the clients send a heartbeat every 30 seconds, a closed tab doesn't send a leave
+const PLAYER_TTL: Duration = Duration::from_secs(90);
+// a new room waits this long for the first player
+const EMPTY_ROOM_TTL: Duration = Duration::from_secs(120);
+
+struct PlayerState
+{
+    player: Player,
+    token: PlayerToken,
+    user_id: UserId,
+    // only used in the race mode
+    entries: Entries,
+    checks: u32,
+    reveals: u32,
+    joined: Instant,
+    joined_at: u64,
+    last_seen: Instant,
+}
+
+struct Room
+{
+    mode: RoomMode,
+    document: CrosswordDocument,
+    solution: Entries,
+    puzzle: Puzzle,
+    players: HashMap<PlayerId, PlayerState>,
+    // only used in the co-op mode
+    shared_entries: Entries,
+    shared_checks: u32,
+    shared_reveals: u32,
+    next_color: usize,
+    created: Instant,
+    created_at: u64,
+    last_active: Instant,
+    // the race room every visitor of the solve link joins
+    solve_link: bool,
+    sender: broadcast::Sender<RoomEvent>,
+}
+
+impl Room
+{
+    fn snapshot(&self, code: &str, player_id: &PlayerId) -> RoomSnapshot
+    {
+        let entries = self.entries(player_id);
+
+        RoomSnapshot
+        {
+            code: code.to_owned(),
+            mode: self.mode,
+            puzzle: self.puzzle.clone(),
+            players: self.players.values().map(|p| p.player.clone()).collect(),
+            entries: entries_to_cells(entries),
+        }
+    }
+
+    fn send(&self, event: RoomEvent)
+    {
+        // nobody listening is fine
+        let _ = self.sender.send(event);
+    }
+
+    // in the co-op mode the clock of everyone starts with the room, in the race mode when the player joins
+    fn start(&self, player_id: &PlayerId) -> (Instant, u64)
+    {
+        match self.mode
+        {
+            RoomMode::Coop => (self.created, self.created_at),
+            RoomMode::Race => (self.players[player_id].joined, self.players[player_id].joined_at),
+        }
+    }
+
+    fn entries(&self, player_id: &PlayerId) -> &Entries
+    {
+        match self.mode
+        {
+            RoomMode::Coop => &self.shared_entries,
+            RoomMode::Race => &self.players[player_id].entries,
+        }
+    }
+
+    fn entries_mut(&mut self, player_id: &PlayerId) -> &mut Entries
+    {
+        match self.mode
+        {
+            RoomMode::Coop => &mut self.shared_entries,
+            RoomMode::Race => &mut self.players.get_mut(player_id).expect("the player is checked by the caller").entries,
+        }
+    }
+
+    // in the co-op mode the checks and the reveals of everyone count
+    fn count_assist(&mut self, player_id: &PlayerId, reveal: bool)
+    {
+        let (checks, reveals) = match self.mode
+        {
+            RoomMode::Coop => (&mut self.shared_checks, &mut self.shared_reveals),
+            RoomMode::Race =>
+            {
+                let state = self.players.get_mut(player_id).expect("the player is checked by the caller");
+                (&mut state.checks, &mut state.reveals)
+            }
+        };
+        if reveal { *reveals += 1; } else { *checks += 1; }
+    }
+
+    // returns the players that have just finished
+    fn set_cell(&mut self, player_id: PlayerId, position: Position, tile: Option<Tile>) -> Result<Vec<PlayerId>, ApiError>
+    {
+        if !self.puzzle.cells.contains(&position) { return Err(ApiError::BadRequest("the cell isn't a part of the puzzle".to_owned())); }
+        if tile.as_ref().is_some_and(|tile| !self.puzzle.alphabet.contains(tile)) { return Err(ApiError::BadRequest("the letter isn't in the alphabet".to_owned())); }
+
+        let entries = self.entries_mut(&player_id);
+        match &tile
+        {
+            Some(tile) => { entries.insert(position.clone(), tile.clone()); }
+            None => { entries.remove(&position); }
+        }
+        let entries = self.entries(&player_id);
+
+        let progress = progress(&self.puzzle, entries);
+        let full = progress >= 1.0;
+        let solved = full && is_solved(&self.document, entries);
+
+        if self.mode == RoomMode::Coop
+        {
+            self.send(RoomEvent::CellChanged { player_id, position, tile });
+        }
+
+        // in the co-op mode the progress and the finish belong to everyone
+        let affected = match self.mode
+        {
+            RoomMode::Coop => self.players.keys().copied().collect::<Vec<_>>(),
+            RoomMode::Race => vec![player_id],
+        };
+        let mut finished = Vec::new();
+        for id in affected
+        {
+            let (started, _) = self.start(&id);
+            let state = self.players.get_mut(&id).expect("the ids are taken from the players");
+            if state.player.finish_time_ms.is_some() { continue; }
+
+            state.player.progress = progress;
+            let event = RoomEvent::ProgressChanged { player_id: id, progress };
+            let finish_time_ms = solved.then(|| started.elapsed().as_millis() as u64);
+            state.player.finish_time_ms = finish_time_ms;
+
+            self.send(event);
+            if let Some(time_ms) = finish_time_ms
+            {
+                self.send(RoomEvent::Solved { player_id: id, time_ms });
+                finished.push(id);
+            }
+        }
+
+        if full && !solved
+        {
+            self.send(RoomEvent::Incorrect { player_id });
+        }
+        Ok(finished)
+    }
+
+    fn check(&mut self, player_id: PlayerId) -> Vec<Position>
+    {
+        self.count_assist(&player_id, false);
+        self.entries(&player_id).iter().filter(|(pos, tile)| self.solution.get(pos) != Some(tile)).map(|(pos, _)| pos.clone()).collect()
+    }
+
+    // the unfinished attempts are recorded too, unless nothing was filled
+    fn remove_player(&mut self, player_id: &PlayerId) -> Option<SolveAttempt>
+    {
+        let state = self.players.get(player_id)?;
+        let attempt = (state.player.finish_time_ms.is_none() && state.player.progress > 0.0).then(|| self.attempt(player_id));
+        self.players.remove(player_id);
+        self.send(RoomEvent::PlayerLeft { player_id: *player_id });
+        attempt
+    }
+
+    fn attempt(&self, player_id: &PlayerId) -> SolveAttempt
+    {
+        let state = &self.players[player_id];
+        let (started, started_at) = self.start(player_id);
+        let (checks, reveals) = match self.mode
+        {
+            RoomMode::Coop => (self.shared_checks, self.shared_reveals),
+            RoomMode::Race => (state.checks, state.reveals),
+        };
+        SolveAttempt
+        {
+            user_id: state.user_id,
+            name: state.player.name.clone(),
+            crossword_id: self.puzzle.crossword_id,
+            revision: self.puzzle.revision,
+            mode: self.mode,
+            started_at,
+            time_ms: state.player.finish_time_ms.unwrap_or_else(|| started.elapsed().as_millis() as u64),
+            checks,
+            reveals,
+            completed: state.player.finish_time_ms.is_some(),
+            size: self.puzzle.cells.len(),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Rooms(Mutex<HashMap<String, Room>>);
+
+fn new_room_code() -> String
+{
+    Uuid::new_v4().as_bytes().iter().take(CODE_LENGTH).map(|b| CODE_CHARS[*b as usize % CODE_CHARS.len()] as char).collect()
+}
+
+// drops the players that stopped sending heartbeats and the rooms nobody is in, returns the attempts to record
+fn sweep(rooms: &mut HashMap<String, Room>) -> Vec<SolveAttempt>
+{
+    let mut attempts = Vec::new();
+    for room in rooms.values_mut()
+    {
+        let stale = room.players.iter().filter(|(_, p)| p.last_seen.elapsed() > PLAYER_TTL).map(|(id, _)| *id).collect::<Vec<_>>();
+        attempts.extend(stale.iter().filter_map(|id| room.remove_player(id)));
+    }
+    rooms.retain(|_, room| !room.players.is_empty() || room.last_active.elapsed() < EMPTY_ROOM_TTL);
+    attempts
+}
+
+async fn record_attempts(stats: &SolveStats, attempts: Vec<SolveAttempt>) -> Result<(), ApiError>
+{
+    for attempt in attempts
+    {
+        stats.record(attempt).await?;
+    }
+    Ok(())
+}
+
+fn room_not_found(code: &str) -> ApiError
+{
+    ApiError::NotFound(format!("room {} doesn't exist", code))
+}
+
+async fn open_room(request: CreateRoomRequest, solve_link: bool, store: &CrosswordStore, rooms: &Rooms, stats: &SolveStats) -> Result<Json<CreateRoomResponse>, ApiError>
+{
+    let CreateRoomRequest { crossword_id, revision, mode } = request;
+    let record = store.get(&crossword_id).await.ok_or_else(|| crossword_not_found(&crossword_id))?;
+    let version = record.version(revision).ok_or_else(|| version_not_found(&crossword_id, revision))?;
+    if !version.info.published { return Err(ApiError::BadRequest(format!("version {} isn't published", revision))); }
+    let document = version.document.clone();
+    if document.words.is_empty() { return Err(ApiError::BadRequest("the crossword has no words".to_owned())); }
+
+    let mut rooms = rooms.0.lock().await;
+    let attempts = sweep(&mut rooms);
+    let existing = rooms.iter()
+        .find(|(_, room)| solve_link && room.solve_link && room.puzzle.crossword_id == crossword_id && room.puzzle.revision == revision)
+        .map(|(code, _)| code.clone());
+    if let Some(code) = existing
+    {
+        drop(rooms);
+        record_attempts(stats, attempts).await?;
+        return Ok(Json(CreateRoomResponse { code }));
+    }
+    let code = std::iter::repeat_with(new_room_code).find(|code| !rooms.contains_key(code)).expect("the iterator is infinite");
+    let puzzle = Puzzle::new(crossword_id, revision, &document);
+    rooms.insert(code.clone(), Room
+    {
+        mode,
+        solution: cell_letters(&document.words),
+        document,
+        puzzle,
+        players: HashMap::new(),
+        shared_entries: Entries::new(),
+        shared_checks: 0,
+        shared_reveals: 0,
+        next_color: 0,
+        created: Instant::now(),
+        created_at: now_ms(),
+        last_active: Instant::now(),
+        solve_link,
+        sender: broadcast::channel(256).0,
+    });
+    drop(rooms);
+    record_attempts(stats, attempts).await?;
+    Ok(Json(CreateRoomResponse { code }))
+}
+
+#[post("/rooms", data = "<request>")]
+async fn create_room(request: Json<CreateRoomRequest>, store: &State<CrosswordStore>, rooms: &State<Rooms>, stats: &State<SolveStats>) -> Result<Json<CreateRoomResponse>, ApiError>
+{
+    open_room(request.into_inner(), false, store, rooms, stats).await
+}
+
+// every visit of a solve link goes to the same race room while it's open, the race keeps the times apart
+#[post("/rooms/solve", data = "<request>")]
+async fn solve_room(request: Json<SolveRoomRequest>, store: &State<CrosswordStore>, rooms: &State<Rooms>, stats: &State<SolveStats>) -> Result<Json<CreateRoomResponse>, ApiError>
+{
+    let SolveRoomRequest { crossword_id, revision } = request.into_inner();
+    open_room(CreateRoomRequest { crossword_id, revision, mode: RoomMode::Race }, true, store, rooms, stats).await
+}
+
+#[post("/rooms/<code>/join", data = "<request>")]
+async fn join_room(code: &str, request: Json<JoinRoomRequest>, rooms: &State<Rooms>, stats: &State<SolveStats>) -> Result<Json<JoinRoomResponse>, ApiError>
+{
+    let JoinRoomRequest { name, user_id } = request.into_inner();
+    let name = name.trim().to_owned();
+    if name.is_empty() { return Err(ApiError::BadRequest("the name is empty".to_owned())); }
+
+    let mut rooms = rooms.0.lock().await;
+    let attempts = sweep(&mut rooms);
+    let response = join(&mut rooms, code, name, user_id);
+    drop(rooms);
+    record_attempts(stats, attempts).await?;
+    response.map(Json)
+}
+
+fn join(rooms: &mut HashMap<String, Room>, code: &str, name: String, user_id: UserId) -> Result<JoinRoomResponse, ApiError>
+{
+    let room = rooms.get_mut(code).ok_or_else(|| room_not_found(code))?;
+    room.last_active = Instant::now();
+
+    let player = Player
+    {
+        id: PlayerId::new(),
+        name,
+        color: PLAYER_COLORS[room.next_color % PLAYER_COLORS.len()].to_owned(),
+        progress: match room.mode
+        {
+            RoomMode::Coop => progress(&room.puzzle, &room.shared_entries),
+            RoomMode::Race => 0.0,
+        },
+        finish_time_ms: None,
+        cursor: None,
+    };
+    room.next_color += 1;
+    let token = PlayerToken::generate();
+    room.players.insert(player.id, PlayerState { player: player.clone(), token, user_id, entries: Entries::new(), checks: 0, reveals: 0, joined: Instant::now(), joined_at: now_ms(), last_seen: Instant::now() });
+    room.send(RoomEvent::PlayerJoined { player: player.clone() });
+
+    Ok(JoinRoomResponse { player_id: player.id, token, room: room.snapshot(code, &player.id) })
+}
+
+#[get("/rooms/<code>/events")]
+async fn room_events(code: &str, rooms: &State<Rooms>, mut shutdown: Shutdown) -> Result<EventStream![], ApiError>
+{
+    let mut receiver = rooms.0.lock().await.get(code).ok_or_else(|| room_not_found(code))?.sender.subscribe();
+    Ok(EventStream!
+    {
+        loop
+        {
+            let event = select!
+            {
+                event = receiver.recv() => match event
+                {
+                    Ok(event) => event,
+                    Err(RecvError::Closed) => break,
+                    Err(RecvError::Lagged(_)) => continue,
+                },
+                _ = &mut shutdown => break,
+            };
+            yield Event::json(&event);
+        }
+    })
+}
+
+#[post("/rooms/<code>/players/<player_id>", data = "<request>")]
+async fn player_action(code: &str, player_id: &str, request: Json<PlayerActionRequest>, rooms: &State<Rooms>, stats: &State<SolveStats>) -> Result<Json<ActionOutcome>, ApiError>
+{
+    let player_id = parse_id::<PlayerId>(player_id)?;
+    let PlayerActionRequest { token, action } = request.into_inner();
+    let mut rooms = rooms.0.lock().await;
+    let mut attempts = sweep(&mut rooms);
+    let outcome = act(&mut rooms, code, player_id, token, action, &mut attempts);
+    drop(rooms);
+    record_attempts(stats, attempts).await?;
+    outcome.map(Json)
+}
+
+fn act(rooms: &mut HashMap<String, Room>, code: &str, player_id: PlayerId, token: PlayerToken, action: PlayerAction, attempts: &mut Vec<SolveAttempt>) -> Result<ActionOutcome, ApiError>
+{
+    let room = rooms.get_mut(code).ok_or_else(|| room_not_found(code))?;
+    let state = room.players.get_mut(&player_id).ok_or_else(|| ApiError::NotFound(format!("player {} isn't in the room", player_id)))?;
+    if state.token != token { return Err(ApiError::Forbidden(format!("the token isn't the one of player {}", player_id))); }
+    state.last_seen = Instant::now();
+    room.last_active = Instant::now();
+
+    let mut finished = Vec::new();
+    let outcome = match action
+    {
+        PlayerAction::SetCell { position, tile } =>
+        {
+            finished = room.set_cell(player_id, position, tile)?;
+            ActionOutcome::Done
+        }
+        PlayerAction::MoveCursor { position } =>
+        {
+            room.players.get_mut(&player_id).expect("checked above").player.cursor = Some(position.clone());
+            room.send(RoomEvent::CursorMoved { player_id, position });
+            ActionOutcome::Done
+        }
+        PlayerAction::Check => ActionOutcome::Checked { wrong: room.check(player_id) },
+        PlayerAction::Reveal { position } =>
+        {
+            let tile = room.solution.get(&position).cloned().ok_or_else(|| ApiError::BadRequest("the cell isn't a part of the puzzle".to_owned()))?;
+            room.count_assist(&player_id, true);
+            finished = room.set_cell(player_id, position.clone(), Some(tile.clone()))?;
+            ActionOutcome::Revealed { position, tile }
+        }
+        PlayerAction::Heartbeat => ActionOutcome::Done,
+        PlayerAction::Leave =>
+        {
+            attempts.extend(room.remove_player(&player_id));
+            if room.players.is_empty() { rooms.remove(code); }
+            ActionOutcome::Done
+        }
+    };
+
+    if let Some(room) = rooms.get(code)
+    {
+        attempts.extend(finished.iter().map(|id| room.attempt(id)));
+    }
+    Ok(outcome)
+}
+
+pub fn routes() -> Vec<Route>
+{
+    routes![create_room, solve_room, join_room, room_events, player_action]
+}
@@ -0,0 +1,61 @@
+use std::{fs, path::PathBuf, time::{SystemTime, UNIX_EPOCH}};
+
+use common::stats::{leaderboard, user_stats, LeaderboardEntry, SolveAttempt, UserStats, DAY_MS};
+use rocket::{serde::json::{serde_json, Json}, tokio::{fs::OpenOptions, io::AsyncWriteExt, sync::Mutex}, Route, State};
+
+use crate::error::{parse_id, ApiError};
+
+pub fn now_ms() -> u64
+{
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or_default()
+}
+
+// the attempts are appended to a json lines file and kept in the memory
+pub struct SolveStats
+{
+    path: PathBuf,
+    attempts: Mutex<Vec<SolveAttempt>>,
+}
+
+impl SolveStats
+{
+    pub fn load(path: impl Into<PathBuf>) -> SolveStats
+    {
+        let path = path.into();
+        let attempts = fs::read_to_string(&path).unwrap_or_default()
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+        SolveStats { path, attempts: Mutex::new(attempts) }
+    }
+
+    pub async fn record(&self, attempt: SolveAttempt) -> Result<(), ApiError>
+    {
+        let mut attempts = self.attempts.lock().await;
+        let line = serde_json::to_string(&attempt).map_err(|e| ApiError::Internal(e.to_string()))? + "\n";
+        if let Some(dir) = self.path.parent() { rocket::tokio::fs::create_dir_all(dir).await.map_err(|e| ApiError::Internal(e.to_string()))?; }
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path).await.map_err(|e| ApiError::Internal(e.to_string()))?;
+        file.write_all(line.as_bytes()).await.map_err(|e| ApiError::Internal(e.to_string()))?;
+        attempts.push(attempt);
+        Ok(())
+    }
+}
+
+#[get("/crosswords/<id>/leaderboard?<revision>")]
+async fn get_leaderboard(id: &str, revision: Option<u32>, stats: &State<SolveStats>) -> Result<Json<Vec<LeaderboardEntry>>, ApiError>
+{
+    let id = parse_id(id)?;
+    Ok(Json(leaderboard(&stats.attempts.lock().await, &id, revision)))
+}
+
+#[get("/users/<id>/stats")]
+async fn get_user_stats(id: &str, stats: &State<SolveStats>) -> Result<Json<UserStats>, ApiError>
+{
+    let id = parse_id(id)?;
+    Ok(Json(user_stats(&stats.attempts.lock().await, &id, now_ms() / DAY_MS)))
+}
+
+pub fn routes() -> Vec<Route>
+{
+    routes![get_leaderboard, get_user_stats]
+}
@@ -0,0 +1,236 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use common::{analysis::{analyze_words, AnalysisReport}, document::{CrosswordDocument, CrosswordId}, export::{export_text, TextExportOptions}, schema::load_record, validation::{compatibility_settings, validate_document, SaveResponse}, version::{diff_documents, CrosswordRecord, DocumentDiff, NewVersionRequest, VersionInfo}};
+use rocket::{http::ContentType, serde::json::{serde_json, Json}, tokio::{fs, sync::Mutex}, Route, State};
+
+use crate::{dictionaries::Dictionaries, error::{parse_id, ApiError}, gallery::GalleryIndex, stats::now_ms};
+
+// every crossword is kept in its own json file, the ones that were read once stay in the memory
+pub struct CrosswordStore
+{
+    dir: PathBuf,
+    records: Mutex<HashMap<CrosswordId, CrosswordRecord>>,
+}
+
+impl CrosswordStore
+{
+    pub fn new(dir: impl Into<PathBuf>) -> CrosswordStore
+    {
+        CrosswordStore { dir: dir.into(), records: Mutex::default() }
+    }
+
+    fn path(&self, id: &CrosswordId) -> PathBuf
+    {
+        self.dir.join(format!("{}.json", id))
+    }
+
+    // the files of the older schema versions are migrated when they are read and written back
+    // the record isn't kept in the memory, unlike with get
+    pub async fn read(&self, id: &CrosswordId) -> Option<CrosswordRecord>
+    {
+        let json = fs::read_to_string(self.path(id)).await.ok()?;
+        let (record, migrated) = match load_record(&json)
+        {
+            Ok(loaded) => loaded,
+            Err(e) =>
+            {
+                error!("crossword {} can't be read: {}", id, e);
+                return None;
+            }
+        };
+        if migrated
+        {
+            if let Err(e) = self.write(id, &record).await { error!("migrated crossword {} can't be written: {:?}", id, e); }
+        }
+        Some(record)
+    }
+
+    async fn write(&self, id: &CrosswordId, record: &CrosswordRecord) -> Result<(), ApiError>
+    {
+        let json = serde_json::to_string_pretty(record).map_err(|e| ApiError::Internal(e.to_string()))?;
+        fs::create_dir_all(&self.dir).await.map_err(|e| ApiError::Internal(e.to_string()))?;
+        fs::write(self.path(id), json).await.map_err(|e| ApiError::Internal(e.to_string()))
+    }
+
+    pub async fn get(&self, id: &CrosswordId) -> Option<CrosswordRecord>
+    {
+        let mut records = self.records.lock().await;
+        if let Some(record) = records.get(id) { return Some(record.clone()); }
+
+        let record = self.read(id).await?;
+        records.insert(*id, record.clone());
+        Some(record)
+    }
+
+    // every crossword that has a file, also the ones that weren't read yet
+    pub async fn ids(&self) -> Vec<CrosswordId>
+    {
+        let mut ids = Vec::new();
+        let Ok(mut entries) = fs::read_dir(&self.dir).await else { return ids; };
+        while let Ok(Some(entry)) = entries.next_entry().await
+        {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "json")
+            {
+                if let Some(id) = path.file_stem().and_then(|stem| stem.to_str()).and_then(|stem| stem.parse().ok()) { ids.push(id); }
+            }
+        }
+        ids
+    }
+
+    pub async fn create(&self, document: CrosswordDocument) -> Result<CrosswordId, ApiError>
+    {
+        let id = CrosswordId::new();
+        let record = CrosswordRecord::new(document, now_ms());
+        self.write(&id, &record).await?;
+        self.records.lock().await.insert(id, record);
+        Ok(id)
+    }
+
+    // changes the record and writes it back, nothing is changed if the closure fails
+    pub async fn update<R>(&self, id: &CrosswordId, f: impl FnOnce(&mut CrosswordRecord) -> Result<R, ApiError>) -> Result<R, ApiError>
+    {
+        let mut records = self.records.lock().await;
+        let mut record = match records.get(id)
+        {
+            Some(record) => record.clone(),
+            None => self.read(id).await.ok_or_else(|| crossword_not_found(id))?,
+        };
+
+        let result = f(&mut record)?;
+        self.write(id, &record).await?;
+        records.insert(*id, record);
+        Ok(result)
+    }
+}
+
+pub fn crossword_not_found(id: &CrosswordId) -> ApiError
+{
+    ApiError::NotFound(format!("crossword {} doesn't exist", id))
+}
+
+pub fn version_not_found(id: &CrosswordId, number: u32) -> ApiError
+{
+    ApiError::NotFound(format!("crossword {} has no version {}", id, number))
+}
+
+// drafts are saved even when they are invalid, only publishing needs a valid document
+#[post("/crosswords", data = "<document>")]
+async fn create_crossword(document: Json<CrosswordDocument>, store: &State<CrosswordStore>, dictionaries: &State<Dictionaries>) -> Result<Json<SaveResponse>, ApiError>
+{
+    let errors = validate_document(&document, &compatibility_settings());
+    let mut document = document.into_inner();
+    document.difficulty_score = Some(dictionaries.difficulty_score(&document).await);
+    let id = store.create(document).await?;
+    Ok(Json(SaveResponse { id, errors }))
+}
+
+#[get("/crosswords/<id>")]
+async fn get_crossword(id: &str, store: &State<CrosswordStore>) -> Result<Json<CrosswordDocument>, ApiError>
+{
+    let id = parse_id(id)?;
+    store.get(&id).await.map(|record| Json(record.draft)).ok_or_else(|| crossword_not_found(&id))
+}
+
+// the autosave of the editor
+#[put("/crosswords/<id>", data = "<document>")]
+async fn update_crossword(id: &str, document: Json<CrosswordDocument>, store: &State<CrosswordStore>, dictionaries: &State<Dictionaries>) -> Result<Json<SaveResponse>, ApiError>
+{
+    let id = parse_id(id)?;
+    let errors = validate_document(&document, &compatibility_settings());
+    let mut document = document.into_inner();
+    document.difficulty_score = Some(dictionaries.difficulty_score(&document).await);
+    store.update(&id, |record|
+    {
+        record.draft = document;
+        record.draft_saved_at = now_ms();
+        Ok(())
+    }).await?;
+    Ok(Json(SaveResponse { id, errors }))
+}
+
+#[get("/crosswords/<id>/analysis")]
+async fn get_analysis(id: &str, store: &State<CrosswordStore>) -> Result<Json<AnalysisReport>, ApiError>
+{
+    let id = parse_id(id)?;
+    let record = store.get(&id).await.ok_or_else(|| crossword_not_found(&id))?;
+    Ok(Json(analyze_words(&record.draft.words)))
+}
+
+// markdown, the grid is in a code block
+#[get("/crosswords/<id>/export.txt?<answers>&<clues>")]
+async fn export_txt(id: &str, answers: Option<bool>, clues: Option<bool>, store: &State<CrosswordStore>) -> Result<(ContentType, String), ApiError>
+{
+    let id = parse_id(id)?;
+    let record = store.get(&id).await.ok_or_else(|| crossword_not_found(&id))?;
+    let defaults = TextExportOptions::default();
+    let options = TextExportOptions { answers: answers.unwrap_or(defaults.answers), clues: clues.unwrap_or(defaults.clues) };
+    Ok((ContentType::Plain, export_text(&record.draft, &options)))
+}
+
+#[get("/crosswords/<id>/versions")]
+async fn get_versions(id: &str, store: &State<CrosswordStore>) -> Result<Json<Vec<VersionInfo>>, ApiError>
+{
+    let id = parse_id(id)?;
+    let record = store.get(&id).await.ok_or_else(|| crossword_not_found(&id))?;
+    Ok(Json(record.versions.into_iter().map(|v| v.info).collect()))
+}
+
+// freezes the current draft
+#[post("/crosswords/<id>/versions", data = "<new_version>")]
+async fn create_version(id: &str, new_version: Json<NewVersionRequest>, store: &State<CrosswordStore>, gallery: &State<GalleryIndex>, dictionaries: &State<Dictionaries>) -> Result<Json<VersionInfo>, ApiError>
+{
+    let id = parse_id(id)?;
+    let (info, published) = store.update(&id, |record|
+    {
+        if new_version.publish
+        {
+            let errors = validate_document(&record.draft, &compatibility_settings());
+            if !errors.is_empty() { return Err(ApiError::Invalid(Json(errors))); }
+        }
+        let info = record.add_version(new_version.publish, now_ms());
+        Ok((info.clone(), record.latest_published().filter(|_| info.published).cloned()))
+    }).await?;
+    if let Some(version) = published { gallery.publish(id, &version, dictionaries).await; }
+    Ok(Json(info))
+}
+
+#[get("/crosswords/<id>/versions/<number>")]
+async fn get_version(id: &str, number: u32, store: &State<CrosswordStore>) -> Result<Json<CrosswordDocument>, ApiError>
+{
+    let id = parse_id(id)?;
+    let record = store.get(&id).await.ok_or_else(|| crossword_not_found(&id))?;
+    record.version(number).map(|v| Json(v.document.clone())).ok_or_else(|| version_not_found(&id, number))
+}
+
+// what changed from the version to the other one, or to the draft if there is no other
+#[get("/crosswords/<id>/versions/<number>/diff?<against>")]
+async fn get_version_diff(id: &str, number: u32, against: Option<u32>, store: &State<CrosswordStore>) -> Result<Json<DocumentDiff>, ApiError>
+{
+    let id = parse_id(id)?;
+    let record = store.get(&id).await.ok_or_else(|| crossword_not_found(&id))?;
+    let old = &record.version(number).ok_or_else(|| version_not_found(&id, number))?.document;
+    let new = match against
+    {
+        Some(against) => &record.version(against).ok_or_else(|| version_not_found(&id, against))?.document,
+        None => &record.draft,
+    };
+    Ok(Json(diff_documents(old, new)))
+}
+
+#[post("/crosswords/<id>/versions/<number>/restore")]
+async fn restore_version(id: &str, number: u32, store: &State<CrosswordStore>) -> Result<Json<CrosswordDocument>, ApiError>
+{
+    let id = parse_id(id)?;
+    store.update(&id, |record|
+    {
+        record.draft = record.version(number).ok_or_else(|| version_not_found(&id, number))?.document.clone();
+        record.draft_saved_at = now_ms();
+        Ok(Json(record.draft.clone()))
+    }).await
+}
+
+pub fn routes() -> Vec<Route>
+{
+    routes![create_crossword, get_crossword, update_crossword, get_analysis, export_txt, get_versions, create_version, get_version, get_version_diff, restore_version]
+}
@@ -0,0 +1,163 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
+
+// the content of a single crossword cell, one grapheme or a multi-char unit like "ՈՒ" or "LL"
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Tile(String);
+
+impl Tile
+{
+    pub fn new(value: impl Into<String>) -> Tile
+    {
+        Tile(value.into())
+    }
+
+    pub fn as_str(&self) -> &str
+    {
+        &self.0
+    }
+}
+
+impl fmt::Display for Tile
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        f.write_str(&self.0)
+    }
+}
+
+pub fn tiles_to_string(tiles: &[Tile]) -> String
+{
+    tiles.iter().map(Tile::as_str).collect()
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Locale
+{
+    #[default]
+    English,
+    Armenian,
+    Spanish,
+    Turkish,
+}
+
+impl Locale
+{
+    pub fn code(&self) -> &'static str
+    {
+        match self
+        {
+            Locale::English => "en",
+            Locale::Armenian => "hy",
+            Locale::Spanish => "es",
+            Locale::Turkish => "tr",
+        }
+    }
+
+    // crossword cells are always shown in upper case
+    pub fn fold_case(&self, s: &str) -> String
+    {
+        match self
+        {
+            Locale::Turkish => s.chars().flat_map(|ch| match ch
+            {
+                'i' => "İ".chars().collect::<Vec<_>>(),
+                'ı' => vec!['I'],
+                ch => ch.to_uppercase().collect(),
+            }).collect(),
+            _ => s.to_uppercase(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AlphabetError
+{
+    UnknownTile { grapheme: String, offset: usize }, // offset is in graphemes of the input
+}
+
+impl fmt::Display for AlphabetError
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        match self
+        {
+            AlphabetError::UnknownTile { grapheme, offset } => write!(f, "\"{}\" at position {} is not in the alphabet", grapheme, offset + 1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Alphabet
+{
+    pub name: String,
+    pub locale: Locale,
+    // every tile a cell can hold, already case folded, an empty list accepts any grapheme
+    pub tiles: Vec<Tile>,
+}
+
+impl Alphabet
+{
+    pub fn new(name: impl Into<String>, locale: Locale, tiles: impl IntoIterator<Item = impl AsRef<str>>) -> Alphabet
+    {
+        Alphabet { name: name.into(), locale, tiles: tiles.into_iter().map(|t| Tile::new(locale.fold_case(t.as_ref()))).collect() }
+    }
+
+    pub fn english() -> Alphabet
+    {
+        Alphabet::new("English", Locale::English, ('A'..='Z').map(String::from))
+    }
+
+    pub fn armenian() -> Alphabet
+    {
+        Alphabet::new("Armenian", Locale::Armenian, ('Ա'..='Ֆ').map(String::from).chain(["ու", "և"].map(String::from)))
+    }
+
+    pub fn spanish() -> Alphabet
+    {
+        Alphabet::new("Spanish", Locale::Spanish, "ABCDEFGHIJKLMNÑOPQRSTUVWXYZ".chars().map(String::from).chain(["ch", "ll", "rr"].map(String::from)))
+    }
+
+    pub fn turkish() -> Alphabet
+    {
+        Alphabet::new("Turkish", Locale::Turkish, "ABCÇDEFGĞHIİJKLMNOÖPRSŞTUÜVYZ".chars().map(String::from))
+    }
+
+    pub fn predefined() -> Vec<Alphabet>
+    {
+        vec![Alphabet::english(), Alphabet::armenian(), Alphabet::spanish(), Alphabet::turkish()]
+    }
+
+    pub fn contains(&self, tile: &Tile) -> bool
+    {
+        self.tiles.is_empty() || self.tiles.contains(tile)
+    }
+
+    // splits the text into tiles, the longest tile that ends on a grapheme boundary wins
+    pub fn segment(&self, text: &str) -> Result<Vec<Tile>, AlphabetError>
+    {
+        let folded = self.locale.fold_case(text);
+        let graphemes = folded.graphemes(true).filter(|g| !g.trim().is_empty()).collect::<Vec<_>>();
+        // no tile is longer than this, so the longer candidates aren't tried
+        let longest_tile = self.tiles.iter().map(|t| t.as_str().graphemes(true).count()).max().unwrap_or(1);
+
+        let mut tiles = Vec::new();
+        let mut i = 0;
+        while i < graphemes.len()
+        {
+            let longest = (i + 1..=graphemes.len().min(i + longest_tile)).rev()
+                .map(|end| (end, graphemes[i..end].concat()))
+                .find(|(end, candidate)| self.tiles.iter().any(|t| t.as_str() == candidate) || (*end == i + 1 && self.tiles.is_empty()));
+
+            match longest
+            {
+                Some((end, tile)) => { tiles.push(Tile::new(tile)); i = end; }
+                None => return Err(AlphabetError::UnknownTile { grapheme: graphemes[i].to_owned(), offset: i }),
+            }
+        }
+        Ok(tiles)
+    }
+}
@@ -0,0 +1,133 @@
+use std::{cmp::min, collections::{BTreeMap, HashMap}};
+
+use crossword_generator::{traits::{CrosswordChar, CrosswordString}, word::{Direction, Position}};
+use serde::{Deserialize, Serialize};
+
+use crate::{document::{DocumentWord, WordId}, grid::{cell_at, cell_words, word_components}};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Symmetry
+{
+    // 180° around the center
+    #[default]
+    Rotational,
+    // left to right
+    Horizontal,
+    // top to bottom
+    Vertical,
+}
+
+impl Symmetry
+{
+    pub fn all() -> [Symmetry; 3]
+    {
+        [Symmetry::Rotational, Symmetry::Horizontal, Symmetry::Vertical]
+    }
+
+    // the center is given doubled, so it can also be between two cells
+    pub fn mirror(&self, position: &Position, center_x2: (i16, i16)) -> Position
+    {
+        let (cx, cy) = center_x2;
+        match self
+        {
+            Symmetry::Rotational => Position { x: cx - position.x, y: cy - position.y },
+            Symmetry::Horizontal => Position { x: cx - position.x, y: position.y },
+            Symmetry::Vertical => Position { x: position.x, y: cy - position.y },
+        }
+    }
+
+    // where the mirror of a word starts, the mirror keeps the direction and the length
+    pub fn mirror_start(&self, position: &Position, direction: &Direction, length: usize, center_x2: (i16, i16)) -> Position
+    {
+        let first = self.mirror(position, center_x2);
+        let last = self.mirror(&cell_at(position, direction, length.saturating_sub(1)), center_x2);
+        Position { x: min(first.x, last.x), y: min(first.y, last.y) }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SymmetryScore
+{
+    pub symmetry: Symmetry,
+    // the part of the cells whose mirror cell is also filled, 1 for a symmetric grid
+    pub score: f32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AnalysisReport
+{
+    pub words: usize,
+    pub components: usize,
+    pub cells: usize,
+    // cells that are in an across and a down word
+    pub checked_cells: usize,
+    pub unchecked_cells: usize,
+    pub width: i16,
+    pub height: i16,
+    // filled cells to the cells of the bounding box
+    pub density: f32,
+    // word length to the count of the words with that length
+    pub length_distribution: BTreeMap<usize, usize>,
+    pub symmetry: Vec<SymmetryScore>,
+}
+
+impl AnalysisReport
+{
+    pub fn connected(&self) -> bool
+    {
+        self.components <= 1
+    }
+}
+
+// the doubled center of the bounding box of the cells
+pub fn cells_center_x2<'a>(cells: impl Iterator<Item = &'a Position> + Clone) -> (i16, i16)
+{
+    let min_x = cells.clone().map(|pos| pos.x).min().unwrap_or_default();
+    let max_x = cells.clone().map(|pos| pos.x).max().unwrap_or_default();
+    let min_y = cells.clone().map(|pos| pos.y).min().unwrap_or_default();
+    let max_y = cells.map(|pos| pos.y).max().unwrap_or_default();
+    (min_x + max_x, min_y + max_y)
+}
+
+pub fn analyze<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(words: &[DocumentWord<CharT, StrT>], cells: &HashMap<Position, Vec<(WordId, usize)>>) -> AnalysisReport
+{
+    if cells.is_empty() { return AnalysisReport { words: words.len(), ..Default::default() }; }
+
+    let checked_cells = cells.values().filter(|cell_words| cell_words.len() > 1).count();
+    let min_x = cells.keys().map(|pos| pos.x).min().unwrap_or_default();
+    let min_y = cells.keys().map(|pos| pos.y).min().unwrap_or_default();
+    let width = cells.keys().map(|pos| pos.x).max().unwrap_or_default() - min_x + 1;
+    let height = cells.keys().map(|pos| pos.y).max().unwrap_or_default() - min_y + 1;
+
+    let mut length_distribution = BTreeMap::new();
+    for w in words.iter()
+    {
+        *length_distribution.entry(w.value.as_ref().len()).or_default() += 1;
+    }
+
+    let center_x2 = cells_center_x2(cells.keys());
+    let symmetry = Symmetry::all().into_iter().map(|symmetry| SymmetryScore
+    {
+        symmetry,
+        score: cells.keys().filter(|pos| cells.contains_key(&symmetry.mirror(pos, center_x2))).count() as f32 / cells.len() as f32,
+    }).collect();
+
+    AnalysisReport
+    {
+        words: words.len(),
+        components: word_components(words).len(),
+        cells: cells.len(),
+        checked_cells,
+        unchecked_cells: cells.len() - checked_cells,
+        width,
+        height,
+        density: cells.len() as f32 / (width as f32 * height as f32),
+        length_distribution,
+        symmetry,
+    }
+}
+
+pub fn analyze_words<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(words: &[DocumentWord<CharT, StrT>]) -> AnalysisReport
+{
+    analyze(words, &cell_words(words))
+}
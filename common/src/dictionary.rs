@@ -0,0 +1,20 @@
+use crate::alphabet::Locale;
+
+// the word list of a locale is a text file with a word on every line, the lines that start with # are comments
+// the server reads them from its dictionaries directory and serves them to the editor at DICTIONARIES_URL
+pub const DICTIONARIES_URL: &str = "/data/dictionaries";
+
+pub fn dictionary_file_name(locale: Locale) -> String
+{
+    format!("{}.txt", locale.code())
+}
+
+pub fn dictionary_url(locale: Locale) -> String
+{
+    format!("{}/{}", DICTIONARIES_URL, dictionary_file_name(locale))
+}
+
+pub fn parse_dictionary(text: &str) -> Vec<String>
+{
+    text.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#')).map(str::to_owned).collect()
+}
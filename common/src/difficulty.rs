@@ -0,0 +1,132 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{alphabet::{tiles_to_string, Locale, Tile}, analysis::analyze_words, document::{CrosswordDocument, Difficulty}};
+
+// how much every factor counts in the score, they add up to 1
+const UNKNOWN_WORDS_WEIGHT: f32 = 0.3;
+const LETTER_RARITY_WEIGHT: f32 = 0.15;
+const UNCHECKED_WEIGHT: f32 = 0.25;
+const LENGTH_WEIGHT: f32 = 0.15;
+const CLUE_WEIGHT: f32 = 0.15;
+
+// the average word lengths of the easiest and the hardest puzzles
+const SHORT_WORDS: f32 = 3.0;
+const LONG_WORDS: f32 = 10.0;
+// clues of this many words or less give little to go on
+const TERSE_CLUE_WORDS: usize = 2;
+
+// every factor is between 0 for the easiest and 1 for the hardest
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct DifficultyFactors
+{
+    // the words that aren't in the dictionary of the locale, the word lists have no frequencies
+    // so this only tells the known words from the unknown ones, a rare word of the list counts as known
+    pub unknown_words: f32,
+    // how seldom the letters of the cells are in the dictionary
+    pub letter_rarity: f32,
+    // the cells that are only in one word, so they can't be guessed from the crossing word
+    pub unchecked_cells: f32,
+    pub word_length: f32,
+    // the clues with wordplay, marked by a question mark, and the missing or terse ones
+    pub clues: f32,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct DifficultyEstimate
+{
+    // from 0 to 100
+    pub score: u8,
+    pub factors: DifficultyFactors,
+}
+
+impl DifficultyEstimate
+{
+    pub fn difficulty(&self) -> Difficulty
+    {
+        Difficulty::from_score(self.score)
+    }
+}
+
+// the words and the letter counts of a dictionary word list, how often the words are used isn't known
+// they only depend on the locale, every alphabet of the locale uses the same ones
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WordFrequencies
+{
+    // case folded and without the spaces, like the tiles of an answer put together
+    words: HashSet<String>,
+    letters: HashMap<String, usize>,
+}
+
+impl WordFrequencies
+{
+    pub fn new(locale: Locale, dictionary: &[impl AsRef<str>]) -> WordFrequencies
+    {
+        let mut frequencies = WordFrequencies::default();
+        for word in dictionary.iter().map(|w| locale.fold_case(w.as_ref()).split_whitespace().collect::<String>()).filter(|w| !w.is_empty())
+        {
+            for letter in word.graphemes(true) { *frequencies.letters.entry(letter.to_owned()).or_default() += 1; }
+            frequencies.words.insert(word);
+        }
+        frequencies
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.words.is_empty()
+    }
+
+    pub fn contains(&self, word: &[Tile]) -> bool
+    {
+        self.words.contains(&tiles_to_string(word))
+    }
+
+    // 0 for the most common letter, 1 for the ones the dictionary doesn't have, a tile like "LL" is as rare as its rarest letter
+    pub fn letter_rarity(&self, tile: &Tile) -> f32
+    {
+        let most = self.letters.values().copied().max().unwrap_or_default();
+        if most == 0 { return 0.5; }
+        let count = tile.as_str().graphemes(true).map(|letter| self.letters.get(letter).copied().unwrap_or_default()).min().unwrap_or_default();
+        1.0 - count as f32 / most as f32
+    }
+}
+
+fn mean(values: impl Iterator<Item = f32>) -> f32
+{
+    let (sum, count) = values.fold((0.0, 0), |(sum, count), value| (sum + value, count + 1));
+    if count == 0 { 0.0 } else { sum / count as f32 }
+}
+
+fn clue_difficulty(clue: &str) -> f32
+{
+    let clue = clue.trim();
+    if clue.is_empty() { return 1.0; }
+    let wordplay = if clue.ends_with('?') { 0.5 } else { 0.0 };
+    let terse = if clue.split_whitespace().count() <= TERSE_CLUE_WORDS { 0.5 } else { 0.0 };
+    wordplay + terse
+}
+
+// without a dictionary the words and the letters count as average
+pub fn estimate_difficulty(document: &CrosswordDocument, frequencies: &WordFrequencies) -> DifficultyEstimate
+{
+    let words = &document.words;
+    if words.is_empty() { return DifficultyEstimate::default(); }
+
+    let report = analyze_words(words);
+    let factors = DifficultyFactors
+    {
+        unknown_words: if frequencies.is_empty() { 0.5 } else { mean(words.iter().map(|w| if frequencies.contains(&w.value) { 0.0 } else { 1.0 })) },
+        letter_rarity: mean(words.iter().flat_map(|w| w.value.iter()).map(|tile| frequencies.letter_rarity(tile))),
+        unchecked_cells: if report.cells == 0 { 0.0 } else { report.unchecked_cells as f32 / report.cells as f32 },
+        word_length: ((mean(words.iter().map(|w| w.value.len() as f32)) - SHORT_WORDS) / (LONG_WORDS - SHORT_WORDS)).clamp(0.0, 1.0),
+        clues: mean(words.iter().map(|w| clue_difficulty(document.clue(&w.id)))),
+    };
+    let score = factors.unknown_words * UNKNOWN_WORDS_WEIGHT
+        + factors.letter_rarity * LETTER_RARITY_WEIGHT
+        + factors.unchecked_cells * UNCHECKED_WEIGHT
+        + factors.word_length * LENGTH_WEIGHT
+        + factors.clues * CLUE_WEIGHT;
+    DifficultyEstimate { score: (score * 100.0).round().clamp(0.0, 100.0) as u8, factors }
+}
@@ -0,0 +1,191 @@
+use std::{collections::{BTreeMap, BTreeSet}, fmt, ops::{Deref, DerefMut}, str::FromStr};
+
+use crossword_generator::{placed_word::PlacedWord, traits::{CrosswordChar, CrosswordString}};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{alphabet::{Alphabet, Tile}, duplicates::DuplicateSettings};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct WordId(Uuid);
+
+// a random id is not a default value, so there is no Default
+#[allow(clippy::new_without_default)]
+impl WordId
+{
+    pub fn new() -> WordId
+    {
+        WordId(Uuid::new_v4())
+    }
+}
+
+impl fmt::Display for WordId
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "{}", self.0.simple())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(bound(serialize = "CharT: Serialize, StrT: Serialize", deserialize = "CharT: Deserialize<'de>, StrT: Deserialize<'de>"))]
+pub struct DocumentWord<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
+{
+    pub id: WordId,
+    #[serde(flatten)]
+    pub word: PlacedWord<CharT, StrT>,
+    // locked words can't be selected, moved, removed or changed in the editor
+    #[serde(default)]
+    pub locked: bool,
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> DocumentWord<CharT, StrT>
+{
+    pub fn new(id: WordId, word: PlacedWord<CharT, StrT>) -> DocumentWord<CharT, StrT>
+    {
+        DocumentWord { id, word, locked: false }
+    }
+
+    pub fn from_placed_word(word: PlacedWord<CharT, StrT>) -> DocumentWord<CharT, StrT>
+    {
+        DocumentWord::new(WordId::new(), word)
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Deref for DocumentWord<CharT, StrT>
+{
+    type Target = PlacedWord<CharT, StrT>;
+
+    fn deref(&self) -> &Self::Target
+    {
+        &self.word
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> DerefMut for DocumentWord<CharT, StrT>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target
+    {
+        &mut self.word
+    }
+}
+
+pub type CrosswordWord = DocumentWord<Tile, Vec<Tile>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CrosswordId(Uuid);
+
+// a random id is not a default value, so there is no Default
+#[allow(clippy::new_without_default)]
+impl CrosswordId
+{
+    pub fn new() -> CrosswordId
+    {
+        CrosswordId(Uuid::new_v4())
+    }
+}
+
+impl fmt::Display for CrosswordId
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "{}", self.0.simple())
+    }
+}
+
+impl FromStr for CrosswordId
+{
+    type Err = uuid::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        Uuid::parse_str(s).map(CrosswordId)
+    }
+}
+
+// words that are selected and moved together
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WordGroup
+{
+    pub name: String,
+    pub words: BTreeSet<WordId>,
+}
+
+// how hard the author says the crossword is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Difficulty
+{
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl Difficulty
+{
+    pub fn all() -> [Difficulty; 3]
+    {
+        [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard]
+    }
+
+    pub fn name(&self) -> &'static str
+    {
+        match self
+        {
+            Difficulty::Easy => "easy",
+            Difficulty::Medium => "medium",
+            Difficulty::Hard => "hard",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Difficulty>
+    {
+        Difficulty::all().into_iter().find(|d| d.name().eq_ignore_ascii_case(name))
+    }
+
+    // for an estimated score from 0 to 100
+    pub fn from_score(score: u8) -> Difficulty
+    {
+        match score
+        {
+            0..=33 => Difficulty::Easy,
+            34..=66 => Difficulty::Medium,
+            _ => Difficulty::Hard,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrosswordDocument
+{
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub author: String,
+    pub alphabet: Alphabet,
+    pub words: Vec<CrosswordWord>,
+    #[serde(default)]
+    pub clues: BTreeMap<WordId, String>,
+    #[serde(default)]
+    pub groups: Vec<WordGroup>,
+    // lowercase, for the gallery
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub difficulty: Option<Difficulty>,
+    // estimated from 0 to 100, the server sets it when the document is saved
+    #[serde(default)]
+    pub difficulty_score: Option<u8>,
+    // which repeated answers break the rules of this crossword
+    #[serde(default)]
+    pub duplicate_settings: DuplicateSettings,
+}
+
+impl CrosswordDocument
+{
+    pub fn clue(&self, id: &WordId) -> &str
+    {
+        self.clues.get(id).map(String::as_str).unwrap_or_default()
+    }
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+// which repeated answers are reported, the same answer twice always breaks the rules, the others are house rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DuplicateSettings
+{
+    pub same_answer: bool,
+    // one answer is inside the other, like "CAT" in "CATALOG"
+    pub contained_answer: bool,
+    // both answers start with the same root_length letters, a rough stand-in for the same stem
+    pub shared_root: bool,
+    pub root_length: usize,
+}
+
+impl Default for DuplicateSettings
+{
+    fn default() -> Self
+    {
+        DuplicateSettings { same_answer: true, contained_answer: false, shared_root: false, root_length: 5 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DuplicateIssue
+{
+    SameAnswer,
+    ContainedAnswer,
+    SharedRoot,
+}
+
+impl DuplicateIssue
+{
+    pub fn description(&self) -> &'static str
+    {
+        match self
+        {
+            DuplicateIssue::SameAnswer => "are the same answer",
+            DuplicateIssue::ContainedAnswer => "contain one another",
+            DuplicateIssue::SharedRoot => "share a root",
+        }
+    }
+}
+
+pub fn duplicate_issue<CharT: PartialEq>(first: &[CharT], second: &[CharT], settings: &DuplicateSettings) -> Option<DuplicateIssue>
+{
+    if first.is_empty() || second.is_empty() { return None; }
+
+    let (shorter, longer) = if first.len() <= second.len() { (first, second) } else { (second, first) };
+    if first == second
+    {
+        settings.same_answer.then_some(DuplicateIssue::SameAnswer)
+    }
+    else if settings.contained_answer && longer.windows(shorter.len()).any(|window| window == shorter)
+    {
+        Some(DuplicateIssue::ContainedAnswer)
+    }
+    else if settings.shared_root && settings.root_length > 0 && shorter.len() >= settings.root_length && shorter[..settings.root_length] == longer[..settings.root_length]
+    {
+        Some(DuplicateIssue::SharedRoot)
+    }
+    else { None }
+}
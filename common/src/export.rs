@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use crossword_generator::word::Direction;
+use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{document::CrosswordDocument, print::PrintLayout};
+
+pub const BLOCK: &str = "#";
+pub const BLANK: &str = "_";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TextExportOptions
+{
+    // the letters, or blanks to solve on
+    pub answers: bool,
+    pub clues: bool,
+}
+
+impl Default for TextExportOptions
+{
+    fn default() -> Self
+    {
+        TextExportOptions { answers: true, clues: true }
+    }
+}
+
+// one row of text for every row of the grid, the cells without letters are blocks
+// with tiles like "LL" every cell is padded to the widest one and the cells are split by spaces, so the columns stay in line
+pub fn grid_text(layout: &PrintLayout, answers: bool) -> String
+{
+    let tiles = layout.cells.iter().map(|c| ((c.position.x, c.position.y), c.tile.to_string())).collect::<HashMap<_, _>>();
+    let width = if answers { tiles.values().map(|tile| tile.graphemes(true).count()).max().unwrap_or_default().max(1) } else { 1 };
+    let separator = if width > 1 { " " } else { "" };
+    (0..layout.height).map(|y| (0..layout.width).map(|x|
+    {
+        let cell = match tiles.get(&(x, y))
+        {
+            Some(tile) if answers => tile.as_str(),
+            Some(_) => BLANK,
+            None => BLOCK,
+        };
+        format!("{}{}", cell, " ".repeat(width - cell.graphemes(true).count()))
+    }).collect::<Vec<_>>().join(separator).trim_end().to_owned()).collect::<Vec<_>>().join("\n")
+}
+
+// the text of the title and the clues is shown as it is typed, not as markdown
+fn escape_markdown(text: &str) -> String
+{
+    text.chars().fold(String::new(), |mut escaped, ch|
+    {
+        if "\\`*_[]<>#|~".contains(ch) { escaped.push('\\'); }
+        escaped.push(ch);
+        escaped
+    })
+}
+
+// markdown with the grid in a code block, so it keeps the monospace font in the chats
+pub fn export_text(document: &CrosswordDocument, options: &TextExportOptions) -> String
+{
+    let layout = PrintLayout::new(document);
+    let mut text = format!("# {}\n", if layout.title.is_empty() { "Untitled".to_owned() } else { escape_markdown(&layout.title) });
+    if !layout.author.is_empty() { text += &format!("\nby {}\n", escape_markdown(&layout.author)); }
+    text += &format!("\n```text\n{}\n```\n", grid_text(&layout, options.answers));
+
+    if options.clues
+    {
+        for (direction, heading) in [(Direction::Right, "Across"), (Direction::Down, "Down")]
+        {
+            let clues = layout.clues.iter().filter(|c| c.word.direction == direction).collect::<Vec<_>>();
+            if clues.is_empty() { continue; }
+            text += &format!("\n## {}\n\n", heading);
+            // a numbered markdown list would be numbered again from 1
+            for clue in clues { text += &format!("- **{}** {} ({})\n", clue.word.number, escape_markdown(&clue.clue), clue.word.length); }
+        }
+    }
+    text
+}
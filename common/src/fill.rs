@@ -0,0 +1,142 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crossword_generator::{placed_word::PlacedWord, traits::{CrosswordChar, CrosswordString}, word::Position};
+
+use crate::grid::cell_at;
+
+// a slot is a placed word whose letters are not known yet, None marks a free cell
+pub type Slot<CharT> = PlacedWord<Option<CharT>, Vec<Option<CharT>>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FillError
+{
+    NoCandidates(usize), // index of the slot that no dictionary word fits into
+    Unsatisfiable,
+}
+
+// (index in the first slot, index in the second slot) for every shared cell
+type Crossing = Vec<(usize, usize)>;
+
+struct SlotSolver<'a, CharT: CrosswordChar, StrT: CrosswordString<CharT>>
+{
+    words: Vec<&'a StrT>,
+    crossings: Vec<HashMap<usize, Crossing>>,
+    _char: std::marker::PhantomData<CharT>,
+}
+
+impl<'a, CharT: CrosswordChar, StrT: CrosswordString<CharT>> SlotSolver<'a, CharT, StrT>
+{
+    fn letters(&self, word: usize, indexes: impl Iterator<Item = usize>) -> Vec<&CharT>
+    {
+        let value = self.words[word].as_ref();
+        indexes.map(|i| &value[i]).collect()
+    }
+
+    // removes the words from domains[first] that have no support in domains[second], returns true if anything was removed
+    fn revise(&self, domains: &mut [Vec<usize>], first: usize, second: usize) -> bool
+    {
+        let crossing = &self.crossings[first][&second];
+        let supported = domains[second].iter()
+            .map(|&w| self.letters(w, crossing.iter().map(|(_, j)| *j)))
+            .collect::<HashSet<_>>();
+
+        let old_len = domains[first].len();
+        let retained = domains[first].iter()
+            .copied()
+            .filter(|&w| supported.contains(&self.letters(w, crossing.iter().map(|(i, _)| *i))))
+            .collect::<Vec<_>>();
+        domains[first] = retained;
+        old_len != domains[first].len()
+    }
+
+    fn make_arc_consistent(&self, domains: &mut [Vec<usize>], mut queue: VecDeque<(usize, usize)>) -> bool
+    {
+        while let Some((first, second)) = queue.pop_front()
+        {
+            if self.revise(domains, first, second)
+            {
+                if domains[first].is_empty() { return false; }
+                queue.extend(self.crossings[first].keys().filter(|&&other| other != second).map(|&other| (other, first)));
+            }
+        }
+        true
+    }
+
+    fn solve(&self, domains: Vec<Vec<usize>>, assigned: &mut Vec<Option<usize>>) -> bool
+    {
+        // most constrained slot first
+        let Some(slot) = (0..domains.len()).filter(|&s| assigned[s].is_none()).min_by_key(|&s| domains[s].len()) else { return true; };
+
+        for &word in domains[slot].iter()
+        {
+            // the same answer can't be used twice
+            if assigned.iter().flatten().any(|&w| self.words[w] == self.words[word]) { continue; }
+
+            let mut new_domains = domains.clone();
+            new_domains[slot] = vec![word];
+            for (other, domain) in new_domains.iter_mut().enumerate()
+            {
+                if other != slot && assigned[other].is_none() { domain.retain(|&w| self.words[w] != self.words[word]); }
+            }
+            if new_domains.iter().any(|d| d.is_empty()) { continue; }
+
+            let queue = self.crossings[slot].keys().map(|&other| (other, slot)).collect();
+            if !self.make_arc_consistent(&mut new_domains, queue) { continue; }
+
+            assigned[slot] = Some(word);
+            if self.solve(new_domains, assigned) { return true; }
+            assigned[slot] = None;
+        }
+        false
+    }
+}
+
+pub fn fill_slots<CharT, StrT>(slots: &[Slot<CharT>], dictionary: &[StrT]) -> Result<Vec<StrT>, FillError>
+where
+    CharT: CrosswordChar,
+    StrT: CrosswordString<CharT>,
+{
+    let mut seen = HashSet::new();
+    let words = dictionary.iter().filter(|w| seen.insert(*w)).collect::<Vec<_>>();
+
+    let mut cells: HashMap<Position, Vec<(usize, usize)>> = HashMap::new();
+    for (s, slot) in slots.iter().enumerate()
+    {
+        for i in 0..slot.value.len()
+        {
+            cells.entry(cell_at(&slot.position, &slot.direction, i)).or_default().push((s, i));
+        }
+    }
+
+    let mut crossings: Vec<HashMap<usize, Crossing>> = vec![HashMap::new(); slots.len()];
+    for slots_in_cell in cells.values()
+    {
+        for (&(s1, i1), &(s2, i2)) in slots_in_cell.iter().flat_map(|a| slots_in_cell.iter().map(move |b| (a, b))).filter(|((s1, _), (s2, _))| s1 != s2)
+        {
+            crossings[s1].entry(s2).or_default().push((i1, i2));
+        }
+    }
+
+    let mut domains = Vec::with_capacity(slots.len());
+    for (s, slot) in slots.iter().enumerate()
+    {
+        let domain = (0..words.len()).filter(|&w|
+        {
+            let value = words[w].as_ref();
+            value.len() == slot.value.len() && value.iter().zip(slot.value.iter()).all(|(ch, fixed)| fixed.as_ref().is_none_or(|f| f == ch))
+        }).collect::<Vec<_>>();
+
+        if domain.is_empty() { return Err(FillError::NoCandidates(s)); }
+        domains.push(domain);
+    }
+
+    let solver = SlotSolver { words, crossings, _char: std::marker::PhantomData };
+
+    let queue = (0..slots.len()).flat_map(|s| solver.crossings[s].keys().map(move |&other| (s, other))).collect();
+    if !solver.make_arc_consistent(&mut domains, queue) { return Err(FillError::Unsatisfiable); }
+
+    let mut assigned = vec![None; slots.len()];
+    if !solver.solve(domains, &mut assigned) { return Err(FillError::Unsatisfiable); }
+
+    Ok(assigned.into_iter().map(|w| solver.words[w.expect("every slot is assigned")].clone()).collect())
+}
@@ -0,0 +1,174 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{document::{CrosswordDocument, CrosswordId, Difficulty}, grid::cell_letters, version::Version};
+
+pub const DEFAULT_PAGE_SIZE: usize = 20;
+pub const MAX_PAGE_SIZE: usize = 100;
+
+// a published crossword as the gallery shows it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GalleryItem
+{
+    pub crossword_id: CrosswordId,
+    pub revision: u32,
+    pub title: String,
+    pub author: String,
+    pub tags: Vec<String>,
+    pub difficulty: Option<Difficulty>,
+    // the estimate from 0 to 100
+    pub difficulty_score: Option<u8>,
+    pub width: i16,
+    pub height: i16,
+    pub words: usize,
+    pub published_at: u64,
+}
+
+impl GalleryItem
+{
+    pub fn new(crossword_id: CrosswordId, version: &Version) -> GalleryItem
+    {
+        let document = &version.document;
+        let (width, height) = grid_size(document);
+        GalleryItem
+        {
+            crossword_id,
+            revision: version.info.number,
+            title: document.title.clone(),
+            author: document.author.clone(),
+            tags: document.tags.clone(),
+            difficulty: document.difficulty,
+            difficulty_score: document.difficulty_score,
+            width,
+            height,
+            words: document.words.len(),
+            published_at: version.info.created_at,
+        }
+    }
+
+    pub fn size(&self) -> i16
+    {
+        self.width.max(self.height)
+    }
+}
+
+pub fn grid_size(document: &CrosswordDocument) -> (i16, i16)
+{
+    let letters = cell_letters(&document.words);
+    let span = |values: Vec<i16>| values.iter().max().zip(values.iter().min()).map_or(0, |(max, min)| max - min + 1);
+    (span(letters.keys().map(|pos| pos.x).collect()), span(letters.keys().map(|pos| pos.y).collect()))
+}
+
+pub fn normalize_tag(tag: &str) -> String
+{
+    tag.trim().to_lowercase()
+}
+
+// the tags written with commas in between
+pub fn parse_tags(text: &str) -> Vec<String>
+{
+    let mut tags = Vec::new();
+    for tag in text.split(',').map(normalize_tag).filter(|t| !t.is_empty())
+    {
+        if !tags.contains(&tag) { tags.push(tag); }
+    }
+    tags
+}
+
+// the lowercase title, author, clues and tags that the query is searched in
+pub fn search_text(document: &CrosswordDocument) -> String
+{
+    [&document.title, &document.author].into_iter()
+        .chain(document.clues.values())
+        .chain(document.tags.iter())
+        .map(|s| s.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GalleryFilter
+{
+    // every word of it has to be in the title, the author, the clues or the tags
+    pub query: String,
+    // all of them are needed
+    pub tags: Vec<String>,
+    pub difficulty: Option<Difficulty>,
+    // the longer side of the grid
+    pub min_size: Option<i16>,
+    pub max_size: Option<i16>,
+    // of the estimated difficulty, the crosswords without an estimate are left out
+    pub min_score: Option<u8>,
+    pub max_score: Option<u8>,
+}
+
+impl GalleryFilter
+{
+    // the text is the search text of the document of the item
+    pub fn matches(&self, item: &GalleryItem, text: &str) -> bool
+    {
+        if self.difficulty.is_some() && item.difficulty != self.difficulty { return false; }
+        if self.min_size.is_some_and(|min| item.size() < min) || self.max_size.is_some_and(|max| item.size() > max) { return false; }
+        if self.min_score.is_some() || self.max_score.is_some()
+        {
+            let Some(score) = item.difficulty_score else { return false; };
+            if self.min_score.is_some_and(|min| score < min) || self.max_score.is_some_and(|max| score > max) { return false; }
+        }
+        if !self.tags.iter().all(|tag| item.tags.contains(&normalize_tag(tag))) { return false; }
+        self.query.split_whitespace().all(|term| text.contains(&term.to_lowercase()))
+    }
+}
+
+// where the next page starts, the items are ordered from the newest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GalleryCursor
+{
+    pub published_at: u64,
+    pub crossword_id: CrosswordId,
+}
+
+impl GalleryCursor
+{
+    fn of(item: &GalleryItem) -> GalleryCursor
+    {
+        GalleryCursor { published_at: item.published_at, crossword_id: item.crossword_id }
+    }
+}
+
+impl fmt::Display for GalleryCursor
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "{}.{}", self.published_at, self.crossword_id)
+    }
+}
+
+impl FromStr for GalleryCursor
+{
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let (published_at, crossword_id) = s.split_once('.').ok_or(())?;
+        Ok(GalleryCursor { published_at: published_at.parse().map_err(|_| ())?, crossword_id: crossword_id.parse().map_err(|_| ())? })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GalleryPage
+{
+    pub items: Vec<GalleryItem>,
+    pub next_cursor: Option<String>,
+}
+
+// the items don't have to be sorted, a cursor stays right when crosswords are published in between
+pub fn paginate(mut items: Vec<GalleryItem>, after: Option<GalleryCursor>, limit: usize) -> GalleryPage
+{
+    items.sort_by(|a, b| GalleryCursor::of(b).cmp(&GalleryCursor::of(a)));
+    let mut items = items.into_iter().filter(|item| after.is_none_or(|after| GalleryCursor::of(item) < after)).collect::<Vec<_>>();
+    let limit = limit.clamp(1, MAX_PAGE_SIZE);
+    let next_cursor = (items.len() > limit).then(|| GalleryCursor::of(&items[limit - 1]).to_string());
+    items.truncate(limit);
+    GalleryPage { items, next_cursor }
+}
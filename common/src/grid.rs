@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use crossword_generator::{traits::{CrosswordChar, CrosswordString}, word::{Direction, Position}};
+use serde::{Deserialize, Serialize};
+
+use crate::document::{DocumentWord, WordId};
+
+pub fn cell_at(position: &Position, direction: &Direction, index: usize) -> Position
+{
+    match direction
+    {
+        Direction::Right => Position { x: position.x + index as i16, y: position.y },
+        Direction::Down => Position { x: position.x, y: position.y + index as i16 },
+    }
+}
+
+// the letter of every occupied cell, where the words disagree the first one wins
+pub fn cell_letters<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(words: &[DocumentWord<CharT, StrT>]) -> HashMap<Position, CharT>
+{
+    let mut letters = HashMap::new();
+    for w in words.iter()
+    {
+        for (i, ch) in w.value.as_ref().iter().enumerate()
+        {
+            letters.entry(cell_at(&w.position, &w.direction, i)).or_insert_with(|| ch.clone());
+        }
+    }
+    letters
+}
+
+// the words going through every occupied cell with the index of the cell in the word, like the cell data of the playground
+pub fn cell_words<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(words: &[DocumentWord<CharT, StrT>]) -> HashMap<Position, Vec<(WordId, usize)>>
+{
+    let mut cells = HashMap::<Position, Vec<(WordId, usize)>>::new();
+    for w in words.iter()
+    {
+        for i in 0..w.value.as_ref().len()
+        {
+            cells.entry(cell_at(&w.position, &w.direction, i)).or_default().push((w.id, i));
+        }
+    }
+    cells
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NumberedWord
+{
+    pub id: WordId,
+    pub number: u32,
+    pub position: Position,
+    pub direction: Direction,
+    pub length: usize,
+}
+
+// numbers go in the reading order of the first cells, words that start in the same cell share a number
+pub fn number_words<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(words: &[DocumentWord<CharT, StrT>]) -> Vec<NumberedWord>
+{
+    let mut starts = words.iter().map(|w| (w.position.y, w.position.x)).collect::<Vec<_>>();
+    starts.sort();
+    starts.dedup();
+    let numbers = starts.into_iter().enumerate().map(|(i, start)| (start, i as u32 + 1)).collect::<HashMap<_, _>>();
+
+    let mut numbered = words.iter().map(|w| NumberedWord
+    {
+        id: w.id,
+        number: numbers[&(w.position.y, w.position.x)],
+        position: w.position.clone(),
+        direction: w.direction.clone(),
+        length: w.value.as_ref().len(),
+    }).collect::<Vec<_>>();
+    numbered.sort_by_key(|w| (w.number, w.direction == Direction::Down));
+    numbered
+}
+
+// groups of the words that are connected through shared cells
+pub fn word_components<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(words: &[DocumentWord<CharT, StrT>]) -> Vec<Vec<WordId>>
+{
+    let mut words_at = HashMap::<Position, Vec<usize>>::new();
+    for (i, w) in words.iter().enumerate()
+    {
+        for j in 0..w.value.as_ref().len()
+        {
+            words_at.entry(cell_at(&w.position, &w.direction, j)).or_default().push(i);
+        }
+    }
+
+    let mut component_of = vec![None::<usize>; words.len()];
+    let mut components = Vec::new();
+    for start in 0..words.len()
+    {
+        if component_of[start].is_some() { continue; }
+
+        let mut component = Vec::new();
+        let mut stack = vec![start];
+        component_of[start] = Some(components.len());
+        while let Some(i) = stack.pop()
+        {
+            component.push(words[i].id);
+            let w = &words[i];
+            for j in 0..w.value.as_ref().len()
+            {
+                for &other in words_at[&cell_at(&w.position, &w.direction, j)].iter()
+                {
+                    if component_of[other].is_none()
+                    {
+                        component_of[other] = Some(components.len());
+                        stack.push(other);
+                    }
+                }
+            }
+        }
+        components.push(component);
+    }
+    components
+}
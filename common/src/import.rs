@@ -0,0 +1,196 @@
+use std::fmt;
+
+use crossword_generator::{placed_word::PlacedWord, word::{Direction, Position}};
+use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::alphabet::{Alphabet, AlphabetError, Tile};
+
+pub type ImportedWord = PlacedWord<Tile, Vec<Tile>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ImportFormat
+{
+    // rows of letters, '#' (or a space) for the blocks
+    Grid,
+    // "WORD x y R/D" on every line
+    WordList,
+}
+
+impl ImportFormat
+{
+    // grids have no numbers, so a coordinate after the first word of the first line means a word list
+    pub fn detect(text: &str) -> ImportFormat
+    {
+        let first_line = text.lines().find(|line| !line.trim().is_empty()).unwrap_or_default();
+        let is_word_list = first_line.split_whitespace().nth(1).is_some_and(|field| field.parse::<i16>().is_ok());
+        if is_word_list { ImportFormat::WordList } else { ImportFormat::Grid }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ImportErrorKind
+{
+    Empty,
+    UnknownTile { grapheme: String },
+    WrongFieldCount { found: usize },
+    InvalidCoordinate { value: String },
+    InvalidDirection { value: String },
+}
+
+// lines and columns start from 1, the columns are counted in graphemes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportDiagnostic
+{
+    pub line: usize,
+    pub column: usize,
+    #[serde(flatten)]
+    pub kind: ImportErrorKind,
+}
+
+impl fmt::Display for ImportDiagnostic
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match &self.kind
+        {
+            ImportErrorKind::Empty => write!(f, "there are no words"),
+            ImportErrorKind::UnknownTile { grapheme } => write!(f, "\"{}\" is not in the alphabet", grapheme),
+            ImportErrorKind::WrongFieldCount { found } => write!(f, "expected \"WORD x y R/D\", found {} fields", found),
+            ImportErrorKind::InvalidCoordinate { value } => write!(f, "\"{}\" is not a valid coordinate", value),
+            ImportErrorKind::InvalidDirection { value } => write!(f, "\"{}\" is not a direction, use R or D", value),
+        }
+    }
+}
+
+fn parse_direction(value: &str) -> Option<Direction>
+{
+    match value.to_lowercase().as_str()
+    {
+        "r" | "right" | "a" | "across" => Some(Direction::Right),
+        "d" | "down" => Some(Direction::Down),
+        _ => None,
+    }
+}
+
+// the column in graphemes of the byte offset in the line
+fn column_at(line: &str, byte_offset: usize) -> usize
+{
+    line[..byte_offset].graphemes(true).count() + 1
+}
+
+fn unknown_tile(line: usize, start_column: usize, error: AlphabetError) -> ImportDiagnostic
+{
+    let AlphabetError::UnknownTile { grapheme, offset } = error;
+    ImportDiagnostic { line, column: start_column + offset, kind: ImportErrorKind::UnknownTile { grapheme } }
+}
+
+fn is_block(grapheme: &str) -> bool
+{
+    grapheme == "#" || grapheme.trim().is_empty()
+}
+
+// every run of letters is split into the tiles of the alphabet, so a tile of two letters takes one cell
+fn parse_grid(text: &str, alphabet: &Alphabet) -> Result<Vec<ImportedWord>, Vec<ImportDiagnostic>>
+{
+    let mut rows = Vec::<Vec<Option<Tile>>>::new();
+    let mut diagnostics = Vec::new();
+    for (i, line) in text.lines().enumerate()
+    {
+        let graphemes = line.graphemes(true).collect::<Vec<_>>();
+        let mut row = Vec::new();
+        let mut start = 0;
+        while start < graphemes.len()
+        {
+            if is_block(graphemes[start])
+            {
+                row.push(None);
+                start += 1;
+                continue;
+            }
+            let end = (start..graphemes.len()).find(|&j| is_block(graphemes[j])).unwrap_or(graphemes.len());
+            match alphabet.segment(&graphemes[start..end].concat())
+            {
+                Ok(tiles) => row.extend(tiles.into_iter().map(Some)),
+                Err(error) => diagnostics.push(unknown_tile(i + 1, start + 1, error)),
+            }
+            start = end;
+        }
+        rows.push(row);
+    }
+    if !diagnostics.is_empty() { return Err(diagnostics); }
+
+    let cell = |x: usize, y: usize| rows.get(y).and_then(|row| row.get(x)).cloned().flatten();
+    let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let mut words = Vec::new();
+    for (direction, dx, dy) in [(Direction::Right, 1, 0), (Direction::Down, 0, 1)]
+    {
+        for y in 0..rows.len()
+        {
+            for x in 0..width
+            {
+                // only the runs of at least two letters are words, starting after a block
+                let starts = cell(x, y).is_some() && (x < dx || y < dy || cell(x - dx, y - dy).is_none());
+                if !starts { continue; }
+                let value = (0..).map_while(|k| cell(x + k * dx, y + k * dy)).collect::<Vec<_>>();
+                if value.len() < 2 { continue; }
+                words.push(PlacedWord::new(value, Position { x: x as i16, y: y as i16 }, direction.clone()));
+            }
+        }
+    }
+    Ok(words)
+}
+
+fn parse_word_list(text: &str, alphabet: &Alphabet) -> Result<Vec<ImportedWord>, Vec<ImportDiagnostic>>
+{
+    let mut words = Vec::new();
+    let mut diagnostics = Vec::new();
+    for (i, line) in text.lines().enumerate()
+    {
+        let fields = line.split_whitespace().map(|field| (column_at(line, field.as_ptr() as usize - line.as_ptr() as usize), field)).collect::<Vec<_>>();
+        if fields.is_empty() { continue; }
+        let diagnostic = |column: usize, kind: ImportErrorKind| ImportDiagnostic { line: i + 1, column, kind };
+
+        let [(word_column, word), (x_column, x), (y_column, y), (direction_column, direction)] = fields[..] else
+        {
+            diagnostics.push(diagnostic(fields[0].0, ImportErrorKind::WrongFieldCount { found: fields.len() }));
+            continue;
+        };
+        let value = alphabet.segment(word).map_err(|error| unknown_tile(i + 1, word_column, error));
+        let x = x.parse::<i16>().map_err(|_| diagnostic(x_column, ImportErrorKind::InvalidCoordinate { value: x.to_owned() }));
+        let y = y.parse::<i16>().map_err(|_| diagnostic(y_column, ImportErrorKind::InvalidCoordinate { value: y.to_owned() }));
+        let direction = parse_direction(direction).ok_or_else(|| diagnostic(direction_column, ImportErrorKind::InvalidDirection { value: direction.to_owned() }));
+
+        match (value, x, y, direction)
+        {
+            (Ok(value), Ok(x), Ok(y), Ok(direction)) => words.push(PlacedWord::new(value, Position { x, y }, direction)),
+            (value, x, y, direction) => diagnostics.extend([value.err(), x.err(), y.err(), direction.err()].into_iter().flatten()),
+        }
+    }
+    if diagnostics.is_empty() { Ok(words) } else { Err(diagnostics) }
+}
+
+// all the problems of the text are reported at once
+pub fn import_text(text: &str, format: ImportFormat, alphabet: &Alphabet) -> Result<Vec<ImportedWord>, Vec<ImportDiagnostic>>
+{
+    let words = match format
+    {
+        ImportFormat::Grid => parse_grid(text, alphabet)?,
+        ImportFormat::WordList => parse_word_list(text, alphabet)?,
+    };
+    if words.is_empty() { return Err(vec![ImportDiagnostic { line: 1, column: 1, kind: ImportErrorKind::Empty }]); }
+    Ok(words)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportRequest
+{
+    pub text: String,
+    // found from the text when it isn't given
+    #[serde(default)]
+    pub format: Option<ImportFormat>,
+    #[serde(default = "Alphabet::english")]
+    pub alphabet: Alphabet,
+}
@@ -0,0 +1,20 @@
+pub mod alphabet;
+pub mod analysis;
+pub mod difficulty;
+pub mod dictionary;
+pub mod document;
+pub mod duplicates;
+pub mod export;
+pub mod fill;
+pub mod gallery;
+pub mod grid;
+pub mod import;
+pub mod print;
+pub mod room;
+pub mod schema;
+pub mod search;
+pub mod solve;
+pub mod stats;
+pub mod transform;
+pub mod validation;
+pub mod version;
@@ -0,0 +1,235 @@
+use crossword_generator::word::{Direction, Position};
+use serde::{Deserialize, Serialize};
+
+use crate::{alphabet::Tile, document::CrosswordDocument, grid::{cell_letters, number_words, NumberedWord}, solve::PuzzleClue};
+
+// all the sizes are in millimeters
+pub const MARGIN: f32 = 15.0;
+pub const HEADER_HEIGHT: f32 = 20.0;
+pub const COLUMN_GAP: f32 = 8.0;
+const MAX_CELL_SIZE: f32 = 9.0;
+pub const LINE_HEIGHT: f32 = 5.0;
+// the average width of a character of the clue font
+const CHAR_WIDTH: f32 = 1.9;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PageSize
+{
+    #[default]
+    A4,
+    Letter,
+}
+
+impl PageSize
+{
+    pub fn all() -> [PageSize; 2]
+    {
+        [PageSize::A4, PageSize::Letter]
+    }
+
+    pub fn name(&self) -> &'static str
+    {
+        match self
+        {
+            PageSize::A4 => "A4",
+            PageSize::Letter => "Letter",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<PageSize>
+    {
+        PageSize::all().into_iter().find(|s| s.name().eq_ignore_ascii_case(name))
+    }
+
+    // width and height of the portrait page
+    pub fn size(&self) -> (f32, f32)
+    {
+        match self
+        {
+            PageSize::A4 => (210.0, 297.0),
+            PageSize::Letter => (215.9, 279.4),
+        }
+    }
+
+    pub fn content_width(&self) -> f32
+    {
+        self.size().0 - 2.0 * MARGIN
+    }
+
+    pub fn content_height(&self) -> f32
+    {
+        self.size().1 - 2.0 * MARGIN
+    }
+
+    pub fn column_width(&self) -> f32
+    {
+        (self.content_width() - COLUMN_GAP) / 2.0
+    }
+
+    pub fn chars_per_line(&self) -> usize
+    {
+        ((self.column_width() / CHAR_WIDTH) as usize).max(1)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrintCell
+{
+    // counted from the top left cell of the grid
+    pub position: Position,
+    pub number: Option<u32>,
+    pub tile: Tile,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClueLine
+{
+    Heading(Direction),
+    Clue(PuzzleClue),
+}
+
+// the clues that fit on one page, split in two columns
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CluePage
+{
+    pub columns: [Vec<ClueLine>; 2],
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrintLayout
+{
+    pub title: String,
+    pub author: String,
+    pub width: i16,
+    pub height: i16,
+    pub cells: Vec<PrintCell>,
+    pub clues: Vec<PuzzleClue>,
+}
+
+impl PrintLayout
+{
+    // the grid is moved so the top left cell is at (0, 0), the numbers are the ones of the solvers
+    pub fn new(document: &CrosswordDocument) -> PrintLayout
+    {
+        let letters = cell_letters(&document.words);
+        let min_x = letters.keys().map(|pos| pos.x).min().unwrap_or_default();
+        let min_y = letters.keys().map(|pos| pos.y).min().unwrap_or_default();
+        let normalize = |pos: &Position| Position { x: pos.x - min_x, y: pos.y - min_y };
+
+        let clues = number_words(&document.words).into_iter()
+            .map(|word| PuzzleClue { clue: document.clue(&word.id).to_owned(), word: NumberedWord { position: normalize(&word.position), ..word } })
+            .collect::<Vec<_>>();
+
+        let mut cells = letters.into_iter().map(|(pos, tile)|
+        {
+            let position = normalize(&pos);
+            let number = clues.iter().find(|c| c.word.position == position).map(|c| c.word.number);
+            PrintCell { position, number, tile }
+        }).collect::<Vec<_>>();
+        cells.sort_by_key(|c| (c.position.y, c.position.x));
+
+        PrintLayout
+        {
+            title: document.title.clone(),
+            author: document.author.clone(),
+            width: cells.iter().map(|c| c.position.x + 1).max().unwrap_or_default(),
+            height: cells.iter().map(|c| c.position.y + 1).max().unwrap_or_default(),
+            cells,
+            clues,
+        }
+    }
+
+    // as big as possible, but not bigger than a usual printed cell
+    pub fn cell_size(&self, page_size: PageSize) -> f32
+    {
+        if self.width == 0 || self.height == 0 { return MAX_CELL_SIZE; }
+        let by_width = page_size.content_width() / self.width as f32;
+        let by_height = (page_size.content_height() - HEADER_HEIGHT) * 0.6 / self.height as f32;
+        by_width.min(by_height).min(MAX_CELL_SIZE)
+    }
+
+    pub fn grid_height(&self, page_size: PageSize) -> f32
+    {
+        self.cell_size(page_size) * self.height as f32
+    }
+
+    // the first page has the title and the grid above the clues, the next ones only the clues
+    pub fn clue_pages(&self, page_size: PageSize) -> Vec<CluePage>
+    {
+        let line_count = |line: &ClueLine| match line
+        {
+            ClueLine::Heading(_) => 2,
+            ClueLine::Clue(c) => wrap_text(&clue_text(c), page_size.chars_per_line()).len(),
+        };
+
+        let mut lines = Vec::new();
+        for direction in [Direction::Right, Direction::Down]
+        {
+            let clues = self.clues.iter().filter(|c| c.word.direction == direction).cloned().collect::<Vec<_>>();
+            if clues.is_empty() { continue; }
+            lines.push(ClueLine::Heading(direction));
+            lines.extend(clues.into_iter().map(ClueLine::Clue));
+        }
+
+        let first_page_lines = ((page_size.content_height() - HEADER_HEIGHT - self.grid_height(page_size) - LINE_HEIGHT) / LINE_HEIGHT).max(0.0) as usize;
+        let page_lines = (page_size.content_height() / LINE_HEIGHT) as usize;
+
+        let available = |page: usize| if page == 0 { first_page_lines } else { page_lines };
+
+        let mut pages = vec![CluePage::default()];
+        let mut column = 0;
+        let mut used = 0;
+        for line in lines
+        {
+            let count = line_count(&line);
+            // a heading isn't left alone at the bottom of a column
+            let needed = if matches!(line, ClueLine::Heading(_)) { count + 1 } else { count };
+            // a clue longer than a whole column still goes at the top of one
+            while used + needed > available(pages.len() - 1) && !(used == 0 && pages.len() > 1)
+            {
+                used = 0;
+                column += 1;
+                if column == 2
+                {
+                    pages.push(CluePage::default());
+                    column = 0;
+                }
+            }
+            pages.last_mut().unwrap().columns[column].push(line);
+            used += count;
+        }
+        pages
+    }
+}
+
+pub fn clue_text(clue: &PuzzleClue) -> String
+{
+    format!("{}. {} ({})", clue.word.number, clue.clue, clue.word.length)
+}
+
+// breaks the text at the spaces, a word longer than the line is broken at the width
+pub fn wrap_text(text: &str, chars_per_line: usize) -> Vec<String>
+{
+    let chars_per_line = chars_per_line.max(1);
+    let mut lines = Vec::<String>::new();
+    for word in text.split_whitespace()
+    {
+        let chars = word.chars().collect::<Vec<_>>();
+        if chars.len() > chars_per_line
+        {
+            lines.extend(chars.chunks(chars_per_line).map(|chunk| chunk.iter().collect::<String>()));
+            continue;
+        }
+        match lines.last_mut()
+        {
+            Some(line) if line.chars().count() + 1 + word.chars().count() <= chars_per_line =>
+            {
+                line.push(' ');
+                line.push_str(word);
+            }
+            _ => lines.push(word.to_owned()),
+        }
+    }
+    if lines.is_empty() { lines.push(String::new()); }
+    lines
+}
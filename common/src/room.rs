@@ -0,0 +1,172 @@
+use std::{fmt, str::FromStr};
+
+use crossword_generator::word::Position;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{alphabet::Tile, document::CrosswordId, solve::{CellEntry, Puzzle}, stats::UserId};
+
+// every player gets the next color for the cursor, the colors repeat after the last one
+pub const PLAYER_COLORS: [&str; 8] = ["#e6194b", "#3cb44b", "#4363d8", "#f58231", "#911eb4", "#42d4f4", "#f032e6", "#9a6324"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PlayerId(Uuid);
+
+// a random id is not a default value, so there is no Default
+#[allow(clippy::new_without_default)]
+impl PlayerId
+{
+    pub fn new() -> PlayerId
+    {
+        PlayerId(Uuid::new_v4())
+    }
+}
+
+impl fmt::Display for PlayerId
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "{}", self.0.simple())
+    }
+}
+
+impl FromStr for PlayerId
+{
+    type Err = uuid::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        Uuid::parse_str(s).map(PlayerId)
+    }
+}
+
+// only the joining client gets it, the actions of a player need it
+// the player id is sent to everyone and only tells the players apart
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PlayerToken(Uuid);
+
+impl PlayerToken
+{
+    pub fn generate() -> PlayerToken
+    {
+        PlayerToken(Uuid::new_v4())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RoomMode
+{
+    // everyone fills the same grid
+    #[default]
+    Coop,
+    // everyone has an own grid, only the progress is shared
+    Race,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Player
+{
+    pub id: PlayerId,
+    pub name: String,
+    pub color: String,
+    pub progress: f32,
+    pub finish_time_ms: Option<u64>,
+    pub cursor: Option<Position>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreateRoomRequest
+{
+    pub crossword_id: CrosswordId,
+    // must be a published version
+    pub revision: u32,
+    pub mode: RoomMode,
+}
+
+// the room of a solve link, always a race
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SolveRoomRequest
+{
+    pub crossword_id: CrosswordId,
+    pub revision: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreateRoomResponse
+{
+    pub code: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JoinRoomRequest
+{
+    pub name: String,
+    pub user_id: UserId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoomSnapshot
+{
+    pub code: String,
+    pub mode: RoomMode,
+    pub puzzle: Puzzle,
+    pub players: Vec<Player>,
+    // the shared grid in the co-op mode, the own grid of the player in the race mode
+    pub entries: Vec<CellEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JoinRoomResponse
+{
+    pub player_id: PlayerId,
+    pub token: PlayerToken,
+    pub room: RoomSnapshot,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum PlayerAction
+{
+    SetCell { position: Position, tile: Option<Tile> },
+    MoveCursor { position: Position },
+    // marks the wrong cells, counted in the solve stats
+    Check,
+    // fills the cell with the right letter, counted in the solve stats
+    Reveal { position: Position },
+    // sent now and then while the room is open, the players that stop sending anything are dropped
+    Heartbeat,
+    Leave,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerActionRequest
+{
+    pub token: PlayerToken,
+    pub action: PlayerAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ActionOutcome
+{
+    Done,
+    Checked { wrong: Vec<Position> },
+    Revealed { position: Position, tile: Tile },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum RoomEvent
+{
+    PlayerJoined { player: Player },
+    PlayerLeft { player_id: PlayerId },
+    // only sent in the co-op mode, race grids stay private
+    CellChanged { player_id: PlayerId, position: Position, tile: Option<Tile> },
+    CursorMoved { player_id: PlayerId, position: Position },
+    ProgressChanged { player_id: PlayerId, progress: f32 },
+    // the grid is full, but not right
+    Incorrect { player_id: PlayerId },
+    Solved { player_id: PlayerId, time_ms: u64 },
+}
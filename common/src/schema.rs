@@ -0,0 +1,139 @@
+use std::fmt;
+
+use serde_json::{json, Map, Value};
+
+use crate::{duplicates::DuplicateSettings, version::CrosswordRecord};
+
+// the version of the stored records, every change of the format adds a migration to the end of MIGRATIONS
+// 0: only the document, before the versions
+// 1: the record with the draft and the versions, the words can't be locked and there are no groups
+// 2: the record has the schema version, the words are locked or not and the documents have groups
+// 3: the documents have tags and a difficulty
+// 4: the documents have an estimated difficulty score
+// 5: the documents have the duplicate settings they are validated with
+pub const SCHEMA_VERSION: u32 = 5;
+
+type Migration = fn(Value) -> Result<Value, MigrationError>;
+
+// the migration at index i takes the version i to i + 1
+const MIGRATIONS: [Migration; SCHEMA_VERSION as usize] = [wrap_in_record, add_locks_and_groups, add_tags_and_difficulty, add_difficulty_score, add_duplicate_settings];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MigrationError
+{
+    // written by a newer server
+    TooNew(u32),
+    Malformed(String),
+}
+
+impl fmt::Display for MigrationError
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        match self
+        {
+            MigrationError::TooNew(version) => write!(f, "schema version {} is newer than {}", version, SCHEMA_VERSION),
+            MigrationError::Malformed(message) => write!(f, "malformed record: {}", message),
+        }
+    }
+}
+
+fn object_mut<'a>(value: &'a mut Value, what: &str) -> Result<&'a mut Map<String, Value>, MigrationError>
+{
+    value.as_object_mut().ok_or_else(|| MigrationError::Malformed(format!("{} is not an object", what)))
+}
+
+// the versions before 2 didn't write it, a record is told apart from a document by the draft
+pub fn schema_version(value: &Value) -> u32
+{
+    match value.get("schema_version").and_then(Value::as_u64)
+    {
+        Some(version) => version as u32,
+        None if value.get("draft").is_some() => 1,
+        None => 0,
+    }
+}
+
+fn wrap_in_record(document: Value) -> Result<Value, MigrationError>
+{
+    Ok(json!({ "draft": document, "draft_saved_at": 0, "versions": [] }))
+}
+
+// changes the draft and the documents of all the versions the same way
+fn migrate_documents(mut record: Value, migrate_document: impl Fn(&mut Map<String, Value>) -> Result<(), MigrationError>) -> Result<Value, MigrationError>
+{
+    let fields = object_mut(&mut record, "the record")?;
+    let draft = fields.get_mut("draft").ok_or_else(|| MigrationError::Malformed("there is no draft".to_owned()))?;
+    migrate_document(object_mut(draft, "the draft")?)?;
+    for version in fields.get_mut("versions").and_then(Value::as_array_mut).into_iter().flatten()
+    {
+        if let Some(document) = version.get_mut("document") { migrate_document(object_mut(document, "the document of a version")?)?; }
+    }
+    Ok(record)
+}
+
+fn add_locks_and_groups(record: Value) -> Result<Value, MigrationError>
+{
+    migrate_documents(record, |document|
+    {
+        document.entry("groups").or_insert_with(|| json!([]));
+        for word in document.get_mut("words").and_then(Value::as_array_mut).into_iter().flatten()
+        {
+            object_mut(word, "a word")?.entry("locked").or_insert(Value::Bool(false));
+        }
+        Ok(())
+    })
+}
+
+fn add_tags_and_difficulty(record: Value) -> Result<Value, MigrationError>
+{
+    migrate_documents(record, |document|
+    {
+        document.entry("tags").or_insert_with(|| json!([]));
+        document.entry("difficulty").or_insert(Value::Null);
+        Ok(())
+    })
+}
+
+// the score needs the dictionary, it's estimated again when the document is saved
+fn add_difficulty_score(record: Value) -> Result<Value, MigrationError>
+{
+    migrate_documents(record, |document|
+    {
+        document.entry("difficulty_score").or_insert(Value::Null);
+        Ok(())
+    })
+}
+
+// the older documents were only checked for the same answer twice, which is what the default settings do
+fn add_duplicate_settings(record: Value) -> Result<Value, MigrationError>
+{
+    let settings = serde_json::to_value(DuplicateSettings::default()).map_err(|e| MigrationError::Malformed(e.to_string()))?;
+    migrate_documents(record, |document|
+    {
+        document.entry("duplicate_settings").or_insert_with(|| settings.clone());
+        Ok(())
+    })
+}
+
+// runs the migrations from the version of the value up to the current one
+pub fn migrate(mut value: Value) -> Result<Value, MigrationError>
+{
+    let version = schema_version(&value);
+    if version > SCHEMA_VERSION { return Err(MigrationError::TooNew(version)); }
+    for migration in MIGRATIONS[version as usize..].iter()
+    {
+        value = migration(value)?;
+    }
+    object_mut(&mut value, "the record")?.insert("schema_version".to_owned(), SCHEMA_VERSION.into());
+    Ok(value)
+}
+
+// the record of any version, and whether it had to be migrated
+pub fn load_record(json: &str) -> Result<(CrosswordRecord, bool), MigrationError>
+{
+    let value = serde_json::from_str::<Value>(json).map_err(|e| MigrationError::Malformed(e.to_string()))?;
+    let migrated = schema_version(&value) != SCHEMA_VERSION;
+    let record = serde_json::from_value(migrate(value)?).map_err(|e| MigrationError::Malformed(e.to_string()))?;
+    Ok((record, migrated))
+}
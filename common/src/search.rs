@@ -0,0 +1,99 @@
+use std::fmt;
+
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::alphabet::Locale;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SearchMode
+{
+    #[default]
+    Substring,
+    Regex,
+    // the whole word, ? is one cell and * is any number of cells
+    Wildcard,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SearchError
+{
+    InvalidRegex(String),
+}
+
+impl fmt::Display for SearchError
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        match self
+        {
+            SearchError::InvalidRegex(error) => write!(f, "invalid regex: {}", error),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Pattern
+{
+    Substring(String),
+    Regex(Regex),
+    Wildcard(Vec<String>),
+}
+
+// matches the words by their cells, case is folded the way the locale does it
+#[derive(Debug, Clone)]
+pub struct WordMatcher
+{
+    pattern: Pattern,
+    locale: Locale,
+}
+
+impl WordMatcher
+{
+    pub fn new(query: &str, mode: SearchMode, locale: Locale) -> Result<WordMatcher, SearchError>
+    {
+        let pattern = match mode
+        {
+            SearchMode::Substring => Pattern::Substring(locale.fold_case(query)),
+            SearchMode::Regex => Pattern::Regex(RegexBuilder::new(query).case_insensitive(true).build().map_err(|e| SearchError::InvalidRegex(e.to_string()))?),
+            SearchMode::Wildcard => Pattern::Wildcard(locale.fold_case(query).graphemes(true).filter(|g| !g.trim().is_empty()).map(str::to_owned).collect()),
+        };
+        Ok(WordMatcher { pattern, locale })
+    }
+
+    pub fn matches(&self, cells: &[String]) -> bool
+    {
+        match &self.pattern
+        {
+            Pattern::Substring(text) => self.locale.fold_case(&cells.concat()).contains(text.as_str()),
+            Pattern::Regex(regex) => regex.is_match(&cells.concat()),
+            Pattern::Wildcard(pattern) =>
+            {
+                let cells = cells.iter().map(|c| self.locale.fold_case(c).graphemes(true).map(str::to_owned).collect::<Vec<_>>()).collect::<Vec<_>>();
+                wildcard_match(pattern, &cells)
+            }
+        }
+    }
+}
+
+// a cell can have more than one grapheme, a literal has to cover the whole cell
+// matched[i][j] is whether the pattern from i matches the cells from j, filled from the ends
+fn wildcard_match(pattern: &[String], cells: &[Vec<String>]) -> bool
+{
+    let mut matched = vec![vec![false; cells.len() + 1]; pattern.len() + 1];
+    matched[pattern.len()][cells.len()] = true;
+    for i in (0..pattern.len()).rev()
+    {
+        for j in (0..=cells.len()).rev()
+        {
+            matched[i][j] = match pattern[i].as_str()
+            {
+                "?" => j < cells.len() && matched[i + 1][j + 1],
+                "*" => matched[i + 1][j] || (j < cells.len() && matched[i][j + 1]),
+                _ => j < cells.len() && pattern[i..].starts_with(&cells[j]) && matched[i + cells[j].len()][j + 1],
+            };
+        }
+    }
+    matched[0][0]
+}
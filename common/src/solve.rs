@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use crossword_generator::word::Position;
+use serde::{Deserialize, Serialize};
+
+use crate::{alphabet::{Alphabet, Tile}, document::{CrosswordDocument, CrosswordId}, grid::{cell_letters, number_words, NumberedWord}};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PuzzleClue
+{
+    #[serde(flatten)]
+    pub word: NumberedWord,
+    pub clue: String,
+}
+
+// what a solver gets, the layout and the clues without the answers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Puzzle
+{
+    pub crossword_id: CrosswordId,
+    // the published version the puzzle is made from
+    pub revision: u32,
+    pub title: String,
+    pub author: String,
+    pub alphabet: Alphabet,
+    pub cells: Vec<Position>,
+    pub clues: Vec<PuzzleClue>,
+}
+
+impl Puzzle
+{
+    pub fn new(crossword_id: CrosswordId, revision: u32, document: &CrosswordDocument) -> Puzzle
+    {
+        let mut cells = cell_letters(&document.words).into_keys().collect::<Vec<_>>();
+        cells.sort_by_key(|pos| (pos.y, pos.x));
+
+        Puzzle
+        {
+            crossword_id,
+            revision,
+            title: document.title.clone(),
+            author: document.author.clone(),
+            alphabet: document.alphabet.clone(),
+            cells,
+            clues: number_words(&document.words).into_iter().map(|word| PuzzleClue { clue: document.clue(&word.id).to_owned(), word }).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CellEntry
+{
+    pub position: Position,
+    pub tile: Tile,
+}
+
+pub type Entries = HashMap<Position, Tile>;
+
+pub fn entries_from_cells(cells: Vec<CellEntry>) -> Entries
+{
+    cells.into_iter().map(|cell| (cell.position, cell.tile)).collect()
+}
+
+pub fn entries_to_cells(entries: &Entries) -> Vec<CellEntry>
+{
+    entries.iter().map(|(position, tile)| CellEntry { position: position.clone(), tile: tile.clone() }).collect()
+}
+
+// the part of the cells that is filled, not that is correct, so it can be shown to the other players
+pub fn progress(puzzle: &Puzzle, entries: &Entries) -> f32
+{
+    if puzzle.cells.is_empty() { return 0.0; }
+    puzzle.cells.iter().filter(|pos| entries.contains_key(pos)).count() as f32 / puzzle.cells.len() as f32
+}
+
+pub fn is_solved(document: &CrosswordDocument, entries: &Entries) -> bool
+{
+    let letters = cell_letters(&document.words);
+    letters.len() == entries.len() && letters.iter().all(|(pos, tile)| entries.get(pos) == Some(tile))
+}
+
+// the cells that are filled with something else than the answer
+pub fn wrong_entries(document: &CrosswordDocument, entries: &Entries) -> Vec<Position>
+{
+    let letters = cell_letters(&document.words);
+    entries.iter().filter(|(pos, tile)| letters.get(pos) != Some(tile)).map(|(pos, _)| pos.clone()).collect()
+}
+
+// a check without a room, like the one of an embedded puzzle
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckRequest
+{
+    pub revision: u32,
+    pub entries: Vec<CellEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckResponse
+{
+    pub wrong: Vec<Position>,
+    pub solved: bool,
+}
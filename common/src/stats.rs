@@ -0,0 +1,168 @@
+use std::{collections::{BTreeSet, HashMap}, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{document::CrosswordId, room::RoomMode};
+
+pub const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+// grid sizes in cells, the average times are only comparable between similar grids
+const SIZE_BUCKETS: [(usize, usize); 4] = [(1, 25), (26, 80), (81, 200), (201, usize::MAX)];
+
+// there are no accounts, the browser keeps a random id and sends it when joining a room
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct UserId(Uuid);
+
+// a random id is not a default value, so there is no Default
+#[allow(clippy::new_without_default)]
+impl UserId
+{
+    pub fn new() -> UserId
+    {
+        UserId(Uuid::new_v4())
+    }
+}
+
+impl fmt::Display for UserId
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "{}", self.0.simple())
+    }
+}
+
+impl FromStr for UserId
+{
+    type Err = uuid::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        Uuid::parse_str(s).map(UserId)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SolveAttempt
+{
+    pub user_id: UserId,
+    pub name: String,
+    pub crossword_id: CrosswordId,
+    #[serde(default)]
+    pub revision: u32,
+    pub mode: RoomMode,
+    // milliseconds since the unix epoch
+    pub started_at: u64,
+    pub time_ms: u64,
+    pub checks: u32,
+    pub reveals: u32,
+    pub completed: bool,
+    // the number of cells in the grid
+    pub size: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeaderboardEntry
+{
+    pub rank: usize,
+    pub user_id: UserId,
+    pub name: String,
+    pub revision: u32,
+    pub mode: RoomMode,
+    pub time_ms: u64,
+    pub checks: u32,
+    pub reveals: u32,
+    pub finished_at: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SizeStats
+{
+    // the grids with min_size..=max_size cells
+    pub min_size: usize,
+    pub max_size: usize,
+    pub solved: usize,
+    pub average_time_ms: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserStats
+{
+    pub attempts: usize,
+    pub solved: usize,
+    pub solved_without_reveals: usize,
+    // days in a row with at least one solved crossword, the current one ends today or yesterday
+    pub current_streak: u32,
+    pub longest_streak: u32,
+    pub by_size: Vec<SizeStats>,
+}
+
+// the best solve of every user, clean solves rank above the ones with reveals
+// the revisions are different puzzles, only the solves of one are ranked, the latest solved one without a revision
+pub fn leaderboard(attempts: &[SolveAttempt], crossword_id: &CrosswordId, revision: Option<u32>) -> Vec<LeaderboardEntry>
+{
+    let key = |a: &SolveAttempt| (a.reveals, a.time_ms, a.checks);
+
+    let solved = attempts.iter().filter(|a| a.completed && a.crossword_id == *crossword_id).collect::<Vec<_>>();
+    let Some(revision) = revision.or_else(|| solved.iter().map(|a| a.revision).max()) else { return Vec::new(); };
+    let mut best = HashMap::<UserId, &SolveAttempt>::new();
+    for attempt in solved.into_iter().filter(|a| a.revision == revision)
+    {
+        let entry = best.entry(attempt.user_id).or_insert(attempt);
+        if key(attempt) < key(entry) { *entry = attempt; }
+    }
+
+    let mut best = best.into_values().collect::<Vec<_>>();
+    best.sort_by_key(|a| (key(a), a.started_at));
+    best.into_iter().enumerate().map(|(i, a)| LeaderboardEntry
+    {
+        rank: i + 1,
+        user_id: a.user_id,
+        name: a.name.clone(),
+        revision: a.revision,
+        mode: a.mode,
+        time_ms: a.time_ms,
+        checks: a.checks,
+        reveals: a.reveals,
+        finished_at: a.started_at + a.time_ms,
+    }).collect()
+}
+
+// today is counted in days since the unix epoch, like the days of the streaks
+pub fn user_stats(attempts: &[SolveAttempt], user_id: &UserId, today: u64) -> UserStats
+{
+    let attempts = attempts.iter().filter(|a| a.user_id == *user_id).collect::<Vec<_>>();
+    let solved = attempts.iter().filter(|a| a.completed).collect::<Vec<_>>();
+
+    let days = solved.iter().map(|a| (a.started_at + a.time_ms) / DAY_MS).collect::<BTreeSet<_>>();
+    let mut longest_streak = 0;
+    let mut streak = 0;
+    let mut previous = None;
+    for &day in days.iter()
+    {
+        streak = if previous.is_some_and(|p| p + 1 == day) { streak + 1 } else { 1 };
+        longest_streak = longest_streak.max(streak);
+        previous = Some(day);
+    }
+    let current_streak = match previous
+    {
+        Some(last) if last + 1 >= today => streak,
+        _ => 0,
+    };
+
+    let by_size = SIZE_BUCKETS.iter().filter_map(|&(min_size, max_size)|
+    {
+        let times = solved.iter().filter(|a| (min_size..=max_size).contains(&a.size)).map(|a| a.time_ms).collect::<Vec<_>>();
+        (!times.is_empty()).then(|| SizeStats { min_size, max_size, solved: times.len(), average_time_ms: times.iter().sum::<u64>() / times.len() as u64 })
+    }).collect();
+
+    UserStats
+    {
+        attempts: attempts.len(),
+        solved: solved.len(),
+        solved_without_reveals: solved.iter().filter(|a| a.reveals == 0).count(),
+        current_streak,
+        longest_streak,
+        by_size,
+    }
+}
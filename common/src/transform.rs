@@ -0,0 +1,116 @@
+use std::{collections::{HashMap, HashSet}, fmt};
+
+use crossword_generator::{traits::{CrosswordChar, CrosswordString}, word::{Direction, Position}};
+use serde::{Deserialize, Serialize};
+
+use crate::{analysis::Symmetry, document::{DocumentWord, WordId}, grid::cell_at};
+
+// geometric changes of a group of words, all of them are around the bounding box of the group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Transform
+{
+    // swaps x and y, across words become down words
+    Transpose,
+    // left to right
+    MirrorHorizontal,
+    // top to bottom
+    MirrorVertical,
+    Translate(i16, i16),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TransformError
+{
+    // the crossing words wouldn't have the same letter in this cell anymore
+    BrokenCrossing(Position),
+    // the words were edited after the transform, so it can't be undone
+    ChangedSince,
+    // a moved word would leave the coordinates of the grid
+    OutOfGrid,
+}
+
+impl fmt::Display for TransformError
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        match self
+        {
+            TransformError::BrokenCrossing(pos) => write!(f, "the letters wouldn't read correctly, the words crossing at ({}, {}) would disagree", pos.x, pos.y),
+            TransformError::ChangedSince => write!(f, "the words were changed after the last transform"),
+            TransformError::OutOfGrid => write!(f, "the words would be moved out of the grid"),
+        }
+    }
+}
+
+fn conflicting_cells<'a, CharT: CrosswordChar + 'a, StrT: CrosswordString<CharT> + 'a>(words: impl Iterator<Item = &'a DocumentWord<CharT, StrT>>) -> HashSet<Position>
+{
+    let mut letters = HashMap::<Position, HashSet<&CharT>>::new();
+    for w in words
+    {
+        for (i, ch) in w.value.as_ref().iter().enumerate()
+        {
+            letters.entry(cell_at(&w.position, &w.direction, i)).or_default().insert(ch);
+        }
+    }
+    letters.into_iter().filter(|(_, chars)| chars.len() > 1).map(|(pos, _)| pos).collect()
+}
+
+// whether the words of the grid can be swapped for the replacements with the same ids,
+// the cells that already had a conflict before don't block it
+pub fn check_replacement<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(words: &[DocumentWord<CharT, StrT>], replacements: &[DocumentWord<CharT, StrT>]) -> Result<(), TransformError>
+{
+    let replaced = replacements.iter().map(|w| w.id).collect::<HashSet<WordId>>();
+    let before = conflicting_cells(words.iter());
+    let after = conflicting_cells(words.iter().filter(|w| !replaced.contains(&w.id)).chain(replacements.iter()));
+    match after.difference(&before).min_by_key(|pos| (pos.y, pos.x))
+    {
+        Some(pos) => Err(TransformError::BrokenCrossing(pos.clone())),
+        None => Ok(()),
+    }
+}
+
+// the selected words of the grid keep their ids and their letters in reading order,
+// the transform is refused when the moved letters would disagree with any word of the grid
+pub fn transform_words<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(grid: &[DocumentWord<CharT, StrT>], selected: &HashSet<WordId>, transform: Transform) -> Result<Vec<DocumentWord<CharT, StrT>>, TransformError>
+{
+    let words = grid.iter().filter(|w| selected.contains(&w.id)).cloned().collect::<Vec<_>>();
+    let cells = words.iter().flat_map(|w| (0..w.value.as_ref().len()).map(|i| cell_at(&w.position, &w.direction, i))).collect::<Vec<_>>();
+    let min_x = cells.iter().map(|pos| pos.x).min().unwrap_or_default();
+    let max_x = cells.iter().map(|pos| pos.x).max().unwrap_or_default();
+    let min_y = cells.iter().map(|pos| pos.y).min().unwrap_or_default();
+    let max_y = cells.iter().map(|pos| pos.y).max().unwrap_or_default();
+    let center_x2 = (min_x + max_x, min_y + max_y);
+
+    let transformed = words.iter().map(|w|
+    {
+        let mut w = w.clone();
+        let length = w.value.as_ref().len();
+        match transform
+        {
+            Transform::Transpose =>
+            {
+                w.position = Position { x: min_x + w.position.y - min_y, y: min_y + w.position.x - min_x };
+                w.direction = w.direction.opposite();
+            }
+            Transform::MirrorHorizontal => w.position = Symmetry::Horizontal.mirror_start(&w.position, &w.direction, length, center_x2),
+            Transform::MirrorVertical => w.position = Symmetry::Vertical.mirror_start(&w.position, &w.direction, length, center_x2),
+            Transform::Translate(dx, dy) =>
+            {
+                // the last letter has to fit too, the offset is typed in by the user
+                let x = w.position.x.checked_add(dx).ok_or(TransformError::OutOfGrid)?;
+                let y = w.position.y.checked_add(dy).ok_or(TransformError::OutOfGrid)?;
+                let last = length.saturating_sub(1) as i16;
+                match w.direction
+                {
+                    Direction::Right => x.checked_add(last).ok_or(TransformError::OutOfGrid)?,
+                    Direction::Down => y.checked_add(last).ok_or(TransformError::OutOfGrid)?,
+                };
+                w.position = Position { x, y };
+            }
+        }
+        Ok(w)
+    }).collect::<Result<Vec<_>, TransformError>>()?;
+
+    check_replacement(grid, &transformed)?;
+    Ok(transformed)
+}
@@ -0,0 +1,141 @@
+use crossword_generator::{crossword::{WordCompatibilityError, WordCompatibilitySettings}, word::Position};
+use serde::{Deserialize, Serialize};
+
+use crate::{alphabet::{tiles_to_string, Tile}, document::{CrosswordDocument, CrosswordId, WordId}, duplicates::{duplicate_issue, DuplicateIssue}, grid::{cell_letters, word_components}};
+
+// the biggest width and height a crossword can have
+pub const MAX_GRID_SIZE: i16 = 50;
+
+// the rules the editor places the words with, the server checks the same ones
+pub fn compatibility_settings() -> WordCompatibilitySettings
+{
+    WordCompatibilitySettings { corner_by_corner: false, ..WordCompatibilitySettings::default() }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CompatibilityIssue
+{
+    SideBySide,
+    HeadByHead,
+    CornerByCorner,
+    SideByHead,
+    InvalidIntersection,
+}
+
+impl From<&WordCompatibilityError> for CompatibilityIssue
+{
+    fn from(error: &WordCompatibilityError) -> Self
+    {
+        match error
+        {
+            WordCompatibilityError::SideBySide => CompatibilityIssue::SideBySide,
+            WordCompatibilityError::HeadByHead => CompatibilityIssue::HeadByHead,
+            WordCompatibilityError::CornerByCorner => CompatibilityIssue::CornerByCorner,
+            WordCompatibilityError::SideByHead => CompatibilityIssue::SideByHead,
+            WordCompatibilityError::InvalidIntersection => CompatibilityIssue::InvalidIntersection,
+        }
+    }
+}
+
+impl CompatibilityIssue
+{
+    fn description(&self) -> &'static str
+    {
+        match self
+        {
+            CompatibilityIssue::SideBySide => "are side by side",
+            CompatibilityIssue::HeadByHead => "touch head to head",
+            CompatibilityIssue::CornerByCorner => "touch by the corners",
+            CompatibilityIssue::SideByHead => "touch side to head",
+            CompatibilityIssue::InvalidIntersection => "cross with different letters",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ValidationError
+{
+    NoWords,
+    EmptyWord { word: WordId },
+    UnknownTile { word: WordId, tile: Tile },
+    IncompatibleWords { first: WordId, second: WordId, issue: CompatibilityIssue },
+    RepeatedAnswer { first: WordId, second: WordId, issue: DuplicateIssue },
+    MissingClue { word: WordId },
+    Disconnected { components: usize },
+    TooLarge { width: i16, height: i16 },
+}
+
+impl ValidationError
+{
+    // the words are referenced by ids, the document gives them their text back
+    pub fn message(&self, document: &CrosswordDocument) -> String
+    {
+        let word = |id: &WordId| document.words.iter().find(|w| w.id == *id)
+            .map(|w| format!("\"{}\"", tiles_to_string(&w.value)))
+            .unwrap_or_else(|| "a removed word".to_owned());
+
+        match self
+        {
+            ValidationError::NoWords => "The crossword has no words".to_owned(),
+            ValidationError::EmptyWord { .. } => "A word is empty".to_owned(),
+            ValidationError::UnknownTile { word: id, tile } => format!("{} has \"{}\", which is not in the {} alphabet", word(id), tile, document.alphabet.name),
+            ValidationError::IncompatibleWords { first, second, issue } => format!("{} and {} {}", word(first), word(second), issue.description()),
+            ValidationError::RepeatedAnswer { first, second, issue } => format!("{} and {} {}", word(first), word(second), issue.description()),
+            ValidationError::MissingClue { word: id } => format!("{} has no clue", word(id)),
+            ValidationError::Disconnected { components } => format!("The words form {} separate groups instead of one", components),
+            ValidationError::TooLarge { width, height } => format!("The grid is {}x{}, the limit is {}x{}", width, height, MAX_GRID_SIZE, MAX_GRID_SIZE),
+        }
+    }
+}
+
+pub fn validate_document(document: &CrosswordDocument, settings: &WordCompatibilitySettings) -> Vec<ValidationError>
+{
+    if document.words.is_empty() { return vec![ValidationError::NoWords]; }
+
+    let mut errors = Vec::new();
+    for w in document.words.iter()
+    {
+        if w.value.is_empty() { errors.push(ValidationError::EmptyWord { word: w.id }); }
+        if let Some(tile) = w.value.iter().find(|t| !document.alphabet.contains(t))
+        {
+            errors.push(ValidationError::UnknownTile { word: w.id, tile: tile.clone() });
+        }
+    }
+
+    for (i, first) in document.words.iter().enumerate()
+    {
+        for second in document.words[i + 1..].iter()
+        {
+            if let Some(issue) = settings.word_compatibility_issue(first, second)
+            {
+                errors.push(ValidationError::IncompatibleWords { first: first.id, second: second.id, issue: (&issue).into() });
+            }
+            // the house rules the author picked for this crossword
+            if let Some(issue) = duplicate_issue(&first.value, &second.value, &document.duplicate_settings)
+            {
+                errors.push(ValidationError::RepeatedAnswer { first: first.id, second: second.id, issue });
+            }
+        }
+    }
+
+    errors.extend(document.words.iter().filter(|w| document.clue(&w.id).trim().is_empty()).map(|w| ValidationError::MissingClue { word: w.id }));
+
+    let components = word_components(&document.words).len();
+    if components > 1 { errors.push(ValidationError::Disconnected { components }); }
+
+    let cells = cell_letters(&document.words);
+    let size = |coord: fn(&Position) -> i16| cells.keys().map(coord).max().unwrap_or_default() - cells.keys().map(coord).min().unwrap_or_default() + 1;
+    let (width, height) = (size(|pos| pos.x), size(|pos| pos.y));
+    if width > MAX_GRID_SIZE || height > MAX_GRID_SIZE { errors.push(ValidationError::TooLarge { width, height }); }
+
+    errors
+}
+
+// what the server answers to every save of the draft
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaveResponse
+{
+    pub id: CrosswordId,
+    pub errors: Vec<ValidationError>,
+}
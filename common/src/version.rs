@@ -0,0 +1,122 @@
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{document::{CrosswordDocument, CrosswordWord, WordId}, schema::SCHEMA_VERSION};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionInfo
+{
+    pub number: u32,
+    // milliseconds since the unix epoch
+    pub created_at: u64,
+    // published versions are the ones that can be solved
+    pub published: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NewVersionRequest
+{
+    pub publish: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Version
+{
+    #[serde(flatten)]
+    pub info: VersionInfo,
+    pub document: CrosswordDocument,
+}
+
+// everything stored for one crossword, the draft is what the editor autosaves, the versions never change
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrosswordRecord
+{
+    pub schema_version: u32,
+    pub draft: CrosswordDocument,
+    pub draft_saved_at: u64,
+    pub versions: Vec<Version>,
+}
+
+impl CrosswordRecord
+{
+    pub fn new(draft: CrosswordDocument, now: u64) -> CrosswordRecord
+    {
+        CrosswordRecord { schema_version: SCHEMA_VERSION, draft, draft_saved_at: now, versions: Vec::new() }
+    }
+
+    pub fn version(&self, number: u32) -> Option<&Version>
+    {
+        self.versions.iter().find(|v| v.info.number == number)
+    }
+
+    pub fn latest_published(&self) -> Option<&Version>
+    {
+        self.versions.iter().rev().find(|v| v.info.published)
+    }
+
+    pub fn add_version(&mut self, published: bool, now: u64) -> VersionInfo
+    {
+        let info = VersionInfo { number: self.versions.last().map_or(1, |v| v.info.number + 1), created_at: now, published };
+        self.versions.push(Version { info: info.clone(), document: self.draft.clone() });
+        info
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WordChange
+{
+    pub old: CrosswordWord,
+    pub new: CrosswordWord,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClueChange
+{
+    pub id: WordId,
+    pub old: String,
+    pub new: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocumentDiff
+{
+    pub title: Option<(String, String)>,
+    pub author: Option<(String, String)>,
+    pub added: Vec<CrosswordWord>,
+    pub removed: Vec<CrosswordWord>,
+    // moved, turned or respelled words, matched by their ids
+    pub changed: Vec<WordChange>,
+    pub clues: Vec<ClueChange>,
+}
+
+impl DocumentDiff
+{
+    pub fn is_empty(&self) -> bool
+    {
+        *self == DocumentDiff::default()
+    }
+}
+
+pub fn diff_documents(old: &CrosswordDocument, new: &CrosswordDocument) -> DocumentDiff
+{
+    let changed_text = |old: &String, new: &String| (old != new).then(|| (old.clone(), new.clone()));
+    let find = |document: &CrosswordDocument, id: &WordId| document.words.iter().find(|w| w.id == *id).cloned();
+
+    let ids = old.clues.keys().chain(new.clues.keys()).collect::<BTreeSet<_>>();
+
+    DocumentDiff
+    {
+        title: changed_text(&old.title, &new.title),
+        author: changed_text(&old.author, &new.author),
+        added: new.words.iter().filter(|w| find(old, &w.id).is_none()).cloned().collect(),
+        removed: old.words.iter().filter(|w| find(new, &w.id).is_none()).cloned().collect(),
+        changed: old.words.iter()
+            .filter_map(|w| find(new, &w.id).filter(|n| n != w).map(|n| WordChange { old: w.clone(), new: n }))
+            .collect(),
+        clues: ids.into_iter()
+            .filter(|id| old.clue(id) != new.clue(id))
+            .map(|id| ClueChange { id: *id, old: old.clue(id).to_owned(), new: new.clue(id).to_owned() })
+            .collect(),
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::BTreeMap;
+
+use common::{alphabet::Alphabet, analysis::{analyze_words, AnalysisReport, Symmetry}, document::CrosswordWord};
+use crossword_generator::{placed_word::PlacedWord, word::{Direction, Position}};
+
+fn word(value: &str, x: i16, y: i16, direction: Direction) -> CrosswordWord
+{
+    CrosswordWord::from_placed_word(PlacedWord::new(Alphabet::english().segment(value).unwrap(), Position { x, y }, direction))
+}
+
+fn symmetry_score(report: &AnalysisReport, symmetry: Symmetry) -> f32
+{
+    report.symmetry.iter().find(|s| s.symmetry == symmetry).unwrap().score
+}
+
+#[test]
+fn an_empty_grid_has_an_empty_report()
+{
+    let words: Vec<CrosswordWord> = vec![];
+    let report = analyze_words(&words);
+    assert_eq!(report, AnalysisReport::default());
+    assert!(report.connected());
+}
+
+#[test]
+fn empty_words_are_counted_without_cells()
+{
+    let empty = CrosswordWord::from_placed_word(PlacedWord::new(vec![], Position { x: 0, y: 0 }, Direction::Right));
+    assert_eq!(analyze_words(&[empty]), AnalysisReport { words: 1, ..AnalysisReport::default() });
+}
+
+#[test]
+fn a_single_word_is_unchecked_and_symmetric()
+{
+    let report = analyze_words(&[word("CAT", 4, 7, Direction::Right)]);
+    assert_eq!((report.words, report.components, report.cells), (1, 1, 3));
+    assert_eq!((report.checked_cells, report.unchecked_cells), (0, 3));
+    assert_eq!((report.width, report.height), (3, 1));
+    assert_eq!(report.density, 1.0);
+    assert_eq!(report.length_distribution, BTreeMap::from([(3, 1)]));
+    for symmetry in Symmetry::all()
+    {
+        assert_eq!(symmetry_score(&report, symmetry), 1.0);
+    }
+}
+
+#[test]
+fn crossing_words_share_a_checked_cell()
+{
+    let report = analyze_words(&[word("CAT", 0, 0, Direction::Right), word("TOE", 2, 0, Direction::Down), word("EGGS", 2, 2, Direction::Right)]);
+    assert_eq!((report.words, report.components, report.cells), (3, 1, 8));
+    assert_eq!((report.checked_cells, report.unchecked_cells), (2, 6));
+    assert_eq!((report.width, report.height), (6, 3));
+    assert_eq!(report.density, 8.0 / 18.0);
+    assert_eq!(report.length_distribution, BTreeMap::from([(3, 2), (4, 1)]));
+    assert!(report.connected());
+}
+
+#[test]
+fn symmetry_is_the_part_of_the_cells_with_a_filled_mirror()
+{
+    // C A T
+    //     O
+    //     E
+    let report = analyze_words(&[word("CAT", 0, 0, Direction::Right), word("TOE", 2, 0, Direction::Down)]);
+    assert_eq!(symmetry_score(&report, Symmetry::Rotational), 2.0 / 5.0);
+    assert_eq!(symmetry_score(&report, Symmetry::Horizontal), 3.0 / 5.0);
+    assert_eq!(symmetry_score(&report, Symmetry::Vertical), 3.0 / 5.0);
+}
+
+#[test]
+fn separate_words_are_separate_components()
+{
+    let report = analyze_words(&[word("CAT", 0, 0, Direction::Right), word("DOG", 0, 5, Direction::Right)]);
+    assert_eq!(report.components, 2);
+    assert!(!report.connected());
+}
+
+#[test]
+fn the_mirror_of_a_word_starts_at_its_mirrored_end()
+{
+    let center_x2 = (4, 4);
+    assert_eq!(Symmetry::Rotational.mirror_start(&Position { x: 0, y: 0 }, &Direction::Right, 3, center_x2), Position { x: 2, y: 4 });
+    assert_eq!(Symmetry::Horizontal.mirror_start(&Position { x: 0, y: 1 }, &Direction::Right, 3, center_x2), Position { x: 2, y: 1 });
+    assert_eq!(Symmetry::Vertical.mirror_start(&Position { x: 1, y: 0 }, &Direction::Down, 2, center_x2), Position { x: 1, y: 3 });
+}
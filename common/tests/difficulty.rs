@@ -0,0 +1,95 @@
+use common::{alphabet::{Alphabet, Locale, Tile}, difficulty::{estimate_difficulty, DifficultyEstimate, WordFrequencies}, document::{CrosswordDocument, CrosswordWord, Difficulty}};
+use crossword_generator::{placed_word::PlacedWord, word::{Direction, Position}};
+
+fn word(value: &str, x: i16, y: i16, direction: Direction) -> CrosswordWord
+{
+    CrosswordWord::from_placed_word(PlacedWord::new(Alphabet::english().segment(value).unwrap(), Position { x, y }, direction))
+}
+
+fn document(words: Vec<CrosswordWord>, clue: &str) -> CrosswordDocument
+{
+    let clues = words.iter().map(|w| (w.id, clue.to_owned())).collect();
+    CrosswordDocument { alphabet: Alphabet::english(), words, clues, ..CrosswordDocument::default() }
+}
+
+fn assert_close(actual: f32, expected: f32)
+{
+    assert!((actual - expected).abs() < 1e-6, "{} isn't {}", actual, expected);
+}
+
+#[test]
+fn an_empty_grid_scores_zero()
+{
+    let estimate = estimate_difficulty(&document(vec![], ""), &WordFrequencies::new(Locale::English, &["cat"]));
+    assert_eq!(estimate, DifficultyEstimate::default());
+    assert_eq!(estimate.difficulty(), Difficulty::Easy);
+}
+
+#[test]
+fn without_a_dictionary_the_words_and_the_letters_are_average()
+{
+    let estimate = estimate_difficulty(&document(vec![word("CAT", 0, 0, Direction::Right)], ""), &WordFrequencies::default());
+    let factors = estimate.factors;
+    assert_eq!((factors.unknown_words, factors.letter_rarity), (0.5, 0.5));
+    // a single word has no crossings, and a missing clue is the hardest one
+    assert_eq!((factors.unchecked_cells, factors.word_length, factors.clues), (1.0, 0.0, 1.0));
+    assert_eq!(estimate.difficulty(), Difficulty::Medium);
+}
+
+#[test]
+fn known_crossing_words_with_plain_clues_are_easy()
+{
+    let frequencies = WordFrequencies::new(Locale::English, &["cat", "toe"]);
+    let estimate = estimate_difficulty(&document(vec![word("CAT", 0, 0, Direction::Right), word("TOE", 2, 0, Direction::Down)], "A small pet that purrs"), &frequencies);
+    let factors = estimate.factors;
+    assert_eq!((factors.unknown_words, factors.word_length, factors.clues), (0.0, 0.0, 0.0));
+    // T is the most common letter, the other ones are half as common
+    assert_close(factors.letter_rarity, 4.0 * 0.5 / 6.0);
+    assert_close(factors.unchecked_cells, 4.0 / 5.0);
+    assert_eq!(estimate.score, 25);
+    assert_eq!(estimate.difficulty(), Difficulty::Easy);
+}
+
+#[test]
+fn the_hardest_grid_scores_100()
+{
+    let frequencies = WordFrequencies::new(Locale::English, &["dog"]);
+    let estimate = estimate_difficulty(&document(vec![word("QUIZZICALLY", 0, 0, Direction::Right)], ""), &frequencies);
+    let factors = estimate.factors;
+    assert_eq!((factors.unknown_words, factors.letter_rarity, factors.unchecked_cells, factors.word_length, factors.clues), (1.0, 1.0, 1.0, 1.0, 1.0));
+    assert_eq!(estimate.score, 100);
+    assert_eq!(estimate.difficulty(), Difficulty::Hard);
+}
+
+#[test]
+fn wordplay_and_terse_clues_count_half_each()
+{
+    let frequencies = WordFrequencies::new(Locale::English, &["cat"]);
+    let clue = |clue: &str| estimate_difficulty(&document(vec![word("CAT", 0, 0, Direction::Right)], clue), &frequencies).factors.clues;
+    assert_eq!(clue("A small pet that purrs"), 0.0);
+    assert_eq!(clue("A pet that might purr?"), 0.5);
+    assert_eq!(clue("Pet"), 0.5);
+    assert_eq!(clue("Purr?"), 1.0);
+    assert_eq!(clue("   "), 1.0);
+}
+
+#[test]
+fn the_dictionary_is_folded_like_the_answers()
+{
+    let turkish = Alphabet::turkish();
+    let frequencies = WordFrequencies::new(Locale::Turkish, &["ılık", "ice cream"]);
+    assert!(frequencies.contains(&turkish.segment("ILIK").unwrap()));
+    assert!(!frequencies.contains(&turkish.segment("İLİK").unwrap()));
+    // the spaces of the dictionary words aren't in the cells
+    assert!(frequencies.contains(&[Tile::new("İ"), Tile::new("C"), Tile::new("E"), Tile::new("C"), Tile::new("R"), Tile::new("E"), Tile::new("A"), Tile::new("M")]));
+}
+
+#[test]
+fn a_tile_of_several_letters_is_as_rare_as_its_rarest_letter()
+{
+    let frequencies = WordFrequencies::new(Locale::Spanish, &["llama", "loca"]);
+    assert_eq!(frequencies.letter_rarity(&Tile::new("A")), 0.0);
+    assert_eq!(frequencies.letter_rarity(&Tile::new("LL")), 0.0);
+    assert_eq!(frequencies.letter_rarity(&Tile::new("CH")), 1.0);
+    assert_close(frequencies.letter_rarity(&Tile::new("C")), 2.0 / 3.0);
+}
@@ -0,0 +1,90 @@
+use common::{alphabet::{Alphabet, Tile}, duplicates::{duplicate_issue, DuplicateIssue, DuplicateSettings}};
+
+fn tiles(alphabet: &Alphabet, text: &str) -> Vec<Tile>
+{
+    alphabet.segment(text).unwrap()
+}
+
+fn issue(first: &str, second: &str, settings: &DuplicateSettings) -> Option<DuplicateIssue>
+{
+    let english = Alphabet::english();
+    duplicate_issue(&tiles(&english, first), &tiles(&english, second), settings)
+}
+
+fn only_contained() -> DuplicateSettings
+{
+    DuplicateSettings { contained_answer: true, ..DuplicateSettings::default() }
+}
+
+fn only_shared_root(root_length: usize) -> DuplicateSettings
+{
+    DuplicateSettings { shared_root: true, root_length, ..DuplicateSettings::default() }
+}
+
+#[test]
+fn the_same_answer_is_reported_by_default()
+{
+    assert_eq!(issue("CAT", "cat", &DuplicateSettings::default()), Some(DuplicateIssue::SameAnswer));
+    assert_eq!(issue("CAT", "DOG", &DuplicateSettings::default()), None);
+    assert_eq!(issue("CAT", "CAT", &DuplicateSettings { same_answer: false, ..DuplicateSettings::default() }), None);
+}
+
+#[test]
+fn the_house_rules_are_off_by_default()
+{
+    assert_eq!(issue("CAT", "CATALOG", &DuplicateSettings::default()), None);
+    assert_eq!(issue("LOVELY", "LOVELESS", &DuplicateSettings::default()), None);
+}
+
+#[test]
+fn contained_answers_are_found_anywhere_in_the_longer_one()
+{
+    assert_eq!(issue("CAT", "CATALOG", &only_contained()), Some(DuplicateIssue::ContainedAnswer));
+    assert_eq!(issue("CATALOG", "ALO", &only_contained()), Some(DuplicateIssue::ContainedAnswer));
+    assert_eq!(issue("LOG", "CATALOG", &only_contained()), Some(DuplicateIssue::ContainedAnswer));
+    assert_eq!(issue("DOG", "CATALOG", &only_contained()), None);
+}
+
+#[test]
+fn shared_roots_need_root_length_equal_tiles()
+{
+    assert_eq!(issue("LOVELY", "LOVELESS", &only_shared_root(5)), Some(DuplicateIssue::SharedRoot));
+    assert_eq!(issue("STATE", "STATUS", &only_shared_root(5)), None);
+    assert_eq!(issue("STATE", "STATUS", &only_shared_root(4)), Some(DuplicateIssue::SharedRoot));
+}
+
+#[test]
+fn words_shorter_than_the_root_have_no_root()
+{
+    assert_eq!(issue("CAT", "CATTLE", &only_shared_root(5)), None);
+    assert_eq!(issue("CATTLE", "CAT", &only_shared_root(5)), None);
+    assert_eq!(issue("CATS", "CATTLE", &only_shared_root(0)), None);
+}
+
+#[test]
+fn empty_answers_are_never_duplicates()
+{
+    let settings = DuplicateSettings { same_answer: true, contained_answer: true, shared_root: true, root_length: 1 };
+    assert_eq!(duplicate_issue::<Tile>(&[], &[], &settings), None);
+    assert_eq!(duplicate_issue(&[], &tiles(&Alphabet::english(), "CAT"), &settings), None);
+}
+
+#[test]
+fn answers_are_compared_after_the_case_folding_of_the_locale()
+{
+    let turkish = Alphabet::turkish();
+    // the dotless ı is upper cased to I and the dotted i to İ
+    assert_eq!(duplicate_issue(&tiles(&turkish, "ılık"), &tiles(&turkish, "ILIK"), &DuplicateSettings::default()), Some(DuplicateIssue::SameAnswer));
+    assert_eq!(duplicate_issue(&tiles(&turkish, "ilik"), &tiles(&turkish, "ILIK"), &DuplicateSettings::default()), None);
+    assert_eq!(duplicate_issue(&tiles(&turkish, "izmir"), &tiles(&turkish, "İZMİR"), &DuplicateSettings::default()), Some(DuplicateIssue::SameAnswer));
+}
+
+#[test]
+fn the_root_is_counted_in_tiles()
+{
+    let spanish = Alphabet::spanish();
+    // LL and CH are one tile each
+    assert_eq!(duplicate_issue(&tiles(&spanish, "llama"), &tiles(&spanish, "llamar"), &only_shared_root(4)), Some(DuplicateIssue::SharedRoot));
+    assert_eq!(duplicate_issue(&tiles(&spanish, "chico"), &tiles(&spanish, "chica"), &only_shared_root(3)), Some(DuplicateIssue::SharedRoot));
+    assert_eq!(duplicate_issue(&tiles(&spanish, "chico"), &tiles(&spanish, "chica"), &only_shared_root(4)), None);
+}
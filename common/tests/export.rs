@@ -0,0 +1,35 @@
+use common::{alphabet::Alphabet, document::{CrosswordDocument, CrosswordWord}, export::{export_text, grid_text, TextExportOptions}, print::PrintLayout};
+use crossword_generator::{placed_word::PlacedWord, word::{Direction, Position}};
+
+fn document(alphabet: Alphabet, words: &[(&str, i16, i16, Direction)]) -> CrosswordDocument
+{
+    let words = words.iter().map(|(value, x, y, direction)| CrosswordWord::from_placed_word(PlacedWord::new(alphabet.segment(value).unwrap(), Position { x: *x, y: *y }, direction.clone()))).collect();
+    CrosswordDocument { alphabet, words, ..Default::default() }
+}
+
+#[test]
+fn single_letter_tiles_have_one_character_per_cell()
+{
+    let layout = PrintLayout::new(&document(Alphabet::english(), &[("CAT", 0, 0, Direction::Right), ("AT", 1, 0, Direction::Down)]));
+    assert_eq!(grid_text(&layout, true), "CAT\n#T#");
+    assert_eq!(grid_text(&layout, false), "___\n#_#");
+}
+
+#[test]
+fn wider_tiles_keep_the_columns_in_line()
+{
+    let layout = PrintLayout::new(&document(Alphabet::spanish(), &[("ALLA", 0, 0, Direction::Right), ("LLA", 1, 0, Direction::Down)]));
+    assert_eq!(grid_text(&layout, true), "A  LL A\n#  A  #");
+}
+
+#[test]
+fn markdown_in_the_clues_is_escaped()
+{
+    let mut document = document(Alphabet::english(), &[("CAT", 0, 0, Direction::Right)]);
+    document.title = "*Pets*".to_owned();
+    let id = document.words[0].id;
+    document.clues.insert(id, "A [pet] with_whiskers".to_owned());
+    let text = export_text(&document, &TextExportOptions::default());
+    assert!(text.starts_with("# \\*Pets\\*\n"));
+    assert!(text.contains("- **1** A \\[pet\\] with\\_whiskers (3)\n"));
+}
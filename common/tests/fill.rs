@@ -0,0 +1,65 @@
+use common::{alphabet::{Alphabet, Tile}, fill::{fill_slots, FillError, Slot}};
+use crossword_generator::{placed_word::PlacedWord, word::{Direction, Position}};
+
+fn slot(x: i16, y: i16, direction: Direction, length: usize) -> Slot<Tile>
+{
+    PlacedWord::new(vec![None; length], Position { x, y }, direction)
+}
+
+fn dictionary(alphabet: &Alphabet, words: &[&str]) -> Vec<Vec<Tile>>
+{
+    words.iter().map(|w| alphabet.segment(w).unwrap()).collect()
+}
+
+fn text(words: &[Vec<Tile>]) -> Vec<String>
+{
+    words.iter().map(|w| w.iter().map(Tile::as_str).collect()).collect()
+}
+
+#[test]
+fn crossing_slots_agree_on_the_shared_cell()
+{
+    let english = Alphabet::english();
+    let slots = [slot(0, 0, Direction::Right, 3), slot(2, 0, Direction::Down, 3)];
+    let filled = fill_slots(&slots, &dictionary(&english, &["dog", "cat", "ace", "tea"])).unwrap();
+    assert_eq!(text(&filled), ["CAT", "TEA"]);
+}
+
+#[test]
+fn fixed_letters_are_kept()
+{
+    let english = Alphabet::english();
+    let mut fixed = slot(0, 0, Direction::Right, 3);
+    fixed.value[0] = Some(Tile::new("D"));
+    let filled = fill_slots(&[fixed], &dictionary(&english, &["cat", "dog"])).unwrap();
+    assert_eq!(text(&filled), ["DOG"]);
+}
+
+#[test]
+fn an_answer_is_used_once()
+{
+    let english = Alphabet::english();
+    let slots = [slot(0, 0, Direction::Right, 3), slot(0, 2, Direction::Right, 3)];
+    assert_eq!(fill_slots(&slots, &dictionary(&english, &["cat", "cat"])), Err(FillError::Unsatisfiable));
+    let filled = fill_slots(&slots, &dictionary(&english, &["cat", "dog"])).unwrap();
+    assert_ne!(filled[0], filled[1]);
+}
+
+#[test]
+fn a_slot_without_candidates_is_reported()
+{
+    let english = Alphabet::english();
+    let slots = [slot(0, 0, Direction::Right, 3), slot(0, 2, Direction::Right, 5)];
+    assert_eq!(fill_slots(&slots, &dictionary(&english, &["cat"])), Err(FillError::NoCandidates(1)));
+}
+
+#[test]
+fn the_dictionary_is_folded_by_the_locale()
+{
+    // the turkish dotted i is upper case İ, not I
+    let turkish = Alphabet::turkish();
+    let mut fixed = slot(0, 0, Direction::Right, 3);
+    fixed.value[1] = Some(Tile::new("İ"));
+    let filled = fill_slots(&[fixed], &dictionary(&turkish, &["kış", "kiş"])).unwrap();
+    assert_eq!(text(&filled), ["KİŞ"]);
+}
@@ -0,0 +1,156 @@
+use common::{document::{CrosswordId, Difficulty}, gallery::{paginate, parse_tags, GalleryCursor, GalleryFilter, GalleryItem, MAX_PAGE_SIZE}};
+
+fn item(published_at: u64) -> GalleryItem
+{
+    GalleryItem
+    {
+        crossword_id: CrosswordId::new(),
+        revision: 1,
+        title: "Pets".to_owned(),
+        author: "Ann".to_owned(),
+        tags: vec!["animals".to_owned(), "easy".to_owned()],
+        difficulty: Some(Difficulty::Easy),
+        difficulty_score: Some(20),
+        width: 5,
+        height: 7,
+        words: 6,
+        published_at,
+    }
+}
+
+// every page until the last one, following the cursors
+fn all_pages(items: &[GalleryItem], limit: usize) -> Vec<Vec<GalleryItem>>
+{
+    let mut pages = Vec::new();
+    let mut after = None;
+    loop
+    {
+        let page = paginate(items.to_vec(), after, limit);
+        pages.push(page.items);
+        match page.next_cursor
+        {
+            Some(cursor) => after = Some(cursor.parse::<GalleryCursor>().unwrap()),
+            None => return pages,
+        }
+    }
+}
+
+#[test]
+fn pages_go_from_the_newest()
+{
+    let items = vec![item(10), item(30), item(20)];
+    let page = paginate(items, None, 2);
+    assert_eq!(page.items.iter().map(|i| i.published_at).collect::<Vec<_>>(), [30, 20]);
+    assert!(page.next_cursor.is_some());
+}
+
+#[test]
+fn items_with_the_same_time_are_on_exactly_one_page()
+{
+    let items = (0..7).map(|_| item(100)).chain([item(200), item(50)]).collect::<Vec<_>>();
+    let pages = all_pages(&items, 2);
+    assert_eq!(pages.iter().map(Vec::len).collect::<Vec<_>>(), [2, 2, 2, 2, 1]);
+
+    assert_eq!(pages.iter().flatten().map(|i| i.published_at).collect::<Vec<_>>(), [200, 100, 100, 100, 100, 100, 100, 100, 50]);
+    let mut seen = pages.iter().flatten().map(|i| i.crossword_id).collect::<Vec<_>>();
+    seen.sort();
+    seen.dedup();
+    assert_eq!(seen.len(), items.len());
+}
+
+#[test]
+fn newly_published_items_dont_move_the_cursor()
+{
+    let items = vec![item(10), item(20), item(30), item(40)];
+    let first = paginate(items.clone(), None, 2);
+    let cursor = first.next_cursor.unwrap().parse::<GalleryCursor>().unwrap();
+
+    let mut newer = items.clone();
+    newer.push(item(50));
+    let second = paginate(newer, Some(cursor), 2);
+    assert_eq!(second.items.iter().map(|i| i.published_at).collect::<Vec<_>>(), [20, 10]);
+}
+
+#[test]
+fn the_last_page_has_no_cursor()
+{
+    let items = vec![item(10), item(20), item(30), item(40)];
+    // a full last page too
+    assert_eq!(all_pages(&items, 2).len(), 2);
+    assert_eq!(all_pages(&items, 4).len(), 1);
+    assert_eq!(paginate(vec![], None, 2), Default::default());
+}
+
+#[test]
+fn the_page_size_is_limited()
+{
+    let items = (0..MAX_PAGE_SIZE as u64 + 5).map(item).collect::<Vec<_>>();
+    assert_eq!(paginate(items.clone(), None, 0).items.len(), 1);
+    assert_eq!(paginate(items, None, 1000).items.len(), MAX_PAGE_SIZE);
+}
+
+#[test]
+fn cursors_are_read_back_and_bad_ones_are_refused()
+{
+    let cursor = GalleryCursor { published_at: 42, crossword_id: CrosswordId::new() };
+    assert_eq!(cursor.to_string().parse::<GalleryCursor>(), Ok(cursor));
+    assert!("42".parse::<GalleryCursor>().is_err());
+    assert!("x.y".parse::<GalleryCursor>().is_err());
+}
+
+#[test]
+fn the_query_needs_every_word()
+{
+    let item = item(0);
+    let text = "pets\nann\na small pet\nanimals";
+    let filter = |query: &str| GalleryFilter { query: query.to_owned(), ..GalleryFilter::default() };
+    assert!(GalleryFilter::default().matches(&item, text));
+    assert!(filter("Small ANN").matches(&item, text));
+    assert!(!filter("small dog").matches(&item, text));
+}
+
+#[test]
+fn every_tag_is_needed()
+{
+    let item = item(0);
+    let filter = |tags: &[&str]| GalleryFilter { tags: tags.iter().map(|t| t.to_string()).collect(), ..GalleryFilter::default() };
+    assert!(filter(&[" Animals "]).matches(&item, ""));
+    assert!(filter(&["animals", "easy"]).matches(&item, ""));
+    assert!(!filter(&["animals", "hard"]).matches(&item, ""));
+}
+
+#[test]
+fn the_difficulty_is_the_one_of_the_author()
+{
+    let filter = |difficulty| GalleryFilter { difficulty: Some(difficulty), ..GalleryFilter::default() };
+    assert!(filter(Difficulty::Easy).matches(&item(0), ""));
+    assert!(!filter(Difficulty::Hard).matches(&item(0), ""));
+    assert!(!filter(Difficulty::Easy).matches(&GalleryItem { difficulty: None, ..item(0) }, ""));
+}
+
+#[test]
+fn the_size_is_the_longer_side()
+{
+    let filter = |min_size, max_size| GalleryFilter { min_size, max_size, ..GalleryFilter::default() };
+    assert!(filter(Some(7), Some(7)).matches(&item(0), ""));
+    assert!(!filter(Some(8), None).matches(&item(0), ""));
+    assert!(!filter(None, Some(6)).matches(&item(0), ""));
+}
+
+#[test]
+fn the_score_bounds_leave_out_the_crosswords_without_a_score()
+{
+    let filter = |min_score, max_score| GalleryFilter { min_score, max_score, ..GalleryFilter::default() };
+    assert!(filter(Some(20), Some(20)).matches(&item(0), ""));
+    assert!(!filter(Some(21), None).matches(&item(0), ""));
+    assert!(!filter(None, Some(19)).matches(&item(0), ""));
+    let unscored = GalleryItem { difficulty_score: None, ..item(0) };
+    assert!(!filter(Some(0), None).matches(&unscored, ""));
+    assert!(GalleryFilter::default().matches(&unscored, ""));
+}
+
+#[test]
+fn tags_are_lowercase_and_once()
+{
+    assert_eq!(parse_tags(" Animals, easy,,ANIMALS , "), ["animals", "easy"]);
+}
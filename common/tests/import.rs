@@ -0,0 +1,97 @@
+use common::{alphabet::{tiles_to_string, Alphabet}, import::{import_text, ImportDiagnostic, ImportErrorKind, ImportFormat, ImportedWord}};
+use crossword_generator::word::Direction;
+
+fn summary(words: &[ImportedWord]) -> Vec<(String, i16, i16, Direction)>
+{
+    words.iter().map(|w| (tiles_to_string(&w.value), w.position.x, w.position.y, w.direction.clone())).collect()
+}
+
+fn diagnostic(line: usize, column: usize, kind: ImportErrorKind) -> ImportDiagnostic
+{
+    ImportDiagnostic { line, column, kind }
+}
+
+#[test]
+fn the_format_is_detected_from_the_first_line()
+{
+    assert_eq!(ImportFormat::detect("\nCAT 0 0 R\n"), ImportFormat::WordList);
+    assert_eq!(ImportFormat::detect("CAT\nA#\n"), ImportFormat::Grid);
+    assert_eq!(ImportFormat::detect("CAT DOG\n"), ImportFormat::Grid);
+}
+
+#[test]
+fn a_grid_gives_the_across_and_the_down_words()
+{
+    let words = import_text("cat\na#o\nr#g", ImportFormat::Grid, &Alphabet::english()).unwrap();
+    assert_eq!(summary(&words), [
+        ("CAT".to_owned(), 0, 0, Direction::Right),
+        ("CAR".to_owned(), 0, 0, Direction::Down),
+        ("TOG".to_owned(), 2, 0, Direction::Down),
+    ]);
+}
+
+#[test]
+fn spaces_are_blocks_and_single_letters_are_not_words()
+{
+    let words = import_text("ox a\n", ImportFormat::Grid, &Alphabet::english()).unwrap();
+    assert_eq!(summary(&words), [("OX".to_owned(), 0, 0, Direction::Right)]);
+}
+
+#[test]
+fn a_tile_of_two_letters_takes_one_cell()
+{
+    let words = import_text("alla\n#a##", ImportFormat::Grid, &Alphabet::spanish()).unwrap();
+    assert_eq!(summary(&words), [("ALLA".to_owned(), 0, 0, Direction::Right), ("LLA".to_owned(), 1, 0, Direction::Down)]);
+    assert_eq!(words[0].value.len(), 3);
+}
+
+#[test]
+fn a_word_list_accepts_the_direction_names()
+{
+    let words = import_text("cat 0 0 R\ntea 2 0 down\n\nox -1 3 across\n", ImportFormat::WordList, &Alphabet::english()).unwrap();
+    assert_eq!(summary(&words), [
+        ("CAT".to_owned(), 0, 0, Direction::Right),
+        ("TEA".to_owned(), 2, 0, Direction::Down),
+        ("OX".to_owned(), -1, 3, Direction::Right),
+    ]);
+}
+
+#[test]
+fn unknown_letters_of_a_grid_are_located()
+{
+    let errors = import_text("cat\n#h3y", ImportFormat::Grid, &Alphabet::english()).unwrap_err();
+    assert_eq!(errors, [diagnostic(2, 3, ImportErrorKind::UnknownTile { grapheme: "3".to_owned() })]);
+}
+
+#[test]
+fn every_problem_of_a_word_list_is_reported()
+{
+    let text = "cat 0 0 R\nd0g 1 1 D\ntea 2\nox x 0 up\n";
+    let errors = import_text(text, ImportFormat::WordList, &Alphabet::english()).unwrap_err();
+    assert_eq!(errors, [
+        diagnostic(2, 2, ImportErrorKind::UnknownTile { grapheme: "0".to_owned() }),
+        diagnostic(3, 1, ImportErrorKind::WrongFieldCount { found: 2 }),
+        diagnostic(4, 4, ImportErrorKind::InvalidCoordinate { value: "x".to_owned() }),
+        diagnostic(4, 8, ImportErrorKind::InvalidDirection { value: "up".to_owned() }),
+    ]);
+}
+
+#[test]
+fn the_columns_are_counted_in_graphemes()
+{
+    let errors = import_text("ԱԲ 0 0 R\nԱ1 0 0 R", ImportFormat::WordList, &Alphabet::armenian()).unwrap_err();
+    assert_eq!(errors, [diagnostic(2, 2, ImportErrorKind::UnknownTile { grapheme: "1".to_owned() })]);
+}
+
+#[test]
+fn a_text_without_words_is_refused()
+{
+    assert_eq!(import_text("#a#\n", ImportFormat::Grid, &Alphabet::english()).unwrap_err(), [diagnostic(1, 1, ImportErrorKind::Empty)]);
+    assert_eq!(import_text("\n\n", ImportFormat::WordList, &Alphabet::english()).unwrap_err(), [diagnostic(1, 1, ImportErrorKind::Empty)]);
+}
+
+#[test]
+fn diagnostics_read_with_their_place()
+{
+    assert_eq!(diagnostic(3, 1, ImportErrorKind::WrongFieldCount { found: 2 }).to_string(), "line 3, column 1: expected \"WORD x y R/D\", found 2 fields");
+}
@@ -0,0 +1,173 @@
+use std::{fs, path::PathBuf};
+
+use common::{alphabet::{tiles_to_string, Alphabet}, document::Difficulty, duplicates::DuplicateSettings, schema::{load_record, migrate, schema_version, MigrationError, SCHEMA_VERSION}, version::CrosswordRecord};
+use serde_json::{json, Value};
+
+// there is a fixture for every schema version that was ever stored
+fn fixture(version: u32) -> String
+{
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(format!("v{}.json", version));
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("no fixture for schema version {} at {}: {}", version, path.display(), e))
+}
+
+fn check_common_content(record: &CrosswordRecord)
+{
+    let draft = &record.draft;
+    assert_eq!(record.schema_version, SCHEMA_VERSION);
+    assert_eq!(draft.title, "Greetings");
+    assert_eq!(draft.author, "Ann");
+    assert_eq!(draft.words.len(), 2);
+    assert_eq!(tiles_to_string(&draft.words[0].value), "HELLO");
+    assert_eq!(tiles_to_string(&draft.words[1].value), "EAT");
+    assert_eq!(draft.clue(&draft.words[0].id), "A greeting");
+    assert_eq!(draft.clue(&draft.words[1].id), "Have a meal");
+}
+
+#[test]
+fn every_version_has_a_fixture_of_its_own()
+{
+    for version in 0..=SCHEMA_VERSION
+    {
+        let value = serde_json::from_str::<Value>(&fixture(version)).unwrap();
+        assert_eq!(schema_version(&value), version);
+    }
+}
+
+#[test]
+fn every_version_loads_as_the_current_one()
+{
+    for version in 0..=SCHEMA_VERSION
+    {
+        let (record, migrated) = load_record(&fixture(version)).unwrap_or_else(|e| panic!("version {}: {}", version, e));
+        assert_eq!(migrated, version != SCHEMA_VERSION);
+        check_common_content(&record);
+    }
+}
+
+#[test]
+fn version_0_gets_a_record_without_versions()
+{
+    let (record, _) = load_record(&fixture(0)).unwrap();
+    assert_eq!(record.draft_saved_at, 0);
+    assert!(record.versions.is_empty());
+    assert!(record.draft.groups.is_empty());
+    assert!(record.draft.words.iter().all(|w| !w.locked));
+}
+
+#[test]
+fn version_1_keeps_the_versions_and_gets_unlocked_words()
+{
+    let (record, _) = load_record(&fixture(1)).unwrap();
+    assert_eq!(record.draft_saved_at, 1760000000000);
+    assert_eq!(record.versions.len(), 1);
+    assert!(record.versions[0].info.published);
+    assert_eq!(record.versions[0].document.words.len(), 1);
+    assert!(record.versions[0].document.groups.is_empty());
+    assert!(record.draft.words.iter().chain(record.versions[0].document.words.iter()).all(|w| !w.locked));
+}
+
+#[test]
+fn version_2_keeps_locks_and_groups()
+{
+    let (record, _) = load_record(&fixture(2)).unwrap();
+    assert!(record.draft.words[0].locked);
+    assert!(!record.draft.words[1].locked);
+    assert_eq!(record.draft.groups.len(), 1);
+    assert_eq!(record.draft.groups[0].words.len(), 2);
+}
+
+#[test]
+fn versions_before_3_get_no_tags_and_no_difficulty()
+{
+    for version in 0..3
+    {
+        let (record, _) = load_record(&fixture(version)).unwrap();
+        assert!(record.draft.tags.is_empty());
+        assert_eq!(record.draft.difficulty, None);
+    }
+}
+
+#[test]
+fn version_3_keeps_tags_and_difficulty()
+{
+    let (record, _) = load_record(&fixture(3)).unwrap();
+    assert_eq!(record.draft.tags, vec!["animals".to_owned(), "kids".to_owned()]);
+    assert_eq!(record.draft.difficulty, Some(Difficulty::Easy));
+}
+
+#[test]
+fn versions_before_4_get_no_difficulty_score()
+{
+    for version in 0..4
+    {
+        let (record, _) = load_record(&fixture(version)).unwrap();
+        assert_eq!(record.draft.difficulty_score, None);
+    }
+}
+
+#[test]
+fn version_4_keeps_the_difficulty_score()
+{
+    let (record, _) = load_record(&fixture(4)).unwrap();
+    assert_eq!(record.draft.difficulty_score, Some(42));
+}
+
+#[test]
+fn versions_before_5_get_the_default_duplicate_settings()
+{
+    for version in 0..5
+    {
+        let (record, _) = load_record(&fixture(version)).unwrap();
+        assert_eq!(record.draft.duplicate_settings, DuplicateSettings::default());
+    }
+}
+
+#[test]
+fn version_5_keeps_the_duplicate_settings()
+{
+    let (record, _) = load_record(&fixture(5)).unwrap();
+    assert_eq!(record.draft.duplicate_settings, DuplicateSettings { same_answer: true, contained_answer: true, shared_root: true, root_length: 4 });
+}
+
+#[test]
+fn migrating_twice_changes_nothing()
+{
+    for version in 0..=SCHEMA_VERSION
+    {
+        let once = migrate(serde_json::from_str(&fixture(version)).unwrap()).unwrap();
+        assert_eq!(migrate(once.clone()).unwrap(), once);
+    }
+}
+
+#[test]
+fn the_current_fixture_is_what_the_server_writes()
+{
+    let text = fixture(SCHEMA_VERSION);
+    let (record, _) = load_record(&text).unwrap();
+    assert_eq!(serde_json::to_string_pretty(&record).unwrap(), text.trim_end());
+    assert_eq!(record.draft.alphabet, Alphabet::english());
+}
+
+#[test]
+fn saved_records_round_trip()
+{
+    let (record, _) = load_record(&fixture(0)).unwrap();
+    let (loaded, migrated) = load_record(&serde_json::to_string(&record).unwrap()).unwrap();
+    assert!(!migrated);
+    assert_eq!(loaded, record);
+}
+
+#[test]
+fn newer_versions_are_refused()
+{
+    let mut value = serde_json::from_str::<Value>(&fixture(SCHEMA_VERSION)).unwrap();
+    value["schema_version"] = json!(SCHEMA_VERSION + 1);
+    assert_eq!(migrate(value), Err(MigrationError::TooNew(SCHEMA_VERSION + 1)));
+}
+
+#[test]
+fn malformed_records_are_refused()
+{
+    assert!(matches!(load_record("[1, 2, 3]"), Err(MigrationError::Malformed(_))));
+    assert!(matches!(load_record("{ \"draft\": 5, \"draft_saved_at\": 0, \"versions\": [] }"), Err(MigrationError::Malformed(_))));
+}
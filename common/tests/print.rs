@@ -0,0 +1,95 @@
+use common::{document::WordId, grid::NumberedWord, print::{wrap_text, ClueLine, PageSize, PrintLayout}, solve::PuzzleClue};
+use crossword_generator::word::{Direction, Position};
+
+fn clue(number: u32, direction: Direction, text: &str) -> PuzzleClue
+{
+    PuzzleClue { word: NumberedWord { id: WordId::new(), number, position: Position { x: 0, y: number as i16 }, direction, length: 3 }, clue: text.to_owned() }
+}
+
+// a layout without a grid, so the first page has room for all but the header of the clues
+fn layout(across: usize, down: usize) -> PrintLayout
+{
+    let clues = (0..across).map(|i| clue(i as u32 + 1, Direction::Right, "Short"))
+        .chain((0..down).map(|i| clue(i as u32 + 1, Direction::Down, "Short")))
+        .collect();
+    PrintLayout { clues, ..PrintLayout::default() }
+}
+
+// the lines of every column of every page
+fn column_lengths(layout: &PrintLayout) -> Vec<[usize; 2]>
+{
+    layout.clue_pages(PageSize::A4).iter().map(|page| [page.columns[0].len(), page.columns[1].len()]).collect()
+}
+
+#[test]
+fn words_are_wrapped_at_the_spaces()
+{
+    assert_eq!(wrap_text("a bb ccc", 4), ["a bb", "ccc"]);
+    assert_eq!(wrap_text("abc de", 6), ["abc de"]);
+    assert_eq!(wrap_text("  spaced   out  ", 20), ["spaced out"]);
+}
+
+#[test]
+fn words_longer_than_the_line_are_broken_at_the_width()
+{
+    assert_eq!(wrap_text("ab abcdefghij cd", 4), ["ab", "abcd", "efgh", "ij", "cd"]);
+    assert_eq!(wrap_text("abcdefgh", 4), ["abcd", "efgh"]);
+    // characters, not bytes
+    assert_eq!(wrap_text("ŞŞŞŞŞ", 2), ["ŞŞ", "ŞŞ", "Ş"]);
+}
+
+#[test]
+fn empty_text_is_one_empty_line()
+{
+    assert_eq!(wrap_text("", 10), [""]);
+    assert_eq!(wrap_text("ab", 0), ["a", "b"]);
+}
+
+#[test]
+fn the_first_column_is_filled_before_the_second()
+{
+    // on A4 the first page has 48 lines in a column without a grid, the heading takes 2
+    assert_eq!(column_lengths(&layout(46, 0)), [[47, 0]]);
+    assert_eq!(column_lengths(&layout(47, 0)), [[47, 1]]);
+}
+
+#[test]
+fn the_clues_go_on_to_the_next_page_at_the_boundary()
+{
+    // the second column of the first page takes 48 more, the next pages have 53 lines in a column
+    assert_eq!(column_lengths(&layout(46 + 48, 0)), [[47, 48]]);
+    assert_eq!(column_lengths(&layout(46 + 48 + 1, 0)), [[47, 48], [1, 0]]);
+    assert_eq!(column_lengths(&layout(46 + 48 + 53 + 1, 0)), [[47, 48], [53, 1]]);
+}
+
+#[test]
+fn a_heading_isnt_left_alone_at_the_bottom_of_a_column()
+{
+    // 2 + 45 lines are used, the heading of the down clues would fit but not its first clue
+    let pages = layout(45, 3).clue_pages(PageSize::A4);
+    assert_eq!(pages.len(), 1);
+    assert_eq!(pages[0].columns[0].len(), 46);
+    assert_eq!(pages[0].columns[1][0], ClueLine::Heading(Direction::Down));
+    assert_eq!(pages[0].columns[1].len(), 4);
+}
+
+#[test]
+fn long_clues_take_several_lines()
+{
+    let mut layout = layout(1, 0);
+    // about 45 characters fit in a column on A4
+    layout.clues[0].clue = "word ".repeat(30);
+    layout.clues.extend((2..=44).map(|i| clue(i, Direction::Right, "Short")));
+    // the long clue takes 4 lines, so 3 short ones less fit in the first column
+    assert_eq!(column_lengths(&layout), [[1 + 1 + 42, 1]]);
+}
+
+#[test]
+fn a_clue_longer_than_a_column_starts_a_column_of_the_next_page()
+{
+    let mut layout = layout(1, 0);
+    layout.clues[0].clue = "word ".repeat(500);
+    let pages = layout.clue_pages(PageSize::A4);
+    assert_eq!(pages.len(), 2);
+    assert!(matches!(pages[1].columns[0][..], [ClueLine::Clue(_)]));
+}
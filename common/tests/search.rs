@@ -0,0 +1,49 @@
+use common::{alphabet::{Alphabet, Locale}, search::{SearchMode, WordMatcher}};
+
+fn cells(alphabet: &Alphabet, word: &str) -> Vec<String>
+{
+    alphabet.segment(word).unwrap().iter().map(ToString::to_string).collect()
+}
+
+fn wildcard(query: &str, locale: Locale) -> WordMatcher
+{
+    WordMatcher::new(query, SearchMode::Wildcard, locale).unwrap()
+}
+
+#[test]
+fn wildcards_cover_cells()
+{
+    let english = Alphabet::english();
+    assert!(wildcard("c?t", Locale::English).matches(&cells(&english, "cat")));
+    assert!(wildcard("c*", Locale::English).matches(&cells(&english, "cat")));
+    assert!(wildcard("*a*", Locale::English).matches(&cells(&english, "cat")));
+    assert!(wildcard("c*t", Locale::English).matches(&cells(&english, "ct")));
+    assert!(!wildcard("c?", Locale::English).matches(&cells(&english, "cat")));
+    assert!(!wildcard("*x*", Locale::English).matches(&cells(&english, "cat")));
+}
+
+#[test]
+fn a_literal_covers_a_whole_cell()
+{
+    let spanish = Alphabet::spanish();
+    assert!(wildcard("?ll?", Locale::Spanish).matches(&cells(&spanish, "alla")));
+    assert!(wildcard("a?a", Locale::Spanish).matches(&cells(&spanish, "alla")));
+    assert!(!wildcard("al?a", Locale::Spanish).matches(&cells(&spanish, "alla")));
+}
+
+#[test]
+fn the_case_is_folded_by_the_locale()
+{
+    let turkish = Alphabet::turkish();
+    assert!(wildcard("i?", Locale::Turkish).matches(&cells(&turkish, "İK")));
+    assert!(!wildcard("ı?", Locale::Turkish).matches(&cells(&turkish, "İK")));
+    assert!(WordMatcher::new("ik", SearchMode::Substring, Locale::Turkish).unwrap().matches(&cells(&turkish, "bİK")));
+}
+
+#[test]
+fn long_patterns_stay_fast()
+{
+    let english = Alphabet::english();
+    let word = cells(&english, &"a".repeat(40));
+    assert!(!wildcard(&format!("{}b", "*a".repeat(20)), Locale::English).matches(&word));
+}
@@ -0,0 +1,89 @@
+use common::{alphabet::{Alphabet, Tile}, document::{CrosswordDocument, CrosswordId, CrosswordWord}, solve::{entries_from_cells, entries_to_cells, is_solved, progress, wrong_entries, Entries, Puzzle}};
+use crossword_generator::{placed_word::PlacedWord, word::{Direction, Position}};
+
+fn word(value: &str, x: i16, y: i16, direction: Direction) -> CrosswordWord
+{
+    CrosswordWord::from_placed_word(PlacedWord::new(Alphabet::english().segment(value).unwrap(), Position { x, y }, direction))
+}
+
+// C A T
+//     O
+//     E
+fn document() -> CrosswordDocument
+{
+    CrosswordDocument { alphabet: Alphabet::english(), words: vec![word("CAT", 0, 0, Direction::Right), word("TOE", 2, 0, Direction::Down)], ..CrosswordDocument::default() }
+}
+
+fn entries(cells: &[(i16, i16, &str)]) -> Entries
+{
+    cells.iter().map(|&(x, y, tile)| (Position { x, y }, Tile::new(tile))).collect()
+}
+
+fn solution() -> Entries
+{
+    entries(&[(0, 0, "C"), (1, 0, "A"), (2, 0, "T"), (2, 1, "O"), (2, 2, "E")])
+}
+
+#[test]
+fn an_empty_puzzle_has_no_progress()
+{
+    let empty = CrosswordDocument::default();
+    let puzzle = Puzzle::new(CrosswordId::new(), 1, &empty);
+    assert_eq!(progress(&puzzle, &Entries::new()), 0.0);
+    assert_eq!(progress(&puzzle, &entries(&[(0, 0, "A")])), 0.0);
+    assert_eq!(wrong_entries(&empty, &Entries::new()), []);
+}
+
+#[test]
+fn the_progress_is_the_part_of_the_filled_cells()
+{
+    let puzzle = Puzzle::new(CrosswordId::new(), 1, &document());
+    assert_eq!(progress(&puzzle, &Entries::new()), 0.0);
+    // wrong letters count too, the other players only see how much is filled
+    assert_eq!(progress(&puzzle, &entries(&[(0, 0, "C"), (1, 0, "X")])), 2.0 / 5.0);
+    // entries outside the grid don't
+    assert_eq!(progress(&puzzle, &entries(&[(0, 0, "C"), (5, 5, "X")])), 1.0 / 5.0);
+    assert_eq!(progress(&puzzle, &solution()), 1.0);
+}
+
+#[test]
+fn only_the_whole_answer_solves_the_puzzle()
+{
+    let document = document();
+    assert!(is_solved(&document, &solution()));
+    assert!(!is_solved(&document, &Entries::new()));
+    assert!(!is_solved(&document, &entries(&[(0, 0, "C"), (1, 0, "A"), (2, 0, "T")])));
+
+    let mut extra = solution();
+    extra.insert(Position { x: 5, y: 5 }, Tile::new("X"));
+    assert!(!is_solved(&document, &extra));
+}
+
+#[test]
+fn a_wrong_letter_doesnt_solve_and_is_reported()
+{
+    let document = document();
+    let mut wrong = solution();
+    wrong.insert(Position { x: 2, y: 1 }, Tile::new("A"));
+    assert!(!is_solved(&document, &wrong));
+    assert_eq!(wrong_entries(&document, &wrong), [Position { x: 2, y: 1 }]);
+    // empty cells aren't wrong, only the filled ones are checked
+    assert_eq!(wrong_entries(&document, &entries(&[(0, 0, "C"), (1, 0, "E")])), [Position { x: 1, y: 0 }]);
+}
+
+#[test]
+fn the_puzzle_has_the_cells_and_the_clues_without_the_answers()
+{
+    let mut document = document();
+    document.clues.insert(document.words[0].id, "Purrs".to_owned());
+    let puzzle = Puzzle::new(CrosswordId::new(), 3, &document);
+    assert_eq!(puzzle.revision, 3);
+    assert_eq!(puzzle.cells, [Position { x: 0, y: 0 }, Position { x: 1, y: 0 }, Position { x: 2, y: 0 }, Position { x: 2, y: 1 }, Position { x: 2, y: 2 }]);
+    assert_eq!(puzzle.clues.iter().map(|c| (c.word.number, c.word.direction.clone(), c.clue.as_str())).collect::<Vec<_>>(), [(1, Direction::Right, "Purrs"), (2, Direction::Down, "")]);
+}
+
+#[test]
+fn entries_are_sent_as_cells()
+{
+    assert_eq!(entries_from_cells(entries_to_cells(&solution())), solution());
+}
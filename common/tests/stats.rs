@@ -0,0 +1,113 @@
+use common::{document::CrosswordId, room::RoomMode, stats::{leaderboard, user_stats, SizeStats, SolveAttempt, UserId, DAY_MS}};
+
+fn attempt(user_id: UserId, crossword_id: CrosswordId, time_ms: u64, checks: u32, reveals: u32) -> SolveAttempt
+{
+    SolveAttempt { user_id, name: user_id.to_string(), crossword_id, revision: 1, mode: RoomMode::Race, started_at: 0, time_ms, checks, reveals, completed: true, size: 20 }
+}
+
+// a solve that ends on the given day
+fn solved_on(user_id: UserId, day: u64) -> SolveAttempt
+{
+    SolveAttempt { started_at: day * DAY_MS, ..attempt(user_id, CrosswordId::new(), 1000, 0, 0) }
+}
+
+#[test]
+fn fewer_reveals_rank_above_a_faster_time_and_the_time_above_the_checks()
+{
+    let crossword = CrosswordId::new();
+    let (revealer, slow, checker) = (UserId::new(), UserId::new(), UserId::new());
+    let attempts = [attempt(revealer, crossword, 1000, 0, 1), attempt(slow, crossword, 5000, 0, 0), attempt(checker, crossword, 4000, 9, 0)];
+
+    let board = leaderboard(&attempts, &crossword, None);
+    assert_eq!(board.iter().map(|e| (e.rank, e.user_id)).collect::<Vec<_>>(), [(1, checker), (2, slow), (3, revealer)]);
+}
+
+#[test]
+fn checks_break_the_ties_of_the_time()
+{
+    let crossword = CrosswordId::new();
+    let (careful, checker) = (UserId::new(), UserId::new());
+    let attempts = [attempt(checker, crossword, 1000, 3, 0), attempt(careful, crossword, 1000, 1, 0)];
+    assert_eq!(leaderboard(&attempts, &crossword, None).iter().map(|e| e.user_id).collect::<Vec<_>>(), [careful, checker]);
+}
+
+#[test]
+fn only_the_best_finished_solve_of_a_user_is_ranked()
+{
+    let crossword = CrosswordId::new();
+    let user = UserId::new();
+    let unfinished = SolveAttempt { completed: false, ..attempt(user, crossword, 10, 0, 0) };
+    let attempts = [attempt(user, crossword, 3000, 0, 0), unfinished, attempt(user, crossword, 2000, 0, 0), attempt(UserId::new(), CrosswordId::new(), 1, 0, 0)];
+
+    let board = leaderboard(&attempts, &crossword, None);
+    assert_eq!(board.len(), 1);
+    assert_eq!((board[0].time_ms, board[0].finished_at), (2000, 2000));
+}
+
+#[test]
+fn the_revisions_are_ranked_apart()
+{
+    let crossword = CrosswordId::new();
+    let (first, second) = (UserId::new(), UserId::new());
+    let attempts = [attempt(first, crossword, 1000, 0, 0), SolveAttempt { revision: 2, ..attempt(second, crossword, 5000, 0, 0) }];
+
+    // the latest solved revision without one
+    assert_eq!(leaderboard(&attempts, &crossword, None).iter().map(|e| (e.user_id, e.revision)).collect::<Vec<_>>(), [(second, 2)]);
+    assert_eq!(leaderboard(&attempts, &crossword, Some(1)).iter().map(|e| (e.user_id, e.revision)).collect::<Vec<_>>(), [(first, 1)]);
+    assert_eq!(leaderboard(&attempts, &crossword, Some(3)), []);
+    assert_eq!(leaderboard(&[], &crossword, None), []);
+}
+
+#[test]
+fn streaks_are_days_in_a_row()
+{
+    let user = UserId::new();
+    let attempts = [solved_on(user, 10), solved_on(user, 11), solved_on(user, 11), solved_on(user, 12), solved_on(user, 20), solved_on(user, 21)];
+
+    let stats = user_stats(&attempts, &user, 21);
+    assert_eq!((stats.current_streak, stats.longest_streak), (2, 3));
+    // the current streak lasts until the end of the next day
+    assert_eq!(user_stats(&attempts, &user, 22).current_streak, 2);
+    assert_eq!(user_stats(&attempts, &user, 23).current_streak, 0);
+    assert_eq!(user_stats(&attempts, &user, 23).longest_streak, 3);
+}
+
+#[test]
+fn the_day_of_a_solve_is_the_day_it_ends()
+{
+    let user = UserId::new();
+    let overnight = SolveAttempt { started_at: 5 * DAY_MS - 10, time_ms: 20, ..attempt(user, CrosswordId::new(), 0, 0, 0) };
+    let stats = user_stats(&[overnight, solved_on(user, 6)], &user, 6);
+    assert_eq!((stats.current_streak, stats.longest_streak), (2, 2));
+}
+
+#[test]
+fn unfinished_attempts_count_but_dont_solve()
+{
+    let user = UserId::new();
+    let unfinished = SolveAttempt { completed: false, ..solved_on(user, 3) };
+    let revealed = SolveAttempt { reveals: 2, ..solved_on(user, 4) };
+    let stats = user_stats(&[unfinished, revealed, solved_on(user, 4), solved_on(UserId::new(), 4)], &user, 4);
+    assert_eq!((stats.attempts, stats.solved, stats.solved_without_reveals), (3, 2, 1));
+    assert_eq!((stats.current_streak, stats.longest_streak), (1, 1));
+}
+
+#[test]
+fn average_times_are_split_by_the_size_of_the_grid()
+{
+    let user = UserId::new();
+    let sized = |size, time_ms| SolveAttempt { size, time_ms, ..attempt(user, CrosswordId::new(), 0, 0, 0) };
+    let attempts = [sized(25, 1000), sized(1, 3000), sized(26, 5000), sized(500, 7000), sized(201, 9000)];
+
+    assert_eq!(user_stats(&attempts, &user, 0).by_size, [
+        SizeStats { min_size: 1, max_size: 25, solved: 2, average_time_ms: 2000 },
+        SizeStats { min_size: 26, max_size: 80, solved: 1, average_time_ms: 5000 },
+        SizeStats { min_size: 201, max_size: usize::MAX, solved: 2, average_time_ms: 8000 },
+    ]);
+}
+
+#[test]
+fn a_user_without_attempts_has_empty_stats()
+{
+    assert_eq!(user_stats(&[solved_on(UserId::new(), 1)], &UserId::new(), 1), Default::default());
+}
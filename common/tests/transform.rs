@@ -0,0 +1,47 @@
+use std::collections::HashSet;
+
+use common::{alphabet::Alphabet, document::CrosswordWord, transform::{transform_words, Transform, TransformError}};
+use crossword_generator::{placed_word::PlacedWord, word::{Direction, Position}};
+
+fn word(value: &str, x: i16, y: i16, direction: Direction) -> CrosswordWord
+{
+    CrosswordWord::from_placed_word(PlacedWord::new(Alphabet::english().segment(value).unwrap(), Position { x, y }, direction))
+}
+
+#[test]
+fn moved_letters_are_checked_against_the_words_that_stay()
+{
+    let grid = vec![word("CAT", 0, 0, Direction::Right), word("DOG", 0, 2, Direction::Right)];
+    let selected = HashSet::from([grid[0].id]);
+    assert_eq!(transform_words(&grid, &selected, Transform::Translate(0, 2)), Err(TransformError::BrokenCrossing(Position { x: 0, y: 2 })));
+}
+
+#[test]
+fn moved_letters_can_agree_with_the_words_that_stay()
+{
+    let grid = vec![word("CAT", 0, 0, Direction::Right), word("TOE", 4, 0, Direction::Down)];
+    let selected = HashSet::from([grid[0].id]);
+    let moved = transform_words(&grid, &selected, Transform::Translate(2, 0)).unwrap();
+    assert_eq!(moved.len(), 1);
+    assert_eq!(moved[0].id, grid[0].id);
+    assert_eq!(moved[0].position, Position { x: 2, y: 0 });
+}
+
+#[test]
+fn conflicts_that_were_there_before_dont_block()
+{
+    let grid = vec![word("CAT", 0, 0, Direction::Right), word("DOG", 0, 0, Direction::Down), word("EAR", 0, 4, Direction::Right)];
+    let selected = HashSet::from([grid[2].id]);
+    assert!(transform_words(&grid, &selected, Transform::Translate(1, 0)).is_ok());
+}
+
+#[test]
+fn offsets_out_of_the_grid_are_refused()
+{
+    let grid = vec![word("CAT", 0, 0, Direction::Right), word("DOG", 0, 2, Direction::Down), word("EAR", -1, 6, Direction::Right)];
+    assert_eq!(transform_words(&grid, &HashSet::from([grid[1].id]), Transform::Translate(0, i16::MAX)), Err(TransformError::OutOfGrid));
+    // the start fits, the last letter doesn't
+    assert_eq!(transform_words(&grid, &HashSet::from([grid[0].id]), Transform::Translate(i16::MAX - 1, 0)), Err(TransformError::OutOfGrid));
+    assert_eq!(transform_words(&grid, &HashSet::from([grid[2].id]), Transform::Translate(i16::MIN, 0)), Err(TransformError::OutOfGrid));
+    assert!(transform_words(&grid, &HashSet::from([grid[0].id]), Transform::Translate(i16::MAX - 2, 0)).is_ok());
+}
@@ -0,0 +1,101 @@
+use common::{alphabet::{Alphabet, Tile}, document::{CrosswordDocument, CrosswordWord}, duplicates::{DuplicateIssue, DuplicateSettings}, validation::{compatibility_settings, validate_document, CompatibilityIssue, ValidationError}};
+use crossword_generator::{placed_word::PlacedWord, word::{Direction, Position}};
+
+fn word(value: &str, x: i16, y: i16, direction: Direction) -> CrosswordWord
+{
+    CrosswordWord::from_placed_word(PlacedWord::new(Alphabet::english().segment(value).unwrap(), Position { x, y }, direction))
+}
+
+// every word has a clue, so only the rule a test is about is broken
+fn document(words: Vec<CrosswordWord>) -> CrosswordDocument
+{
+    let clues = words.iter().map(|w| (w.id, "A clue".to_owned())).collect();
+    CrosswordDocument { alphabet: Alphabet::english(), words, clues, ..CrosswordDocument::default() }
+}
+
+fn validate(document: &CrosswordDocument) -> Vec<ValidationError>
+{
+    validate_document(document, &compatibility_settings())
+}
+
+#[test]
+fn a_connected_grid_with_clues_is_valid()
+{
+    let document = document(vec![word("CAT", 0, 0, Direction::Right), word("TOE", 2, 0, Direction::Down)]);
+    assert_eq!(validate(&document), []);
+}
+
+#[test]
+fn an_empty_crossword_is_only_reported_once()
+{
+    assert_eq!(validate(&document(vec![])), [ValidationError::NoWords]);
+}
+
+#[test]
+fn empty_words_are_reported()
+{
+    let empty = CrosswordWord::from_placed_word(PlacedWord::new(vec![], Position { x: 0, y: 0 }, Direction::Right));
+    let document = document(vec![empty]);
+    assert_eq!(validate(&document), [ValidationError::EmptyWord { word: document.words[0].id }]);
+}
+
+#[test]
+fn tiles_outside_the_alphabet_are_reported()
+{
+    let mut cat = word("CAT", 0, 0, Direction::Right);
+    cat.value[1] = Tile::new("Ç");
+    let document = document(vec![cat]);
+    assert_eq!(validate(&document), [ValidationError::UnknownTile { word: document.words[0].id, tile: Tile::new("Ç") }]);
+}
+
+#[test]
+fn crossings_with_different_letters_are_reported()
+{
+    let document = document(vec![word("CAT", 0, 0, Direction::Right), word("BOX", 1, -1, Direction::Down)]);
+    assert_eq!(validate(&document), [ValidationError::IncompatibleWords { first: document.words[0].id, second: document.words[1].id, issue: CompatibilityIssue::InvalidIntersection }]);
+}
+
+#[test]
+fn repeated_answers_follow_the_settings_of_the_document()
+{
+    let mut document = document(vec![word("CAT", 0, 0, Direction::Right), word("CAT", 0, 0, Direction::Down)]);
+    assert_eq!(validate(&document), [ValidationError::RepeatedAnswer { first: document.words[0].id, second: document.words[1].id, issue: DuplicateIssue::SameAnswer }]);
+
+    document.duplicate_settings = DuplicateSettings { same_answer: false, ..DuplicateSettings::default() };
+    assert_eq!(validate(&document), []);
+}
+
+#[test]
+fn missing_and_blank_clues_are_reported()
+{
+    let mut document = document(vec![word("CAT", 0, 0, Direction::Right), word("TOE", 2, 0, Direction::Down)]);
+    let (cat, toe) = (document.words[0].id, document.words[1].id);
+    document.clues.remove(&cat);
+    document.clues.insert(toe, "  ".to_owned());
+    assert_eq!(validate(&document), [ValidationError::MissingClue { word: cat }, ValidationError::MissingClue { word: toe }]);
+}
+
+#[test]
+fn separate_groups_of_words_are_reported()
+{
+    let document = document(vec![word("CAT", 0, 0, Direction::Right), word("DOG", 0, 5, Direction::Right)]);
+    assert_eq!(validate(&document), [ValidationError::Disconnected { components: 2 }]);
+}
+
+#[test]
+fn grids_over_the_size_limit_are_reported()
+{
+    let wide = document(vec![word(&"A".repeat(51), 0, 0, Direction::Right)]);
+    assert_eq!(validate(&wide), [ValidationError::TooLarge { width: 51, height: 1 }]);
+
+    let tall = document(vec![word(&"A".repeat(50), 0, 0, Direction::Down)]);
+    assert_eq!(validate(&tall), []);
+}
+
+#[test]
+fn messages_name_the_words()
+{
+    let document = document(vec![word("CAT", 0, 0, Direction::Right)]);
+    let error = ValidationError::MissingClue { word: document.words[0].id };
+    assert_eq!(error.message(&document), "\"CAT\" has no clue");
+}
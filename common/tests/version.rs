@@ -0,0 +1,100 @@
+use common::{alphabet::Alphabet, document::{CrosswordDocument, CrosswordWord}, version::{diff_documents, ClueChange, CrosswordRecord, WordChange}};
+use crossword_generator::{placed_word::PlacedWord, word::{Direction, Position}};
+
+fn word(value: &str, x: i16, y: i16, direction: Direction) -> CrosswordWord
+{
+    CrosswordWord::from_placed_word(PlacedWord::new(Alphabet::english().segment(value).unwrap(), Position { x, y }, direction))
+}
+
+fn document(words: Vec<CrosswordWord>) -> CrosswordDocument
+{
+    CrosswordDocument { title: "Pets".to_owned(), author: "Ann".to_owned(), alphabet: Alphabet::english(), words, ..CrosswordDocument::default() }
+}
+
+#[test]
+fn the_same_document_has_no_diff()
+{
+    let old = document(vec![word("CAT", 0, 0, Direction::Right)]);
+    assert!(diff_documents(&old, &old.clone()).is_empty());
+}
+
+#[test]
+fn words_are_added_and_removed_by_their_ids()
+{
+    let cat = word("CAT", 0, 0, Direction::Right);
+    let old = document(vec![cat.clone(), word("TOE", 2, 0, Direction::Down)]);
+    // the same word with another id is a removed and an added one
+    let new = document(vec![cat, word("TOE", 2, 0, Direction::Down)]);
+
+    let diff = diff_documents(&old, &new);
+    assert_eq!(diff.added, [new.words[1].clone()]);
+    assert_eq!(diff.removed, [old.words[1].clone()]);
+    assert_eq!(diff.changed, []);
+}
+
+#[test]
+fn moved_turned_and_respelled_words_are_changed()
+{
+    let old = document(vec![word("CAT", 0, 0, Direction::Right), word("DOG", 0, 2, Direction::Right), word("EAR", 0, 4, Direction::Right)]);
+    let mut new = old.clone();
+    new.words[0].position = Position { x: 1, y: 0 };
+    new.words[1].direction = Direction::Down;
+    new.words[2].value = Alphabet::english().segment("EAT").unwrap();
+
+    let diff = diff_documents(&old, &new);
+    assert_eq!(diff.added, []);
+    assert_eq!(diff.removed, []);
+    assert_eq!(diff.changed, (0..3).map(|i| WordChange { old: old.words[i].clone(), new: new.words[i].clone() }).collect::<Vec<_>>());
+}
+
+#[test]
+fn clues_are_compared_by_word_id()
+{
+    let mut old = document(vec![word("CAT", 0, 0, Direction::Right), word("DOG", 0, 2, Direction::Right), word("EAR", 0, 4, Direction::Right)]);
+    let (cat, dog, ear) = (old.words[0].id, old.words[1].id, old.words[2].id);
+    old.clues.insert(cat, "Purrs".to_owned());
+    old.clues.insert(dog, "Barks".to_owned());
+    let mut new = old.clone();
+    new.clues.insert(cat, "Meows".to_owned());
+    new.clues.remove(&dog);
+    new.clues.insert(ear, "Hears".to_owned());
+
+    let diff = diff_documents(&old, &new);
+    let change = |id, old: &str, new: &str| ClueChange { id, old: old.to_owned(), new: new.to_owned() };
+    let mut expected = vec![change(cat, "Purrs", "Meows"), change(dog, "Barks", ""), change(ear, "", "Hears")];
+    expected.sort_by_key(|c| c.id);
+    assert_eq!(diff.clues, expected);
+    assert!(diff.changed.is_empty());
+}
+
+#[test]
+fn an_empty_clue_is_the_same_as_a_missing_one()
+{
+    let old = document(vec![word("CAT", 0, 0, Direction::Right)]);
+    let mut new = old.clone();
+    new.clues.insert(new.words[0].id, String::new());
+    assert!(diff_documents(&old, &new).is_empty());
+}
+
+#[test]
+fn the_title_and_the_author_are_old_and_new()
+{
+    let old = document(vec![]);
+    let new = CrosswordDocument { title: "Farm".to_owned(), ..old.clone() };
+    let diff = diff_documents(&old, &new);
+    assert_eq!(diff.title, Some(("Pets".to_owned(), "Farm".to_owned())));
+    assert_eq!(diff.author, None);
+}
+
+#[test]
+fn versions_are_numbered_from_one_and_keep_the_draft()
+{
+    let mut record = CrosswordRecord::new(document(vec![word("CAT", 0, 0, Direction::Right)]), 10);
+    assert_eq!(record.add_version(true, 20).number, 1);
+    record.draft.title = "Farm".to_owned();
+    assert_eq!(record.add_version(false, 30).number, 2);
+
+    assert_eq!(record.version(1).unwrap().document.title, "Pets");
+    assert_eq!(record.version(2).unwrap().document.title, "Farm");
+    assert_eq!(record.latest_published().unwrap().info.number, 1);
+}
@@ -0,0 +1,98 @@
+use std::rc::Rc;
+
+use common::{analysis::{analyze_words, Symmetry}, difficulty::{estimate_difficulty, WordFrequencies}, document::CrosswordDocument};
+use stylist::{css, yew::styled_component};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::utils::dictionary::load_dictionary;
+
+#[derive(Properties, PartialEq)]
+pub struct AnalysisComponentProperties
+{
+    pub document: CrosswordDocument,
+}
+
+fn percent(value: f32) -> String
+{
+    format!("{}%", (value * 100.0).round())
+}
+
+#[styled_component]
+pub fn AnalysisComponent(AnalysisComponentProperties{document}: &AnalysisComponentProperties) -> Html
+{
+    let report = use_memo(document.words.clone(), |words| analyze_words(words));
+
+    // the estimate is made with empty frequencies until the dictionary of the locale is loaded
+    let frequencies = use_state(|| Rc::new(WordFrequencies::default()));
+    {
+        let frequencies = frequencies.clone();
+        use_effect_with(document.alphabet.locale, move |locale|
+        {
+            let locale = *locale;
+            spawn_local(async move
+            {
+                // a locale without a dictionary keeps the empty frequencies, the same as on the server
+                let dictionary = load_dictionary(locale).await.ok().flatten().unwrap_or_default();
+                frequencies.set(Rc::new(WordFrequencies::new(locale, &dictionary)));
+            });
+        });
+    }
+    let estimate = use_memo((document.clone(), (*frequencies).clone()), |(document, frequencies)| estimate_difficulty(document, frequencies));
+    let longest = report.length_distribution.values().copied().max().unwrap_or_default().max(1);
+
+    html!
+    {
+        <div class={classes!("analysis", css!( margin-top: 8px; font-size: 13px; ))}>
+            <b>{"Analysis"}</b>
+            <div class={css!( display: grid; grid-template-columns: auto auto; gap: 2px 12px; )}>
+                <span>{"Connectivity"}</span>
+                <span>{ if report.connected() { "all words are connected".to_owned() } else { format!("{} separate groups", report.components) } }</span>
+                <span>{"Checked cells"}</span>
+                <span>{ format!("{} of {} ({} unchecked)", report.checked_cells, report.cells, report.unchecked_cells) }</span>
+                <span>{"Size"}</span>
+                <span>{ format!("{}x{}, density {}", report.width, report.height, percent(report.density)) }</span>
+                <span>{"Estimated difficulty"}</span>
+                <span>{ format!("{} of 100, {}", estimate.score, estimate.difficulty().name()) }</span>
+                if frequencies.is_empty()
+                {
+                    <span class={css!( grid-column: span 2; padding-left: 12px; )}>{"Without a dictionary for the locale the words and the letters count as average"}</span>
+                }
+                {
+                    for [
+                        ("Words not in the dictionary", estimate.factors.unknown_words),
+                        ("Rare letters", estimate.factors.letter_rarity),
+                        ("Unchecked cells", estimate.factors.unchecked_cells),
+                        ("Word length", estimate.factors.word_length),
+                        ("Hard clues", estimate.factors.clues),
+                    ].into_iter().map(|(name, factor)| html!
+                    {
+                        <>
+                            <span class={css!( padding-left: 12px; )}>{ name }</span>
+                            <span>{ percent(factor) }</span>
+                        </>
+                    })
+                }
+                {
+                    for report.symmetry.iter().map(|s| html!
+                    {
+                        <>
+                            <span>{ match s.symmetry { Symmetry::Rotational => "Rotational symmetry", Symmetry::Horizontal => "Horizontal symmetry", Symmetry::Vertical => "Vertical symmetry" } }</span>
+                            <span>{ percent(s.score) }</span>
+                        </>
+                    })
+                }
+            </div>
+            <div class={css!( margin-top: 4px; )}>{"Word lengths"}</div>
+            {
+                for report.length_distribution.iter().map(|(length, count)| html!
+                {
+                    <div class={css!( display: flex; align-items: center; gap: 6px; )}>
+                        <span class={css!( width: 20px; text-align: right; )}>{ length }</span>
+                        <span class={css!( height: 10px; background-color: rgb(200, 200, 200); )} style={format!("width: {}px;", count * 150 / longest)}/>
+                        <span>{ count }</span>
+                    </div>
+                })
+            }
+        </div>
+    }
+}
@@ -0,0 +1,235 @@
+use common::{alphabet::Tile, document::CrosswordId, solve::{entries_to_cells, progress, CheckRequest, CheckResponse, Entries, Puzzle}};
+use crossword_generator::word::{Direction, Position};
+use gloo_utils::format::JsValueSerdeExt;
+use serde::{Deserialize, Serialize};
+use stylist::{css, yew::styled_component};
+use wasm_bindgen::JsValue;
+use web_sys::js_sys::Date;
+use yew::{platform::spawn_local, prelude::*};
+use yew_router::prelude::use_location;
+
+use crate::{components::{room_page::format_time, solve_grid_component::SolveGridComponent}, utils::{api::{get_json, post_json}, color_rgba::ColorRGBA, settings::{StyleSettings, Theme}}};
+
+// what the host page gets through postMessage
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum EmbedEvent
+{
+    Loaded { crossword_id: CrosswordId, revision: u32, title: String, cells: usize, clues: usize },
+    Progress { progress: f32 },
+    Solved { time_ms: u64 },
+}
+
+// ?mode=view shows the puzzle without playing it, the colors of the theme are given in hex like ?color_dark=336699
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+struct EmbedQuery
+{
+    mode: Option<String>,
+    // where the events may go, every origin by default
+    origin: Option<String>,
+    color_dark: Option<String>,
+    color_normal: Option<String>,
+    color_light: Option<String>,
+    color_error_dark: Option<String>,
+    color_error_normal: Option<String>,
+    color_error_light: Option<String>,
+    color_locked: Option<String>,
+    color_grouped: Option<String>,
+}
+
+impl EmbedQuery
+{
+    fn apply_to(&self, theme: &mut Theme)
+    {
+        let colors = [
+            (&self.color_dark, &mut theme.color_dark),
+            (&self.color_normal, &mut theme.color_normal),
+            (&self.color_light, &mut theme.color_light),
+            (&self.color_error_dark, &mut theme.color_error_dark),
+            (&self.color_error_normal, &mut theme.color_error_normal),
+            (&self.color_error_light, &mut theme.color_error_light),
+            (&self.color_locked, &mut theme.color_locked),
+            (&self.color_grouped, &mut theme.color_grouped),
+        ];
+        for (hex, color) in colors
+        {
+            if let Some(new_color) = hex.as_deref().and_then(ColorRGBA::from_hex) { *color = new_color; }
+        }
+    }
+}
+
+fn post_to_host(event: &EmbedEvent, origin: &str)
+{
+    let Ok(Some(parent)) = gloo_utils::window().parent() else { return; };
+    if let Ok(message) = JsValue::from_serde(event) { let _ = parent.post_message(&message, origin); }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct EmbedPageProperties
+{
+    pub crossword_id: String,
+}
+
+#[styled_component]
+pub fn EmbedPage(EmbedPageProperties{crossword_id}: &EmbedPageProperties) -> Html
+{
+    let query = use_location().and_then(|location| location.query::<EmbedQuery>().ok()).unwrap_or_default();
+    let origin = query.origin.clone().unwrap_or_else(|| "*".to_owned());
+    let playable = query.mode.as_deref() != Some("view");
+
+    let style_settings = use_context::<StyleSettings>().expect("No style provided");
+    let style_settings =
+    {
+        let mut style_settings = style_settings;
+        query.apply_to(&mut style_settings.playground_style_settings.theme);
+        query.apply_to(&mut style_settings.word_style_settings.theme);
+        style_settings
+    };
+
+    let puzzle = use_state(|| None::<Result<Puzzle, String>>);
+    let entries = use_state(Entries::new);
+    let wrong_cells = use_state(Vec::<Position>::new);
+    let message = use_state(String::new);
+    let started_at = use_state(|| 0.0);
+    let solved = use_state(|| false);
+
+    {
+        let puzzle = puzzle.clone();
+        let started_at = started_at.clone();
+        let origin = origin.clone();
+        use_effect_with(crossword_id.clone(), move |crossword_id|
+        {
+            let url = format!("/api/crosswords/{}/puzzle", crossword_id);
+            spawn_local(async move
+            {
+                let loaded = get_json::<Puzzle>(&url).await.map_err(|e| e.to_string());
+                if let Ok(p) = &loaded
+                {
+                    started_at.set(Date::now());
+                    post_to_host(&EmbedEvent::Loaded { crossword_id: p.crossword_id, revision: p.revision, title: p.title.clone(), cells: p.cells.len(), clues: p.clues.len() }, &origin);
+                }
+                puzzle.set(Some(loaded));
+            });
+        });
+    }
+
+    let puzzle = match &*puzzle
+    {
+        None => return html! { <p>{"Loading..."}</p> },
+        Some(Err(error)) => return html! { <p>{ error.clone() }</p> },
+        Some(Ok(puzzle)) => puzzle.clone(),
+    };
+
+    let check =
+    {
+        let url = format!("/api/crosswords/{}/puzzle/check", puzzle.crossword_id);
+        let revision = puzzle.revision;
+        let wrong_cells = wrong_cells.clone();
+        let message = message.clone();
+        let solved = solved.clone();
+        let started_at = *started_at;
+        let origin = origin.clone();
+        move |entries: Entries|
+        {
+            let url = url.clone();
+            let request = CheckRequest { revision, entries: entries_to_cells(&entries) };
+            let wrong_cells = wrong_cells.clone();
+            let message = message.clone();
+            let solved = solved.clone();
+            let origin = origin.clone();
+            spawn_local(async move
+            {
+                match post_json::<_, CheckResponse>(&url, &request).await
+                {
+                    Ok(CheckResponse { solved: true, .. }) =>
+                    {
+                        let time_ms = (Date::now() - started_at).max(0.0) as u64;
+                        solved.set(true);
+                        message.set(format!("Solved in {}", format_time(time_ms)));
+                        post_to_host(&EmbedEvent::Solved { time_ms }, &origin);
+                    }
+                    Ok(CheckResponse { wrong, .. }) =>
+                    {
+                        message.set(if wrong.is_empty() { "Everything filled is right".to_owned() } else { format!("{} wrong cells", wrong.len()) });
+                        wrong_cells.set(wrong);
+                    }
+                    Err(error) => message.set(error.to_string()),
+                }
+            });
+        }
+    };
+
+    let on_input =
+    {
+        let entries = entries.clone();
+        let wrong_cells = wrong_cells.clone();
+        let puzzle = puzzle.clone();
+        let origin = origin.clone();
+        let check = check.clone();
+        let solved = *solved;
+        Callback::from(move |(position, tile): (Position, Option<Tile>)|
+        {
+            if solved { return; }
+            let mut new_entries = (*entries).clone();
+            match tile
+            {
+                Some(tile) => { new_entries.insert(position.clone(), tile); }
+                None => { new_entries.remove(&position); }
+            }
+            wrong_cells.set(wrong_cells.iter().filter(|pos| **pos != position).cloned().collect());
+            post_to_host(&EmbedEvent::Progress { progress: progress(&puzzle, &new_entries) }, &origin);
+            // a full grid is checked right away, like in the rooms
+            if new_entries.len() == puzzle.cells.len() { check(new_entries.clone()); }
+            entries.set(new_entries);
+        })
+    };
+
+    let on_check =
+    {
+        let entries = entries.clone();
+        Callback::from(move |_: MouseEvent| check((*entries).clone()))
+    };
+
+    let clue_list = |direction: Direction, title: &str| html!
+    {
+        <div class={css!( flex: 1; min-width: 160px; )}>
+            <h3>{ title }</h3>
+            <ol class={css!( list-style: none; padding: 0; )}>
+            {
+                for puzzle.clues.iter().filter(|c| c.word.direction == direction).map(|c| html!
+                {
+                    <li>{ format!("{}. {} ({})", c.word.number, c.clue, c.word.length) }</li>
+                })
+            }
+            </ol>
+        </div>
+    };
+
+    html!
+    {
+        <ContextProvider<StyleSettings> context={style_settings}>
+            <div class={classes!("embed", css!( display: flex; flex-direction: column; gap: 8px; color: white; font-family: sans-serif; ))}>
+                <h3 class={css!( margin: 0; )}>{ if puzzle.title.is_empty() { "Untitled".to_owned() } else { puzzle.title.clone() } }</h3>
+                if playable
+                {
+                    <div class={css!( display: flex; gap: 8px; align-items: center; )}>
+                        <button onclick={on_check} disabled={*solved}>{"Check"}</button>
+                        <span>{ (*message).clone() }</span>
+                    </div>
+                }
+                <div class={css!( display: flex; gap: 16px; align-items: flex-start; flex-wrap: wrap; )}>
+                    if playable
+                    {
+                        <SolveGridComponent puzzle={puzzle.clone()} entries={(*entries).clone()} wrong_cells={(*wrong_cells).clone()} {on_input}/>
+                    }
+                    else
+                    {
+                        <SolveGridComponent puzzle={puzzle.clone()} entries={Entries::new()}/>
+                    }
+                    { clue_list(Direction::Right, "Across") }
+                    { clue_list(Direction::Down, "Down") }
+                </div>
+            </div>
+        </ContextProvider<StyleSettings>>
+    }
+}
@@ -0,0 +1,139 @@
+use std::str::FromStr;
+
+use common::{document::Difficulty, gallery::{parse_tags, GalleryFilter, GalleryItem, GalleryPage as Page}};
+use stylist::{css, yew::styled_component};
+use web_sys::{js_sys::encode_uri_component, HtmlInputElement, HtmlSelectElement};
+use yew::{platform::spawn_local, prelude::*};
+use yew_router::prelude::Link;
+
+use crate::{utils::api::get_json, Route};
+
+fn gallery_url(filter: &GalleryFilter, cursor: Option<&str>) -> String
+{
+    let mut params = Vec::new();
+    if !filter.query.trim().is_empty() { params.push(("q", filter.query.trim().to_owned())); }
+    if !filter.tags.is_empty() { params.push(("tags", filter.tags.join(","))); }
+    if let Some(difficulty) = filter.difficulty { params.push(("difficulty", difficulty.name().to_owned())); }
+    if let Some(min_size) = filter.min_size { params.push(("min_size", min_size.to_string())); }
+    if let Some(max_size) = filter.max_size { params.push(("max_size", max_size.to_string())); }
+    if let Some(min_score) = filter.min_score { params.push(("min_score", min_score.to_string())); }
+    if let Some(max_score) = filter.max_score { params.push(("max_score", max_score.to_string())); }
+    if let Some(cursor) = cursor { params.push(("cursor", cursor.to_owned())); }
+    let params = params.into_iter().map(|(name, value)| format!("{}={}", name, String::from(encode_uri_component(&value)))).collect::<Vec<_>>();
+    if params.is_empty() { "/api/gallery".to_owned() } else { format!("/api/gallery?{}", params.join("&")) }
+}
+
+fn number_value<T: FromStr>(event: &Event) -> Option<T>
+{
+    event.target_dyn_into::<HtmlInputElement>().unwrap().value().trim().parse().ok()
+}
+
+#[styled_component]
+pub fn GalleryPage() -> Html
+{
+    let filter = use_state(GalleryFilter::default);
+    let items = use_state(Vec::<GalleryItem>::new);
+    let next_cursor = use_state(|| None::<String>);
+    let status = use_state(|| "Loading...".to_owned());
+
+    // the first page again when the filter changes, the next ones are added to the end
+    let load =
+    {
+        let items = items.clone();
+        let next_cursor = next_cursor.clone();
+        let status = status.clone();
+        move |filter: GalleryFilter, cursor: Option<String>, loaded: Vec<GalleryItem>|
+        {
+            let url = gallery_url(&filter, cursor.as_deref());
+            let items = items.clone();
+            let next_cursor = next_cursor.clone();
+            let status = status.clone();
+            spawn_local(async move
+            {
+                match get_json::<Page>(&url).await
+                {
+                    Ok(page) =>
+                    {
+                        let mut all = loaded;
+                        all.extend(page.items);
+                        status.set(if all.is_empty() { "No crosswords found".to_owned() } else { String::new() });
+                        items.set(all);
+                        next_cursor.set(page.next_cursor);
+                    }
+                    Err(error) => status.set(error.to_string()),
+                }
+            });
+        }
+    };
+
+    {
+        let load = load.clone();
+        use_effect_with((*filter).clone(), move |filter| load(filter.clone(), None, Vec::new()));
+    }
+
+    let on_more =
+    {
+        let filter = filter.clone();
+        let items = items.clone();
+        let next_cursor = next_cursor.clone();
+        Callback::from(move |_: MouseEvent| load((*filter).clone(), (*next_cursor).clone(), (*items).clone()))
+    };
+
+    let update_filter = |change: fn(&mut GalleryFilter, &Event)|
+    {
+        let filter = filter.clone();
+        Callback::from(move |event: Event|
+        {
+            let mut new_filter = (*filter).clone();
+            change(&mut new_filter, &event);
+            filter.set(new_filter);
+        })
+    };
+
+    html!
+    {
+        <div class={classes!("gallery", css!( color: white; font-family: sans-serif; ))}>
+            <h2>{"Gallery"}</h2>
+            <div class={css!( display: flex; gap: 8px; flex-wrap: wrap; margin-bottom: 16px; )}>
+                <input type="search" placeholder="Search titles and clues" value={filter.query.clone()}
+                    onchange={update_filter(|f, e| f.query = e.target_dyn_into::<HtmlInputElement>().unwrap().value())}/>
+                <input type="text" placeholder="Tags, with commas" value={filter.tags.join(", ")}
+                    onchange={update_filter(|f, e| f.tags = parse_tags(&e.target_dyn_into::<HtmlInputElement>().unwrap().value()))}/>
+                <select onchange={update_filter(|f, e| f.difficulty = Difficulty::from_name(&e.target_dyn_into::<HtmlSelectElement>().unwrap().value()))}>
+                    <option value="" selected={filter.difficulty.is_none()}>{"Any difficulty"}</option>
+                    { for Difficulty::all().into_iter().map(|d| html! { <option value={d.name()} selected={filter.difficulty == Some(d)}>{ d.name() }</option> }) }
+                </select>
+                <input type="number" min="1" placeholder="Min size" value={filter.min_size.map(|s| s.to_string()).unwrap_or_default()}
+                    onchange={update_filter(|f, e| f.min_size = number_value(e))}/>
+                <input type="number" min="1" placeholder="Max size" value={filter.max_size.map(|s| s.to_string()).unwrap_or_default()}
+                    onchange={update_filter(|f, e| f.max_size = number_value(e))}/>
+                <input type="number" min="0" max="100" placeholder="Min score" value={filter.min_score.map(|s| s.to_string()).unwrap_or_default()}
+                    onchange={update_filter(|f, e| f.min_score = number_value(e))}/>
+                <input type="number" min="0" max="100" placeholder="Max score" value={filter.max_score.map(|s| s.to_string()).unwrap_or_default()}
+                    onchange={update_filter(|f, e| f.max_score = number_value(e))}/>
+            </div>
+            <p>{ (*status).clone() }</p>
+            <div class={css!( display: grid; grid-template-columns: repeat(auto-fill, minmax(200px, 1fr)); gap: 16px; )}>
+            {
+                for items.iter().map(|item| html!
+                {
+                    <div class={css!( display: flex; flex-direction: column; gap: 4px; padding: 8px; border: 1px solid gray; )}>
+                        <img src={format!("/api/crosswords/{}/thumbnail.svg", item.crossword_id)} alt={item.title.clone()}
+                            class={css!( width: 100%; height: 140px; object-fit: contain; background: white; )}/>
+                        <b>{ if item.title.is_empty() { "Untitled".to_owned() } else { item.title.clone() } }</b>
+                        if !item.author.is_empty() { <span>{ format!("by {}", item.author) }</span> }
+                        <span>{ format!("{}x{}, {} words{}", item.width, item.height, item.words, item.difficulty.map(|d| format!(", {}", d.name())).unwrap_or_default()) }</span>
+                        if let Some(score) = item.difficulty_score { <span>{ format!("Estimated difficulty {} of 100", score) }</span> }
+                        if !item.tags.is_empty() { <span>{ item.tags.join(", ") }</span> }
+                        <Link<Route> to={Route::Solve { id: item.crossword_id.to_string(), revision: item.revision }}>{"Solve"}</Link<Route>>
+                    </div>
+                })
+            }
+            </div>
+            if next_cursor.is_some()
+            {
+                <button onclick={on_more} class={css!( margin-top: 16px; )}>{"More"}</button>
+            }
+        </div>
+    }
+}
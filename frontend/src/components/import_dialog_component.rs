@@ -0,0 +1,81 @@
+use common::{alphabet::Alphabet, document::CrosswordWord, import::{import_text, ImportDiagnostic, ImportFormat}};
+use stylist::{css, yew::styled_component};
+use web_sys::{HtmlSelectElement, HtmlTextAreaElement};
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ImportDialogComponentProperties
+{
+    pub alphabet: Alphabet,
+    pub on_import: Callback<Vec<CrosswordWord>>,
+    pub on_close: Callback<()>,
+}
+
+// the text is parsed in the browser with the same importers the api uses
+#[styled_component]
+pub fn ImportDialogComponent(ImportDialogComponentProperties{alphabet, on_import, on_close}: &ImportDialogComponentProperties) -> Html
+{
+    let text = use_state(String::new);
+    // none finds the format from the text
+    let format = use_state(|| None::<ImportFormat>);
+    let diagnostics = use_state(Vec::<ImportDiagnostic>::new);
+
+    let on_format_change =
+    {
+        let format = format.clone();
+        Callback::from(move |event: Event|
+        {
+            format.set(match event.target_dyn_into::<HtmlSelectElement>().unwrap().value().as_str()
+            {
+                "grid" => Some(ImportFormat::Grid),
+                "word_list" => Some(ImportFormat::WordList),
+                _ => None,
+            });
+        })
+    };
+
+    let on_import_click =
+    {
+        let text = text.clone();
+        let format = format.clone();
+        let diagnostics = diagnostics.clone();
+        let alphabet = alphabet.clone();
+        let on_import = on_import.clone();
+        Callback::from(move |_: MouseEvent|
+        {
+            let format = format.unwrap_or_else(|| ImportFormat::detect(&text));
+            match import_text(&text, format, &alphabet)
+            {
+                Ok(words) =>
+                {
+                    diagnostics.set(Vec::new());
+                    on_import.emit(words.into_iter().map(CrosswordWord::from_placed_word).collect());
+                }
+                Err(errors) => diagnostics.set(errors),
+            }
+        })
+    };
+
+    html!
+    {
+        <div class={classes!("import-dialog", css!( position: fixed; inset: 0; display: flex; align-items: center; justify-content: center; background-color: rgba(0, 0, 0, 0.5); z-index: 10; ))}>
+            <div class={css!( display: flex; flex-direction: column; gap: 8px; padding: 16px; background-color: #333; color: white; min-width: 400px; ))}>
+                <b>{"Import from text"}</b>
+                <select onchange={on_format_change}>
+                    <option value="auto" selected={format.is_none()}>{"Find the format"}</option>
+                    <option value="grid" selected={*format == Some(ImportFormat::Grid)}>{"Grid, # for blocks"}</option>
+                    <option value="word_list" selected={*format == Some(ImportFormat::WordList)}>{"WORD x y R/D lines"}</option>
+                </select>
+                <textarea rows="12" class={css!( font-family: monospace; )} value={(*text).clone()}
+                    oninput={ let text = text.clone(); Callback::from(move |event: InputEvent| text.set(event.target_unchecked_into::<HtmlTextAreaElement>().value())) }/>
+                <ul class={css!( margin: 0; padding-left: 16px; color: #f88; font-family: monospace; )}>
+                    { for diagnostics.iter().map(|d| html! { <li>{ d.to_string() }</li> }) }
+                </ul>
+                <div class={css!( display: flex; gap: 8px; justify-content: flex-end; )}>
+                    <button onclick={ let on_close = on_close.clone(); Callback::from(move |_: MouseEvent| on_close.emit(())) }>{"Cancel"}</button>
+                    <button onclick={on_import_click}>{"Replace the words"}</button>
+                </div>
+            </div>
+        </div>
+    }
+}
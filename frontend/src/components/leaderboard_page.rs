@@ -0,0 +1,72 @@
+use common::{room::RoomMode, stats::LeaderboardEntry};
+use stylist::{css, yew::styled_component};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::{components::room_page::format_time, utils::{api::get_json, user::user_id}};
+
+#[derive(Properties, PartialEq)]
+pub struct LeaderboardPageProperties
+{
+    pub crossword_id: String,
+    // the latest solved revision when it isn't given
+    #[prop_or_default]
+    pub revision: Option<u32>,
+}
+
+#[styled_component]
+pub fn LeaderboardPage(LeaderboardPageProperties{crossword_id, revision}: &LeaderboardPageProperties) -> Html
+{
+    let entries = use_state(|| None::<Result<Vec<LeaderboardEntry>, String>>);
+
+    {
+        let entries = entries.clone();
+        use_effect_with((crossword_id.clone(), *revision), move |(crossword_id, revision)|
+        {
+            let url = match revision
+            {
+                Some(revision) => format!("/api/crosswords/{}/leaderboard?revision={}", crossword_id, revision),
+                None => format!("/api/crosswords/{}/leaderboard", crossword_id),
+            };
+            spawn_local(async move { entries.set(Some(get_json(&url).await.map_err(|e| e.to_string()))); });
+        });
+    }
+
+    let me = user_id();
+    let body = match &*entries
+    {
+        None => html! { <p>{"Loading..."}</p> },
+        Some(Err(error)) => html! { <p>{ error.clone() }</p> },
+        Some(Ok(entries)) if entries.is_empty() => html! { <p>{"Nobody has solved it yet"}</p> },
+        Some(Ok(entries)) => html!
+        {
+            <table class={css!( border-collapse: collapse; td, th { padding: 4px 12px; text-align: left; } )}>
+                <tr><th>{"#"}</th><th>{"Name"}</th><th>{"Time"}</th><th>{"Checks"}</th><th>{"Reveals"}</th><th>{"Mode"}</th></tr>
+                {
+                    for entries.iter().map(|e| html!
+                    {
+                        <tr class={(e.user_id == me).then_some(css!( font-weight: bold; ))}>
+                            <td>{ e.rank }</td>
+                            <td>{ e.name.clone() }</td>
+                            <td>{ format_time(e.time_ms) }</td>
+                            <td>{ e.checks }</td>
+                            <td>{ e.reveals }</td>
+                            <td>{ match e.mode { RoomMode::Coop => "co-op", RoomMode::Race => "race" } }</td>
+                        </tr>
+                    })
+                }
+            </table>
+        },
+    };
+
+    html!
+    {
+        <div class={classes!("leaderboard", css!( color: white; font-family: sans-serif; ))}>
+            <h2>{"Leaderboard"}</h2>
+            if let Some(Ok(entries)) = &*entries
+            {
+                if let Some(first) = entries.first() { <p>{ format!("Revision {}", first.revision) }</p> }
+            }
+            { body }
+        </div>
+    }
+}
@@ -1,3 +1,13 @@
 pub mod playground_component;
 pub mod playground_children_components;
-pub mod word_component;
\ No newline at end of file
+pub mod word_component;
+pub mod solve_grid_component;
+pub mod room_page;
+pub mod leaderboard_page;
+pub mod stats_page;
+pub mod version_history_component;
+pub mod solve_page;
+pub mod analysis_component;
+pub mod print_page;
+pub mod import_dialog_component;
+pub mod embed_page;pub mod gallery_page;
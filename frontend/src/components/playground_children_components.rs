@@ -1,3 +1,6 @@
+use std::fmt::Display;
+
+use common::document::WordId;
 use crossword_generator::{crossword::WordCompatibilityError, placed_word::PlacedWord, traits::{CrosswordChar, CrosswordString}, word::{Direction, Position}};
 use stylist::{css, yew::styled_component};
 use yew::prelude::*;
@@ -10,10 +13,18 @@ use super::playground_component::PlaygroundWordState;
 pub struct PlaygroundCellComponentProperties<CharT: CrosswordChar>
 {
     pub position: Position,
-    pub word_ids: Vec<usize>,
+    pub word_ids: Vec<WordId>,
     pub character: Option<CharT>,
     #[prop_or(PlaygroundWordState::Normal)]
     pub state: PlaygroundWordState,
+    // the mirror cell is empty in symmetry mode
+    #[prop_or_default]
+    pub asymmetric: bool,
+    #[prop_or_default]
+    pub locked: bool,
+    // a word of the cell matches the search
+    #[prop_or_default]
+    pub found: bool,
     #[prop_or(Callback::noop())]
     pub on_invert_word_direction: Callback<()>,
     #[prop_or(Callback::noop())]
@@ -21,7 +32,7 @@ pub struct PlaygroundCellComponentProperties<CharT: CrosswordChar>
 }
 
 #[styled_component]
-pub fn PlaygroundCellComponent<CharT: CrosswordChar + ToHtml>(PlaygroundCellComponentProperties{position: pos, word_ids, character, state, on_invert_word_direction, on_select}: &PlaygroundCellComponentProperties<CharT>) -> Html
+pub fn PlaygroundCellComponent<CharT: CrosswordChar + Display>(PlaygroundCellComponentProperties{position: pos, word_ids, character, state, asymmetric, locked, found, on_invert_word_direction, on_select}: &PlaygroundCellComponentProperties<CharT>) -> Html
 {
     let StyleSettings { word_style_settings: _, playground_style_settings } = use_context::<StyleSettings>().expect("No style provided");
     let PlaygroundStyleSettings 
@@ -31,7 +42,7 @@ pub fn PlaygroundCellComponent<CharT: CrosswordChar + ToHtml>(PlaygroundCellComp
             border_radius, 
             font_size, 
             word_border_dist_from_cell_wall: _, 
-            word_border_width: _, 
+            word_border_width, 
             word_border_radius: _,
             between_word_width: _,
             between_word_radius: _,
@@ -40,12 +51,14 @@ pub fn PlaygroundCellComponent<CharT: CrosswordChar + ToHtml>(PlaygroundCellComp
     
     let Theme
         {
-            color_dark: _,
-            color_error_dark: _,
+            color_dark,
+            color_error_dark,
             color_normal,
             color_error_normal,
             color_light,
             color_error_light,
+            color_locked,
+            color_grouped: _,
         } = theme;
     
     let words_visible_when_hovered = word_ids.into_iter().map(|id| 
@@ -58,10 +71,21 @@ pub fn PlaygroundCellComponent<CharT: CrosswordChar + ToHtml>(PlaygroundCellComp
     ).collect::<Vec<_>>();
 
     let (background_color, hover_background_color) = 
-        if let Some(_) = &character 
-            { (color_light, color_normal) } 
+        if character.is_none() || *asymmetric
+            { (color_error_light, color_error_normal) } 
+        else if *found
+            { (color_dark, color_normal) } 
+        else if *locked
+            { (color_locked, color_locked) } 
         else 
-            { (color_error_light, color_error_normal) };
+            { (color_light, color_normal) };
+
+    // the outline tells the asymmetric cells apart from the ones with conflicting letters
+    let asymmetric_outline = asymmetric.then_some(css!
+        (
+            box-shadow: inset 0 0 0 ${word_border_width}px ${color_error_dark};
+        )
+    );
 
     let hover_effect_when_selected = (state == &PlaygroundWordState::Selected).then_some(css!
         (
@@ -93,6 +117,7 @@ pub fn PlaygroundCellComponent<CharT: CrosswordChar + ToHtml>(PlaygroundCellComp
                 }
             ),
             words_visible_when_hovered,
+            asymmetric_outline,
             hover_effect_when_selected,
             css!(
                 left: ${pos.x as isize * cell_size as isize + pos.x as isize * gap as isize}px;
@@ -101,19 +126,100 @@ pub fn PlaygroundCellComponent<CharT: CrosswordChar + ToHtml>(PlaygroundCellComp
         )} 
         draggable={ (state == &PlaygroundWordState::Selected).to_string() }
         onclick={on_select.reform(|event: MouseEvent| event.ctrl_key())}>
-            { character }
+            { character.as_ref().map(ToString::to_string) }
         </div>
     }
 }
 
 
 
+#[derive(Properties, PartialEq)]
+pub struct PlaygroundSlotCellComponentProperties
+{
+    pub position: Position,
+    pub slot_ids: Vec<WordId>,
+    #[prop_or(Callback::noop())]
+    pub on_remove: Callback<()>,
+}
+
+#[styled_component]
+pub fn PlaygroundSlotCellComponent(PlaygroundSlotCellComponentProperties{position: pos, slot_ids, on_remove}: &PlaygroundSlotCellComponentProperties) -> Html
+{
+    let StyleSettings { word_style_settings: _, playground_style_settings } = use_context::<StyleSettings>().expect("No style provided");
+    let PlaygroundStyleSettings 
+        { 
+            gap, 
+            cell_size, 
+            border_radius, 
+            font_size: _, 
+            word_border_dist_from_cell_wall: _, 
+            word_border_width, 
+            word_border_radius: _,
+            between_word_width: _,
+            between_word_radius: _,
+            theme
+        } = playground_style_settings;
+    
+    let Theme
+        {
+            color_dark: _,
+            color_error_dark: _,
+            color_normal,
+            color_error_normal: _,
+            color_light,
+            color_error_light: _,
+            color_locked: _,
+            color_grouped: _,
+        } = theme;
+    
+    let slots_visible_when_hovered = slot_ids.iter().map(|id| 
+        css!(
+            :hover ~ #${format!("word{}", id)} 
+            { 
+                visibility: visible;
+            }
+        )
+    ).collect::<Vec<_>>();
+
+    html!
+    {
+        <div class={classes!("playground_slot_cell",
+            css!
+            (
+                position: absolute;
+                background-color: transparent;
+                border: ${word_border_width}px dashed${" "}${color_light};
+                box-sizing: border-box;
+                width: ${cell_size}px;
+                height: ${cell_size}px;
+                border-radius: ${border_radius}px;
+                cursor: default;
+                user-select: none;
+                pointer-events: inherit;
+
+                :hover
+                {  
+                    background-color: ${color_normal};
+                }
+            ),
+            slots_visible_when_hovered,
+            css!(
+                left: ${pos.x as isize * cell_size as isize + pos.x as isize * gap as isize}px;
+                top: ${pos.y as isize * cell_size as isize + pos.y as isize * gap as isize}px;
+            )
+        )} 
+        oncontextmenu={on_remove.reform(|event: MouseEvent| event.prevent_default())}/>
+    }
+}
+
+
+
 #[derive(Properties, PartialEq)]
 pub struct PlaygroundBetweenCellComponentProperties
 {
     pub position: Position,
     pub direction: Direction,
-    pub word_ids: Vec<usize>,
+    pub word_ids: Vec<WordId>,
     pub draggable: bool,
     #[prop_or(Callback::noop())]
     pub on_select: Callback<bool>,
@@ -183,14 +289,18 @@ pub struct PlaygroundWordComponentProperties
     pub position: Position,
     pub width: usize,
     pub height: usize,
-    pub id: usize,
+    pub id: WordId,
     pub error_exists: bool,
     #[prop_or(PlaygroundWordState::Normal)]
     pub state: PlaygroundWordState,
+    #[prop_or_default]
+    pub grouped: bool,
+    #[prop_or_default]
+    pub current_found: bool,
 }
 
 #[styled_component]
-pub fn PlaygroundWordComponent(PlaygroundWordComponentProperties{position: pos, width, height, id, error_exists, state}: &PlaygroundWordComponentProperties) -> Html
+pub fn PlaygroundWordComponent(PlaygroundWordComponentProperties{position: pos, width, height, id, error_exists, state, grouped, current_found}: &PlaygroundWordComponentProperties) -> Html
 {
     let StyleSettings { word_style_settings: _, playground_style_settings } = use_context::<StyleSettings>().expect("No style provided");
     let PlaygroundStyleSettings 
@@ -215,9 +325,22 @@ pub fn PlaygroundWordComponent(PlaygroundWordComponentProperties{position: pos,
             color_error_normal: _,
             color_light: _,
             color_error_light: _,
+            color_locked: _,
+            color_grouped,
         } = theme;
 
     
+    let word_colored_when_grouped = grouped.then_some(
+        css!(
+            visibility: visible;
+            border-color: ${color_grouped};
+    ));
+
+    let word_visible_when_found = current_found.then_some(
+        css!(
+            visibility: visible;
+    ));
+
     let word_red_when_errors = error_exists.then_some(
         css!(
             visibility: visible;
@@ -246,6 +369,8 @@ pub fn PlaygroundWordComponent(PlaygroundWordComponentProperties{position: pos,
                 visibility: hidden;
                 user-select: none;
             ),
+            word_colored_when_grouped,
+            word_visible_when_found,
             word_red_when_errors,
             word_visible_when_selected,
             css!
@@ -302,6 +427,8 @@ pub fn PlaygroundWordErrorOutlineComponent(&PlaygroundWordErrorOutlineComponentP
             color_error_normal,
             color_light: _,
             color_error_light: _,
+            color_locked: _,
+            color_grouped: _,
         } = theme;
 
 
@@ -418,4 +545,134 @@ pub fn PlaygroundWordErrorOutlineComponent(&PlaygroundWordErrorOutlineComponentP
             { for html }
         </>
     }
-}
\ No newline at end of file
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct PlaygroundMinimapWord
+{
+    pub position: Position,
+    pub direction: Direction,
+    pub length: usize,
+    pub error_exists: bool,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PlaygroundMinimapComponentProperties
+{
+    pub words: Vec<PlaygroundMinimapWord>,
+    pub error_cells: Vec<Position>,
+    // (x, y, width, height) in playground pixels
+    pub viewport: (f32, f32, f32, f32),
+    #[prop_or(200)]
+    pub width: usize,
+    #[prop_or(150)]
+    pub height: usize,
+    #[prop_or(Callback::noop())]
+    pub on_click: Callback<(f32, f32)>,
+}
+
+#[styled_component]
+pub fn PlaygroundMinimapComponent(PlaygroundMinimapComponentProperties{words, error_cells, viewport, width, height, on_click}: &PlaygroundMinimapComponentProperties) -> Html
+{
+    let StyleSettings { word_style_settings: _, playground_style_settings } = use_context::<StyleSettings>().expect("No style provided");
+    let PlaygroundStyleSettings 
+        { 
+            gap, 
+            cell_size, 
+            border_radius: _, 
+            font_size: _, 
+            word_border_dist_from_cell_wall: _, 
+            word_border_width: _, 
+            word_border_radius: _,
+            between_word_width: _,
+            between_word_radius: _,
+            theme,
+        } = playground_style_settings;
+    
+    let Theme
+        {
+            color_dark,
+            color_error_dark,
+            color_normal,
+            color_error_normal,
+            color_light,
+            color_error_light: _,
+            color_locked: _,
+            color_grouped: _,
+        } = theme;
+
+    let step = (cell_size + gap) as f32;
+    let &(view_x, view_y, view_width, view_height) = viewport;
+
+    // the minimap shows both the words and the viewport, with a cell of padding around
+    let (min_x, min_y, max_x, max_y) = words.iter().fold((view_x, view_y, view_x + view_width, view_y + view_height), |(min_x, min_y, max_x, max_y), w|
+    {
+        let (w_width, w_height) = match w.direction
+        {
+            Direction::Right => (w.length as f32, 1f32),
+            Direction::Down => (1f32, w.length as f32),
+        };
+        let (left, top) = (w.position.x as f32 * step, w.position.y as f32 * step);
+        (min_x.min(left), min_y.min(top), max_x.max(left + w_width * step), max_y.max(top + w_height * step))
+    });
+    let (min_x, min_y, max_x, max_y) = (min_x - step, min_y - step, max_x + step, max_y + step);
+
+    let scale = (*width as f32 / (max_x - min_x)).min(*height as f32 / (max_y - min_y));
+    let to_minimap = move |x: f32, y: f32| ((x - min_x) * scale, (y - min_y) * scale);
+
+    let word_html = words.iter().map(|w|
+    {
+        let (w_width, w_height) = match w.direction
+        {
+            Direction::Right => (w.length as f32, 1f32),
+            Direction::Down => (1f32, w.length as f32),
+        };
+        let (x, y) = to_minimap(w.position.x as f32 * step, w.position.y as f32 * step);
+        let color = if w.error_exists { color_error_normal } else { color_light };
+        html! 
+        { 
+            <rect x={x.to_string()} y={y.to_string()} width={(w_width * step * scale - gap as f32 * scale).to_string()} height={(w_height * step * scale - gap as f32 * scale).to_string()} fill={color.to_string()}/> 
+        }
+    });
+
+    let error_html = error_cells.iter().map(|pos|
+    {
+        let (x, y) = to_minimap(pos.x as f32 * step, pos.y as f32 * step);
+        html! 
+        { 
+            <rect x={x.to_string()} y={y.to_string()} width={(cell_size as f32 * scale).to_string()} height={(cell_size as f32 * scale).to_string()} fill={color_error_dark.to_string()}/> 
+        }
+    });
+
+    let (viewport_x, viewport_y) = to_minimap(view_x, view_y);
+
+    html!
+    {
+        <svg class={classes!("playground_minimap",
+            css!
+            (
+                position: absolute;
+                right: 8px;
+                bottom: 8px;
+                border-radius: 8px;
+                cursor: pointer;
+            ),
+            css!
+            (
+                background-color: ${color_dark};
+                border: 2px solid${" "}${color_normal};
+            )
+        )}
+            width={width.to_string()}
+            height={height.to_string()}
+            onclick={on_click.reform(move |event: MouseEvent| (event.offset_x() as f32 / scale + min_x, event.offset_y() as f32 / scale + min_y))}
+            onmousedown={Callback::from(|event: MouseEvent| event.stop_propagation())}
+            onmousemove={Callback::from(|event: MouseEvent| event.stop_propagation())}
+            onwheel={Callback::from(|event: WheelEvent| event.stop_propagation())}
+        >
+            { for word_html }
+            { for error_html }
+            <rect x={viewport_x.to_string()} y={viewport_y.to_string()} width={(view_width * scale).to_string()} height={(view_height * scale).to_string()} fill="none" stroke="white" stroke-width="2"/>
+        </svg>
+    }
+}
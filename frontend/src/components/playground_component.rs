@@ -1,16 +1,24 @@
 #![allow(non_upper_case_globals)]
 use std::cell::Cell;
 use std::cmp::{max, min};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::default;
 use std::env::current_exe;
+use std::fmt::Display;
 use std::io::Empty;
 use std::iter::{empty, once, repeat};
 use std::ops::{Deref, DerefMut};
-use std::sync::Mutex;
 use std::thread::current;
 
 use _PlaygroundComponentProps::word_compatibility_settings;
+use common::alphabet::Locale;
+use common::analysis::Symmetry;
+use common::document::{DocumentWord, WordGroup, WordId};
+use common::duplicates::{duplicate_issue, DuplicateIssue, DuplicateSettings};
+use common::fill::{fill_slots, FillError, Slot};
+use common::grid::cell_at;
+use common::search::{SearchError, SearchMode, WordMatcher};
+use common::transform::{check_replacement, transform_words, Transform, TransformError};
 use crossword_generator::crossword::{Crossword, CrosswordError, WordCompatibilityError, WordCompatibilitySettings};
 use crossword_generator::placed_word::PlacedWord;
 use crossword_generator::traits::{CrosswordChar, CrosswordString};
@@ -29,17 +37,18 @@ use wasm_bindgen::JsValue;
 use gloo_utils::format::JsValueSerdeExt;
 use gloo_utils::document;
 use gloo_timers::callback::Timeout;
-use web_sys::{DataTransfer, Element, HtmlElement};
+use web_sys::{ClipboardEvent, DataTransfer, Element, HtmlElement};
 use web_sys::CssStyleDeclaration;
 use yew::virtual_dom::VNode;
 use web_sys::WheelEvent;
 
-use crate::components::playground_children_components::{PlaygroundBetweenCellComponent, PlaygroundCellComponent, PlaygroundWordComponent};
+use crate::components::playground_children_components::{PlaygroundBetweenCellComponent, PlaygroundCellComponent, PlaygroundSlotCellComponent, PlaygroundWordComponent};
 use crate::utils::color_rgba::ColorRGBA;
+use crate::utils::drag_transfer::{clear_drag_data, get_drag_data, get_dropped_drag_data, set_drag_data, DragDataType};
 use crate::utils::settings::StyleSettings;
 
 use super::super::utils::weak_component_link::WeakComponentLink;
-use super::playground_children_components::PlaygroundWordErrorOutlineComponent;
+use super::playground_children_components::{PlaygroundMinimapComponent, PlaygroundMinimapWord, PlaygroundWordErrorOutlineComponent};
 
 #[derive(Default, Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Hash)]
 pub enum PlaygroundWordState
@@ -49,24 +58,60 @@ pub enum PlaygroundWordState
     Selected,
 }
 
+#[derive(Default, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Debug, Hash)]
+pub enum PlaygroundMode
+{
+    #[default]
+    Edit,
+    DrawSlots,
+}
+
+const MIN_ZOOM: f32 = 0.02;
+const MAX_ZOOM: f32 = 2.0;
+const TOUCH_SLOP: f32 = 10.0;
+const LONG_PRESS_MS: u32 = 500;
+
+// what the touch or pen pointers are doing right now, mouse keeps using the mouse and html drag events
+#[derive(Default, Clone, PartialEq, Debug)]
+enum PointerGesture
+{
+    #[default]
+    None,
+    Pressing { pointer_id: i32, start_x: f32, start_y: f32, press_id: u32 },
+    MovingSelection { pointer_id: i32 },
+    Panning { pointer_id: i32 },
+    Pinching,
+}
+
 #[derive(Default, Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Hash)]
 pub struct PlaygroundWord<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
 {
     pub w: PlacedWord<CharT, StrT>,
     pub state: PlaygroundWordState,
+    pub locked: bool,
 }
 
 impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> PlaygroundWord<CharT, StrT>
 {
     fn new(w: PlacedWord<CharT, StrT>, state: PlaygroundWordState) -> PlaygroundWord<CharT, StrT>
     {
-        PlaygroundWord { w, state }
+        PlaygroundWord { w, state, locked: false }
     }
 
     fn from_placed_word(w: PlacedWord<CharT, StrT>) -> PlaygroundWord<CharT, StrT>
     {
         PlaygroundWord::new(w, PlaygroundWordState::Normal)
     }
+
+    fn from_document_word(w: DocumentWord<CharT, StrT>) -> PlaygroundWord<CharT, StrT>
+    {
+        PlaygroundWord { w: w.word, state: PlaygroundWordState::Normal, locked: w.locked }
+    }
+
+    fn to_document_word(&self, id: WordId) -> DocumentWord<CharT, StrT>
+    {
+        DocumentWord { id, word: self.w.clone(), locked: self.locked }
+    }
 }
 
 impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Deref for PlaygroundWord<CharT, StrT>
@@ -87,74 +132,114 @@ impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> DerefMut for Playground
     }
 }
 
-#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Serialize, Deserialize)]
-pub struct PlaygroundWordId
-{
-    id: usize,
-}
-
-impl PlaygroundWordId
-{
-    fn id(&self) -> usize { self.id }
-    fn counter() -> &'static Mutex<usize>
-    {
-        static COUNTER: Mutex<usize> = Mutex::new(0);
-        &COUNTER
-    }
-    fn new() -> PlaygroundWordId 
-    {
-        let mut counter = PlaygroundWordId::counter().lock().unwrap();
-        let new_id = *counter;
-        *counter += 1;
-        PlaygroundWordId{ id: new_id }
-    }
-}
-
 #[derive(PartialEq, Properties)]
 pub struct PlaygroundComponentProps<CharT, StrT>
 where 
-    CharT: CrosswordChar + ToHtml + 'static,
+    CharT: CrosswordChar + Display + 'static,
     StrT: CrosswordString<CharT> + 'static,
     CharT: Serialize + DeserializeOwned, 
     StrT: Serialize + DeserializeOwned,
 {
-    pub words: Vec<PlacedWord<CharT, StrT>>,
+    pub words: Vec<DocumentWord<CharT, StrT>>,
     pub word_compatibility_settings: WordCompatibilitySettings,
-    pub link: WeakComponentLink<PlaygroundComponent<CharT, StrT>>
+    #[prop_or_default]
+    pub duplicate_settings: DuplicateSettings,
+    // how the search folds the case
+    #[prop_or_default]
+    pub locale: Locale,
+    pub link: WeakComponentLink<PlaygroundComponent<CharT, StrT>>,
+    #[prop_or_default]
+    pub mode: PlaygroundMode,
+    #[prop_or(Callback::noop())]
+    pub on_fill: Callback<Result<(), FillError>>,
+    #[prop_or(Callback::noop())]
+    pub on_words_change: Callback<Vec<DocumentWord<CharT, StrT>>>,
+    #[prop_or(Callback::noop())]
+    pub on_transform: Callback<Result<(), TransformError>>,
+    // (number of the current match starting from 1, count of the matches)
+    #[prop_or(Callback::noop())]
+    pub on_find: Callback<Result<(usize, usize), SearchError>>,
+    #[prop_or_default]
+    pub groups: Vec<WordGroup>,
+    #[prop_or(Callback::noop())]
+    pub on_groups_change: Callback<Vec<WordGroup>>,
+    // when set, placing, moving or removing a word does the same with the mirrored slot
+    #[prop_or_default]
+    pub symmetry: Option<Symmetry>,
+    // doubled, so the center can also be between two cells
+    #[prop_or_default]
+    pub symmetry_center: (i16, i16),
 }
 
 #[derive(Debug, Clone)]
 pub enum PlaygroundComponentMessage<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
 {
     SetCrossword(Crossword<CharT, StrT>),
-    SetWords(Vec<PlacedWord<CharT, StrT>>),
+    SetWords(Vec<DocumentWord<CharT, StrT>>),
     AddWord(PlaygroundWord<CharT, StrT>),
-    RemoveWord(PlaygroundWordId),
-    ChangeWord(PlaygroundWordId, PlaygroundWord<CharT, StrT>),
-    SelectWord(PlaygroundWordId),
+    RemoveWord(WordId),
+    ChangeWord(WordId, PlaygroundWord<CharT, StrT>),
+    SelectWord(WordId),
     SelectAll,
-    DeselectWord(PlaygroundWordId),
+    // locked words stay where they are until they are unlocked
+    LockSelected,
+    UnlockAll,
+    // makes a group with this name from the selected words
+    GroupSelected(String),
+    DeselectWord(WordId),
     DeselectAll,
     SetDragging(bool),
-    StartDragging(Vec<PlacedWord<CharT, StrT>>),
+    StartDragging(Vec<DocumentWord<CharT, StrT>>),
     DropDragging,
+    // the words keep their ids when the grid doesn't have them yet
+    PasteWords(Vec<DocumentWord<CharT, StrT>>),
     EndDragging,
 
     MessageBatch(Vec<PlaygroundComponentMessage<CharT, StrT>>),
 
     Scroll(f32, f32),
     Zoom(f32),
+    ZoomAt(f32, f32, f32),
+    ZoomToFit,
+    ZoomToSelection,
+    CenterOn(f32, f32),
 
     SetDraggingOffset(f32, f32),
     SetDraggingDivPos(f32, f32),
+
+    RemoveSlot(WordId),
+    ClearSlots,
+    StartSlotDrawing(Position),
+    UpdateSlotDrawing(Position),
+    EndSlotDrawing,
+    FillSlots(Vec<StrT>),
+
+    // an empty query stops the search
+    Find(String, SearchMode),
+    FindNext,
+    FindPrevious,
+    SelectFound,
+
+    // the transformed selection is shown as a ghost until it is applied
+    PreviewTransform(Option<Transform>),
+    ApplyTransform,
+    // puts the words of the last applied transform back
+    UndoTransform,
+
+    PointerDown(i32, f32, f32),
+    PointerMove(i32, f32, f32),
+    PointerUp(i32),
+    LongPress(u32),
 }
 
 
-type WordsType<CharT, StrT> = HashMap<PlaygroundWordId, PlaygroundWord<CharT, StrT>>;
-type WordDataType = HashMap<PlaygroundWordId, Vec<(WordCompatibilityError, PlaygroundWordId)>>;
-type CellDataType = HashMap<Position, (Vec<(PlaygroundWordId, usize)>, Vec<(WordCompatibilityError, PlaygroundWordId)>)>;
-type BetweenCellDataType = HashMap<(Position, Direction), (Vec<PlaygroundWordId>, Vec<(WordCompatibilityError, PlaygroundWordId)>)>;
-type BetweenWordDataType = Vec<(WordCompatibilityError, (PlaygroundWordId, (i16, i16)), PlaygroundWordId)>;
+type WordsType<CharT, StrT> = HashMap<WordId, PlaygroundWord<CharT, StrT>>;
+type SlotsType<CharT> = HashMap<WordId, Slot<CharT>>;
+type WordDataType = HashMap<WordId, Vec<(WordCompatibilityError, WordId)>>;
+type DuplicateDataType = HashMap<WordId, Vec<(DuplicateIssue, WordId)>>;
+type CellDataType = HashMap<Position, (Vec<(WordId, usize)>, Vec<(WordCompatibilityError, WordId)>)>;
+type BetweenCellDataType = HashMap<(Position, Direction), (Vec<WordId>, Vec<(WordCompatibilityError, WordId)>)>;
+type BetweenWordDataType = Vec<(WordCompatibilityError, (WordId, (i16, i16)), WordId)>;
 
 #[derive(Clone)]
 pub struct PlaygroundComponent<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
@@ -162,7 +247,20 @@ pub struct PlaygroundComponent<CharT: CrosswordChar, StrT: CrosswordString<CharT
     words: WordsType<CharT, StrT>,
     word_compatibility_settings: WordCompatibilitySettings,
 
-    dragging_words: Vec<PlacedWord<CharT, StrT>>,
+    slots: SlotsType<CharT>,
+    // the slot keeps the id it is drawn with
+    slot_drawing: Option<(WordId, Position, Position)>,
+
+    transform_preview: Option<Transform>,
+    // the words of the last applied transform, before and after it
+    transform_undo: Vec<(DocumentWord<CharT, StrT>, DocumentWord<CharT, StrT>)>,
+
+    search: Option<WordMatcher>,
+    // the matching words in reading order
+    found: Vec<WordId>,
+    current_found: usize,
+
+    dragging_words: Vec<DocumentWord<CharT, StrT>>,
     dragging_mouse_offset_x: f32, 
     dragging_mouse_offset_y: f32, 
 
@@ -170,6 +268,10 @@ pub struct PlaygroundComponent<CharT: CrosswordChar, StrT: CrosswordString<CharT
     dragging_div_pos_y: f32, 
 
     currently_dragging: bool,
+
+    touch_pointers: HashMap<i32, (f32, f32)>,
+    pointer_gesture: PointerGesture,
+    press_counter: u32,
     
     transform_x: f32,
     transform_y: f32,
@@ -177,7 +279,11 @@ pub struct PlaygroundComponent<CharT: CrosswordChar, StrT: CrosswordString<CharT
 
     html: Vec<VNode>,
     dragging_html: Vec<VNode>,
+    preview_html: Vec<VNode>,
+    minimap_words: Vec<PlaygroundMinimapWord>,
+    minimap_error_cells: Vec<Position>,
 
+    wrapper_node_ref: NodeRef,
     playground_node_ref: NodeRef,
     dragging_node_ref: NodeRef,
     dragging_image_ref: NodeRef,
@@ -185,7 +291,7 @@ pub struct PlaygroundComponent<CharT: CrosswordChar, StrT: CrosswordString<CharT
 
 impl<CharT, StrT> PlaygroundComponent<CharT, StrT>
     where 
-        CharT: CrosswordChar + ToHtml + 'static,
+        CharT: CrosswordChar + Display + 'static,
         StrT: CrosswordString<CharT> + 'static,
         CharT: Serialize + DeserializeOwned,
         StrT: Serialize + DeserializeOwned,
@@ -193,58 +299,107 @@ impl<CharT, StrT> PlaygroundComponent<CharT, StrT>
     
     fn apply_message(&mut self, ctx: &Context<Self>, msg: PlaygroundComponentMessage<CharT, StrT>) -> bool
     {
-        let msg_clone = msg.clone();
-        log!(format!("Message {:?}", msg_clone));
+        // the dictionary of a fill and the pointer moves would flood the console
+        match &msg
+        {
+            PlaygroundComponentMessage::FillSlots(_) | PlaygroundComponentMessage::PointerMove(..) => (),
+            msg => log!(format!("Message {:?}", msg)),
+        }
         match msg
         {
             PlaygroundComponentMessage::SetWords(ws) => 
             {
-                self.words = ws.into_iter().map(|x| (PlaygroundWordId::new(), PlaygroundWord::from_placed_word(x))).collect(); 
+                // the ids are kept, so the words that are still there stay selected
+                let states = self.words.drain().map(|(w_id, w)| (w_id, w.state)).collect::<HashMap<_, _>>();
+                self.words = ws.into_iter().map(|x| 
+                {
+                    let mut w = PlaygroundWord::from_document_word(x.clone());
+                    if !w.locked { w.state = states.get(&x.id).cloned().unwrap_or_default(); }
+                    (x.id, w)
+                }).collect(); 
                 true
             },
             PlaygroundComponentMessage::SetCrossword(cw) => 
             { 
-                self.words = cw.into_iter().map(|x| (PlaygroundWordId::new(), PlaygroundWord::from_placed_word(x))).collect(); 
+                self.words = cw.into_iter().map(|x| (WordId::new(), PlaygroundWord::from_placed_word(x))).collect(); 
                 true 
             },
             PlaygroundComponentMessage::AddWord(w) => 
             { 
-                self.words.insert(PlaygroundWordId::new(), w); 
+                self.add_mirror_slots(ctx, &[Self::word_place(&w)]);
+                self.words.insert(WordId::new(), w); 
                 true
             },
-            PlaygroundComponentMessage::RemoveWord(w_id) => if let Some(_) = self.words.remove(&w_id) { true } else { false }
+            PlaygroundComponentMessage::RemoveWord(w_id) => if self.words.get(&w_id).is_some_and(|w| !w.locked)
+            { 
+                let w = self.words.remove(&w_id).unwrap();
+                self.remove_mirror_slots(ctx, &[Self::word_place(&w)]);
+                true 
+            } else { false }
             PlaygroundComponentMessage::ChangeWord(w_id, other) => 
                 if let Some(w) = self.words.get_mut(&w_id)
                 {
-                    if *w != other
+                    if *w != other && !w.locked
                     { 
                         *w = other;
                         true
                     } else { false }
                 } else { false }
-            PlaygroundComponentMessage::SelectWord(w_id) =>
+            // a word in a group selects the whole group
+            PlaygroundComponentMessage::SelectWord(w_id) => Self::group_members(ctx, w_id).into_iter().fold(false, |changed, w_id|
                 if let Some(w) = self.words.get_mut(&w_id)
                 {
-                    if w.state == PlaygroundWordState::Normal
+                    if w.state == PlaygroundWordState::Normal && !w.locked
                     { 
                         w.state = PlaygroundWordState::Selected;
                         true
-                    } else { false }
-                } else { false }
+                    } else { changed }
+                } else { changed }
+            ),
             PlaygroundComponentMessage::SelectAll =>
             {
-                self.words.iter_mut().filter(|(_, w)| w.state == PlaygroundWordState::Normal).for_each(|(_, w)| w.state = PlaygroundWordState::Selected);
+                self.words.iter_mut().filter(|(_, w)| w.state == PlaygroundWordState::Normal && !w.locked).for_each(|(_, w)| w.state = PlaygroundWordState::Selected);
+                true
+            }
+            PlaygroundComponentMessage::LockSelected =>
+            {
+                self.words.iter_mut().filter(|(_, w)| w.state == PlaygroundWordState::Selected).for_each(|(_, w)| 
+                {
+                    w.state = PlaygroundWordState::Normal;
+                    w.locked = true;
+                });
+                true
+            }
+            PlaygroundComponentMessage::UnlockAll =>
+            {
+                self.words.values_mut().for_each(|w| w.locked = false);
                 true
             }
-            PlaygroundComponentMessage::DeselectWord(w_id) => 
+            PlaygroundComponentMessage::GroupSelected(name) =>
+            {
+                let selected = self.words.iter().filter(|(_, w)| w.state == PlaygroundWordState::Selected).map(|(w_id, _)| *w_id).collect::<BTreeSet<_>>();
+                if selected.is_empty() || name.is_empty() { return false; }
+
+                // a word is only in one group, the groups left empty are removed
+                let mut groups = ctx.props().groups.iter().filter(|g| g.name != name).cloned().map(|mut g| 
+                {
+                    g.words.retain(|w_id| !selected.contains(w_id));
+                    g
+                }).filter(|g| !g.words.is_empty()).collect_vec();
+                groups.push(WordGroup { name, words: selected });
+                ctx.props().on_groups_change.emit(groups);
+                false
+            }
+            PlaygroundComponentMessage::DeselectWord(w_id) => Self::group_members(ctx, w_id).into_iter().fold(false, |changed, w_id|
                 if let Some(w) = self.words.get_mut(&w_id)
                 {
                     if w.state == PlaygroundWordState::Selected
                     { 
                         w.state = PlaygroundWordState::Normal;
                         true
-                    } else { false }
-                } else { false }
+                    } else { changed }
+                } else { changed }
+            ),
             PlaygroundComponentMessage::DeselectAll =>
             {
                 self.words.iter_mut().filter(|(_, w)| w.state == PlaygroundWordState::Selected).for_each(|(_, w)| w.state = PlaygroundWordState::Normal);
@@ -271,15 +426,17 @@ impl<CharT, StrT> PlaygroundComponent<CharT, StrT>
                 let ph_off_x = ((self.dragging_div_pos_x + (cell_size + gap) / 2.0) / (cell_size + gap)).floor() as isize;
                 let ph_off_y = ((self.dragging_div_pos_y + (cell_size + gap) / 2.0) / (cell_size + gap)).floor() as isize;
 
-                for w in self.dragging_words.iter()
+                let words = self.dragging_words.iter().cloned().map(|mut w|
                 {
-                    let pos = w.position.clone();
-                    let mut w = w.clone();
-                    let new_pos = Position { x: pos.x + ph_off_x as i16, y: pos.y + ph_off_y as i16 }; 
-                    w.position = new_pos;
-                    self.words.insert(PlaygroundWordId::new(), PlaygroundWord::from_placed_word(w));
-                }
-                
+                    w.position = Position { x: w.position.x + ph_off_x as i16, y: w.position.y + ph_off_y as i16 };
+                    w
+                }).collect_vec();
+                self.insert_words(ctx, words, PlaygroundWordState::Normal);
+                true
+            }
+            PlaygroundComponentMessage::PasteWords(words) =>
+            {
+                self.insert_words(ctx, words, PlaygroundWordState::Selected);
                 true
             }
             PlaygroundComponentMessage::EndDragging =>
@@ -293,21 +450,551 @@ impl<CharT, StrT> PlaygroundComponent<CharT, StrT>
                 let this = self.clone();
                 messages.into_iter().for_each(|msg| { self.apply_message(ctx, msg); });
 
-                this.words != self.words || this.dragging_words != self.dragging_words
+                this.words != self.words || this.dragging_words != self.dragging_words || this.slots != self.slots || this.slot_drawing != self.slot_drawing || this.transform_preview != self.transform_preview
             },
             
             PlaygroundComponentMessage::Scroll(amount_x, amount_y) => { self.transform_x += amount_x; self.transform_y += amount_y; false },
-            PlaygroundComponentMessage::Zoom(amount) => { self.transform_zoom *= amount; false },
+            PlaygroundComponentMessage::Zoom(amount) => { self.transform_zoom = (self.transform_zoom * amount).clamp(MIN_ZOOM, MAX_ZOOM); false },
+            PlaygroundComponentMessage::ZoomAt(x, y, amount) => { self.zoom_around(x, y, amount); false },
+            PlaygroundComponentMessage::ZoomToFit => 
+            {
+                let words = self.words.values().map(|w| &w.w).collect_vec();
+                self.zoom_to_words(ctx, &words);
+                false
+            }
+            PlaygroundComponentMessage::ZoomToSelection =>
+            {
+                let words = self.words.values().filter(|w| w.state == PlaygroundWordState::Selected).map(|w| &w.w).collect_vec();
+                self.zoom_to_words(ctx, &words);
+                false
+            }
+            PlaygroundComponentMessage::CenterOn(x, y) =>
+            {
+                let (view_width, view_height) = self.viewport_size();
+                self.transform_x = view_width / 2.0 - x * self.transform_zoom;
+                self.transform_y = view_height / 2.0 - y * self.transform_zoom;
+                false
+            }
 
             PlaygroundComponentMessage::SetDraggingOffset(x, y) => { self.dragging_mouse_offset_x = x; self.dragging_mouse_offset_y = y; false }
             PlaygroundComponentMessage::SetDraggingDivPos(x, y) => { self.dragging_div_pos_x = x; self.dragging_div_pos_y = y; false }
+
+            PlaygroundComponentMessage::RemoveSlot(s_id) => if let Some(slot) = self.slots.remove(&s_id)
+            {
+                self.remove_mirror_slots(ctx, &[(slot.position.clone(), slot.direction.clone(), slot.value.len())]);
+                true
+            } else { false }
+            PlaygroundComponentMessage::ClearSlots =>
+            {
+                let changed = !self.slots.is_empty();
+                self.slots.clear();
+                changed
+            }
+            PlaygroundComponentMessage::StartSlotDrawing(pos) =>
+            {
+                self.slot_drawing = Some((WordId::new(), pos.clone(), pos));
+                false
+            }
+            PlaygroundComponentMessage::UpdateSlotDrawing(pos) =>
+                if let Some((_, _, end)) = &mut self.slot_drawing
+                {
+                    if *end != pos
+                    {
+                        *end = pos;
+                        true
+                    } else { false }
+                } else { false }
+            PlaygroundComponentMessage::EndSlotDrawing =>
+            {
+                if let Some((s_id, slot)) = self.slot_drawing.take().and_then(|(s_id, start, end)| Self::slot_from_cells(&start, &end).map(|slot| (s_id, slot)))
+                {
+                    let place = (slot.position.clone(), slot.direction.clone(), slot.value.len());
+                    self.slots.insert(s_id, slot);
+                    self.add_mirror_slots(ctx, &[place]);
+                }
+                true
+            }
+            PlaygroundComponentMessage::FillSlots(dictionary) =>
+            {
+                let letters = PlaygroundComponent::calculate_cell_letters(&self.words);
+                let (slot_ids, slots): (Vec<_>, Vec<_>) = self.slots.iter().map(|(s_id, slot)|
+                {
+                    let mut slot = slot.clone();
+                    for i in 0..slot.value.len()
+                    {
+                        slot.value[i] = letters.get(&cell_at(&slot.position, &slot.direction, i)).cloned();
+                    }
+                    (*s_id, slot)
+                }).unzip();
+
+                // answers already placed on the playground can't be used again
+                let used_values = self.words.values().map(|w| w.value.clone()).collect::<HashSet<_>>();
+                let dictionary = dictionary.into_iter().filter(|w| !used_values.contains(w)).collect_vec();
+
+                match fill_slots(&slots, &dictionary)
+                {
+                    Ok(values) =>
+                    {
+                        // the word takes the id of its slot, so the groups of the slot stay with it
+                        for ((s_id, slot), value) in slot_ids.into_iter().zip(slots).zip(values)
+                        {
+                            self.slots.remove(&s_id);
+                            self.words.insert(s_id, PlaygroundWord::from_placed_word(PlacedWord::new(value, slot.position, slot.direction)));
+                        }
+                        ctx.props().on_fill.emit(Ok(()));
+                        true
+                    }
+                    Err(error) =>
+                    {
+                        ctx.props().on_fill.emit(Err(error));
+                        false
+                    }
+                }
+            }
+
+            PlaygroundComponentMessage::Find(query, mode) =>
+            {
+                self.current_found = 0;
+                self.search = None;
+                if !query.is_empty()
+                {
+                    match WordMatcher::new(&query, mode, ctx.props().locale)
+                    {
+                        Ok(matcher) => self.search = Some(matcher),
+                        Err(error) => ctx.props().on_find.emit(Err(error)),
+                    }
+                }
+                self.found = self.calculate_found();
+                if self.search.is_some() { self.show_current_found(ctx); }
+                true
+            }
+            PlaygroundComponentMessage::FindNext => self.step_found(ctx, 1),
+            PlaygroundComponentMessage::FindPrevious => self.step_found(ctx, self.found.len().saturating_sub(1)),
+            PlaygroundComponentMessage::SelectFound =>
+            {
+                let messages = once(PlaygroundComponentMessage::DeselectAll).chain(self.found.iter().map(|w_id| PlaygroundComponentMessage::SelectWord(*w_id))).collect_vec();
+                self.apply_message(ctx, PlaygroundComponentMessage::MessageBatch(messages))
+            }
+            PlaygroundComponentMessage::PreviewTransform(transform) =>
+            {
+                self.transform_preview = transform;
+                if let Some(Err(error)) = transform.map(|transform| self.transformed_selection(transform))
+                {
+                    self.transform_preview = None;
+                    ctx.props().on_transform.emit(Err(error));
+                }
+                true
+            }
+            PlaygroundComponentMessage::ApplyTransform =>
+            {
+                let Some(transform) = self.transform_preview.take() else { return false; };
+                match self.transformed_selection(transform)
+                {
+                    Ok(words) =>
+                    {
+                        ctx.props().on_transform.emit(Ok(()));
+                        self.transform_undo = words.iter().map(|w| (DocumentWord::new(w.id, self.words[&w.id].w.clone()), w.clone())).collect();
+                        self.change_words(ctx, words);
+                    }
+                    Err(error) => ctx.props().on_transform.emit(Err(error)),
+                }
+                true
+            }
+            PlaygroundComponentMessage::UndoTransform =>
+            {
+                let undo = std::mem::take(&mut self.transform_undo);
+                if undo.is_empty() { return false; }
+                let unchanged = undo.iter().all(|(_, after)| self.words.get(&after.id).is_some_and(|w| w.w == after.word));
+                let before = undo.into_iter().map(|(before, _)| before).collect_vec();
+                match if unchanged { check_replacement(&self.grid_words(), &before) } else { Err(TransformError::ChangedSince) }
+                {
+                    Ok(()) =>
+                    {
+                        ctx.props().on_transform.emit(Ok(()));
+                        self.change_words(ctx, before);
+                    }
+                    Err(error) => ctx.props().on_transform.emit(Err(error)),
+                }
+                true
+            }
+
+            PlaygroundComponentMessage::PointerDown(pointer_id, x, y) =>
+            {
+                self.touch_pointers.insert(pointer_id, (x, y));
+                match (self.touch_pointers.len(), &self.pointer_gesture)
+                {
+                    (1, _) =>
+                    {
+                        self.press_counter += 1;
+                        let press_id = self.press_counter;
+                        self.pointer_gesture = PointerGesture::Pressing { pointer_id, start_x: x, start_y: y, press_id };
+
+                        let ctx_link = ctx.link().clone();
+                        Timeout::new(LONG_PRESS_MS, move || ctx_link.send_message(PlaygroundComponentMessage::LongPress(press_id))).forget();
+                    }
+                    // a second finger while moving words is ignored, otherwise the gesture becomes a pinch
+                    (_, PointerGesture::MovingSelection { .. }) => (),
+                    (2, _) => self.pointer_gesture = PointerGesture::Pinching,
+                    _ => (),
+                }
+                false
+            }
+            PlaygroundComponentMessage::PointerMove(pointer_id, x, y) =>
+            {
+                let Some((prev_x, prev_y)) = self.touch_pointers.insert(pointer_id, (x, y)) else { return false; };
+                match self.pointer_gesture.clone()
+                {
+                    PointerGesture::Pressing { pointer_id: id, start_x, start_y, .. } if id == pointer_id && (x - start_x).hypot(y - start_y) > TOUCH_SLOP =>
+                    {
+                        // only a drag that starts on a selected word moves the selection, anywhere else it pans
+                        if self.word_at_point(ctx, start_x, start_y, PlaygroundWordState::Selected).is_some()
+                        {
+                            self.pointer_gesture = PointerGesture::MovingSelection { pointer_id };
+                            self.start_pointer_drag(ctx, start_x, start_y);
+                            self.move_pointer_drag(x, y);
+                            true
+                        }
+                        else
+                        {
+                            self.pointer_gesture = PointerGesture::Panning { pointer_id };
+                            self.transform_x += x - start_x;
+                            self.transform_y += y - start_y;
+                            false
+                        }
+                    }
+                    PointerGesture::MovingSelection { pointer_id: id } if id == pointer_id => { self.move_pointer_drag(x, y); false }
+                    PointerGesture::Panning { pointer_id: id } if id == pointer_id => 
+                    { 
+                        self.transform_x += x - prev_x; 
+                        self.transform_y += y - prev_y; 
+                        false 
+                    }
+                    PointerGesture::Pinching =>
+                    {
+                        let Some(&(other_x, other_y)) = self.touch_pointers.iter().find(|(id, _)| **id != pointer_id).map(|(_, pos)| pos) else { return false; };
+                        let (old_center_x, old_center_y) = ((prev_x + other_x) / 2.0, (prev_y + other_y) / 2.0);
+                        let (new_center_x, new_center_y) = ((x + other_x) / 2.0, (y + other_y) / 2.0);
+                        let old_distance = (prev_x - other_x).hypot(prev_y - other_y);
+                        let new_distance = (x - other_x).hypot(y - other_y);
+
+                        self.transform_x += new_center_x - old_center_x;
+                        self.transform_y += new_center_y - old_center_y;
+                        if old_distance > 0.0
+                        {
+                            self.zoom_around(new_center_x, new_center_y, new_distance / old_distance);
+                        }
+                        false
+                    }
+                    _ => false,
+                }
+            }
+            PlaygroundComponentMessage::PointerUp(pointer_id) =>
+            {
+                if self.touch_pointers.remove(&pointer_id).is_none() { return false; }
+                match self.pointer_gesture.clone()
+                {
+                    PointerGesture::MovingSelection { pointer_id: id } if id == pointer_id =>
+                    {
+                        self.pointer_gesture = PointerGesture::None;
+                        self.apply_message(ctx, PlaygroundComponentMessage::MessageBatch(vec![PlaygroundComponentMessage::DropDragging, PlaygroundComponentMessage::SetDragging(false), PlaygroundComponentMessage::EndDragging]))
+                    }
+                    PointerGesture::Pressing { pointer_id: id, .. } | PointerGesture::Panning { pointer_id: id } if id == pointer_id => 
+                    { 
+                        self.pointer_gesture = PointerGesture::None; 
+                        false 
+                    }
+                    // the finger left after a pinch shouldn't start panning
+                    PointerGesture::Pinching if self.touch_pointers.len() < 2 => 
+                    { 
+                        self.pointer_gesture = PointerGesture::None; 
+                        false 
+                    }
+                    _ => false,
+                }
+            }
+            PlaygroundComponentMessage::LongPress(press_id) =>
+            {
+                let PointerGesture::Pressing { start_x, start_y, press_id: current_press_id, .. } = self.pointer_gesture.clone() else { return false; };
+                if press_id != current_press_id { return false; }
+
+                // long press adds the pressed word to the selection, like a ctrl click
+                match self.word_at_point(ctx, start_x, start_y, PlaygroundWordState::Normal)
+                {
+                    Some(w_id) => self.apply_message(ctx, PlaygroundComponentMessage::SelectWord(w_id)),
+                    None => false,
+                }
+            }
         }
     }
 
+    // the word in the given state that has a cell under the client point
+    fn word_at_point(&self, ctx: &Context<Self>, client_x: f32, client_y: f32, state: PlaygroundWordState) -> Option<WordId>
+    {
+        let playground = self.playground_node_ref.cast::<HtmlElement>()?;
+        let style_settings = ctx.link().context::<StyleSettings>(Callback::noop()).unwrap().0;
+        let cell = client_to_cell(&playground, client_x as i32, client_y as i32, self.transform_zoom, &style_settings);
+
+        self.words.iter()
+            .filter(|(_, w)| w.state == state)
+            .find(|(_, w)| (0..w.value.as_ref().len()).any(|i| match &w.direction
+            {
+                Direction::Right => Position { x: w.position.x + i as i16, y: w.position.y } == cell,
+                Direction::Down => Position { x: w.position.x, y: w.position.y + i as i16 } == cell,
+            }))
+            .map(|(w_id, _)| *w_id)
+    }
+
+    fn viewport_size(&self) -> (f32, f32)
+    {
+        self.wrapper_node_ref.cast::<HtmlElement>()
+            .map(|wrapper| (wrapper.client_width() as f32, wrapper.client_height() as f32))
+            .unwrap_or_default()
+    }
+
+    // (x, y, width, height) of the visible part in playground pixels
+    fn viewport(&self) -> (f32, f32, f32, f32)
+    {
+        let (view_width, view_height) = self.viewport_size();
+        (-self.transform_x / self.transform_zoom, -self.transform_y / self.transform_zoom, view_width / self.transform_zoom, view_height / self.transform_zoom)
+    }
+
+    fn zoom_to_words(&mut self, ctx: &Context<Self>, words: &[&PlacedWord<CharT, StrT>])
+    {
+        if words.is_empty() { return; }
+
+        let set = ctx.link().context::<StyleSettings>(Callback::noop()).unwrap().0;
+        let step = (set.playground_style_settings.cell_size + set.playground_style_settings.gap) as f32;
+        let gap = set.playground_style_settings.gap as f32;
+
+        let (min_x, min_y, max_x, max_y) = words.iter().fold((i16::MAX, i16::MAX, i16::MIN, i16::MIN), |(min_x, min_y, max_x, max_y), w|
+        {
+            let (last_x, last_y) = match &w.direction
+            {
+                Direction::Right => (w.position.x + w.value.as_ref().len() as i16 - 1, w.position.y),
+                Direction::Down => (w.position.x, w.position.y + w.value.as_ref().len() as i16 - 1),
+            };
+            (min(min_x, w.position.x), min(min_y, w.position.y), max(max_x, last_x), max(max_y, last_y))
+        });
+
+        let (left, top) = (min_x as f32 * step, min_y as f32 * step);
+        let (content_width, content_height) = ((max_x - min_x + 1) as f32 * step - gap, (max_y - min_y + 1) as f32 * step - gap);
+        let (view_width, view_height) = self.viewport_size();
+
+        // a little margin around, so the words aren't glued to the edges
+        self.transform_zoom = (0.9 * (view_width / content_width).min(view_height / content_height)).clamp(MIN_ZOOM, MAX_ZOOM);
+        self.transform_x = (view_width - content_width * self.transform_zoom) / 2.0 - left * self.transform_zoom;
+        self.transform_y = (view_height - content_height * self.transform_zoom) / 2.0 - top * self.transform_zoom;
+    }
+
+    fn zoom_around(&mut self, client_x: f32, client_y: f32, zoom: f32)
+    {
+        let Some(wrapper) = self.wrapper_node_ref.cast::<HtmlElement>() else { return; };
+        let wrapper_bounding_box = wrapper.get_bounding_client_rect();
+        let zoom = (self.transform_zoom * zoom).clamp(MIN_ZOOM, MAX_ZOOM) / self.transform_zoom;
+
+        self.transform_x = self.transform_x * zoom + (client_x - wrapper_bounding_box.left() as f32) * (1f32 - zoom);
+        self.transform_y = self.transform_y * zoom + (client_y - wrapper_bounding_box.top() as f32) * (1f32 - zoom);
+        self.transform_zoom *= zoom;
+    }
+
+    // the same as the html drag, but without a DataTransfer, so it works where html drag doesn't
+    fn start_pointer_drag(&mut self, ctx: &Context<Self>, client_x: f32, client_y: f32)
+    {
+        let Some(playground) = self.playground_node_ref.cast::<HtmlElement>() else { return; };
+        let playground_bounding_box = playground.get_bounding_client_rect();
+
+        self.dragging_mouse_offset_x = (client_x - playground_bounding_box.left() as f32) / self.transform_zoom;
+        self.dragging_mouse_offset_y = (client_y - playground_bounding_box.top() as f32) / self.transform_zoom;
+
+        let selected_ids = self.words.iter().filter(|(_, w)| w.state == PlaygroundWordState::Selected).map(|(w_id, _)| *w_id).collect_vec();
+        self.dragging_words = selected_ids.iter().filter_map(|w_id| self.words.remove(w_id).map(|w| w.to_document_word(*w_id))).collect();
+        let places = self.dragging_words.iter().map(|w| Self::word_place(w)).collect_vec();
+        self.remove_mirror_slots(ctx, &places);
+        self.currently_dragging = true;
+    }
+
+    fn calculate_found(&self) -> Vec<WordId>
+    {
+        let Some(matcher) = &self.search else { return Vec::new(); };
+        self.words.iter()
+            .filter(|(_, w)| matcher.matches(&w.value.as_ref().iter().map(ToString::to_string).collect_vec()))
+            .sorted_by_key(|(w_id, w)| (w.position.y, w.position.x, w.direction.clone(), **w_id))
+            .map(|(w_id, _)| *w_id)
+            .collect()
+    }
+
+    fn step_found(&mut self, ctx: &Context<Self>, step: usize) -> bool
+    {
+        if self.found.is_empty() { return false; }
+        self.current_found = (self.current_found + step) % self.found.len();
+        self.show_current_found(ctx);
+        true
+    }
+
+    // centers the view on the current match and tells the parent where the search is
+    fn show_current_found(&mut self, ctx: &Context<Self>)
+    {
+        ctx.props().on_find.emit(Ok((if self.found.is_empty() { 0 } else { self.current_found + 1 }, self.found.len())));
+        let Some(w) = self.found.get(self.current_found).and_then(|w_id| self.words.get(w_id)) else { return; };
+
+        let set = ctx.link().context::<StyleSettings>(Callback::noop()).unwrap().0;
+        let step = (set.playground_style_settings.cell_size + set.playground_style_settings.gap) as f32;
+        let cell_size = set.playground_style_settings.cell_size as f32;
+        let (width, height) = match &w.direction
+        {
+            Direction::Right => (w.value.as_ref().len() as f32, 1.0),
+            Direction::Down => (1.0, w.value.as_ref().len() as f32),
+        };
+        let x = w.position.x as f32 * step + ((width - 1.0) * step + cell_size) / 2.0;
+        let y = w.position.y as f32 * step + ((height - 1.0) * step + cell_size) / 2.0;
+        self.apply_message(ctx, PlaygroundComponentMessage::CenterOn(x, y));
+    }
+
+    // a word keeps its id, unless a word with the same id is already here (dropped or pasted into another copy of the document),
+    // then the new id joins the group of the old one
+    fn insert_words(&mut self, ctx: &Context<Self>, words: Vec<DocumentWord<CharT, StrT>>, state: PlaygroundWordState)
+    {
+        let places = words.iter().map(|w| Self::word_place(w)).collect_vec();
+        let mut groups = ctx.props().groups.clone();
+        let mut regrouped = false;
+        for w in words
+        {
+            let w_id = if self.words.contains_key(&w.id) { WordId::new() } else { w.id };
+            if w_id != w.id
+            {
+                for g in groups.iter_mut().filter(|g| g.words.contains(&w.id))
+                {
+                    g.words.insert(w_id);
+                    regrouped = true;
+                }
+            }
+            self.words.insert(w_id, PlaygroundWord { state: state.clone(), ..PlaygroundWord::from_document_word(w) });
+        }
+        self.add_mirror_slots(ctx, &places);
+        if regrouped { ctx.props().on_groups_change.emit(groups); }
+    }
+
+    // the word with the other words of its group
+    fn group_members(ctx: &Context<Self>, w_id: WordId) -> Vec<WordId>
+    {
+        once(w_id).chain(ctx.props().groups.iter().filter(|g| g.words.contains(&w_id)).flat_map(|g| g.words.iter().copied())).unique().collect()
+    }
+
+    // one batch, so the whole transform is a single change
+    fn change_words(&mut self, ctx: &Context<Self>, words: Vec<DocumentWord<CharT, StrT>>)
+    {
+        let messages = words.into_iter().map(|w| PlaygroundComponentMessage::ChangeWord(w.id, PlaygroundWord::new(w.word, PlaygroundWordState::Selected))).collect_vec();
+        self.apply_message(ctx, PlaygroundComponentMessage::MessageBatch(messages));
+    }
+
+    fn grid_words(&self) -> Vec<DocumentWord<CharT, StrT>>
+    {
+        self.words.iter().map(|(w_id, w)| DocumentWord::new(*w_id, w.w.clone())).collect()
+    }
+
+    fn transformed_selection(&self, transform: Transform) -> Result<Vec<DocumentWord<CharT, StrT>>, TransformError>
+    {
+        let selected = self.words.iter().filter(|(_, w)| w.state == PlaygroundWordState::Selected).map(|(w_id, _)| *w_id).collect::<HashSet<_>>();
+        transform_words(&self.grid_words(), &selected, transform)
+    }
+
+    // (position, direction, length) of a word or a slot
+    fn word_place(w: &PlacedWord<CharT, StrT>) -> (Position, Direction, usize)
+    {
+        (w.position.clone(), w.direction.clone(), w.value.as_ref().len())
+    }
+
+    // the place mirroring the given one, None outside of symmetry mode or when the place is its own mirror
+    fn mirror_place(ctx: &Context<Self>, (position, direction, length): &(Position, Direction, usize)) -> Option<(Position, Direction, usize)>
+    {
+        let symmetry = ctx.props().symmetry?;
+        let mirror_position = symmetry.mirror_start(position, direction, *length, ctx.props().symmetry_center);
+        (mirror_position != *position).then(|| (mirror_position, direction.clone(), *length))
+    }
+
+    fn add_mirror_slots(&mut self, ctx: &Context<Self>, places: &[(Position, Direction, usize)])
+    {
+        for place in places.iter().filter_map(|place| Self::mirror_place(ctx, place))
+        {
+            let taken = self.words.values().any(|w| Self::word_place(w) == place) 
+                || self.slots.values().any(|slot| (slot.position.clone(), slot.direction.clone(), slot.value.len()) == place);
+            if !taken
+            {
+                let (position, direction, length) = place;
+                self.slots.insert(WordId::new(), PlacedWord::new(vec![None; length], position, direction));
+            }
+        }
+    }
+
+    // only the mirrored slots go away, the words are never removed by the symmetry
+    fn remove_mirror_slots(&mut self, ctx: &Context<Self>, places: &[(Position, Direction, usize)])
+    {
+        for place in places.iter().filter_map(|place| Self::mirror_place(ctx, place))
+        {
+            self.slots.retain(|_, slot| (slot.position.clone(), slot.direction.clone(), slot.value.len()) != place);
+        }
+    }
+
+    // the word cells whose mirror cell has neither a word nor a slot
+    fn calculate_asymmetric_cells(&self, ctx: &Context<Self>, cell_data: &CellDataType) -> HashSet<Position>
+    {
+        let Some(symmetry) = ctx.props().symmetry else { return HashSet::new(); };
+        let center = ctx.props().symmetry_center;
+        let slot_cells = self.slots.values().flat_map(|slot| (0..slot.value.len()).map(|i| cell_at(&slot.position, &slot.direction, i))).collect::<HashSet<_>>();
+        cell_data.keys()
+            .filter(|pos| 
+            {
+                let mirror = symmetry.mirror(pos, center);
+                !cell_data.contains_key(&mirror) && !slot_cells.contains(&mirror)
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn move_pointer_drag(&mut self, client_x: f32, client_y: f32)
+    {
+        let Some(playground) = self.playground_node_ref.cast::<HtmlElement>() else { return; };
+        let playground_bounding_box = playground.get_bounding_client_rect();
+
+        self.dragging_div_pos_x = (client_x - playground_bounding_box.left() as f32) / self.transform_zoom - self.dragging_mouse_offset_x;
+        self.dragging_div_pos_y = (client_y - playground_bounding_box.top() as f32) / self.transform_zoom - self.dragging_mouse_offset_y;
+    }
+
+    fn slot_from_cells(start: &Position, end: &Position) -> Option<Slot<CharT>>
+    {
+        let (dx, dy) = (end.x - start.x, end.y - start.y);
+        let (position, direction, length) = if dx.abs() >= dy.abs()
+            { (Position { x: min(start.x, end.x), y: start.y }, Direction::Right, dx.unsigned_abs() as usize + 1) }
+        else
+            { (Position { x: start.x, y: min(start.y, end.y) }, Direction::Down, dy.unsigned_abs() as usize + 1) };
+
+        (length >= 2).then(|| PlacedWord::new(vec![None; length], position, direction))
+    }
+
+    fn calculate_cell_letters(words: &WordsType<CharT, StrT>) -> HashMap<Position, CharT>
+    {
+        let mut letters: HashMap<Position, HashSet<CharT>> = HashMap::new();
+        for w in words.values()
+        {
+            for (i, ch) in w.value.as_ref().iter().enumerate()
+            {
+                let pos = match &w.direction
+                {
+                    Direction::Right => Position { x: w.position.x + i as i16, y: w.position.y },
+                    Direction::Down => Position { x: w.position.x, y: w.position.y + i as i16 },
+                };
+                letters.entry(pos).or_default().insert(ch.clone());
+            }
+        }
+
+        // cells with conflicting letters are left free
+        letters.into_iter().filter(|(_, chars)| chars.len() == 1).map(|(pos, chars)| (pos, chars.into_iter().next().unwrap())).collect()
+    }
+
 
     fn calculate_word_data(words: &WordsType<CharT, StrT>, word_comp_settings: &WordCompatibilitySettings) -> WordDataType
     {
-        let mut word_data = words.keys().map(|i| (*i, (Vec::<(WordCompatibilityError, PlaygroundWordId)>::default()))).collect::<HashMap<_, _>>();
+        let mut word_data = words.keys().map(|i| (*i, (Vec::<(WordCompatibilityError, WordId)>::default()))).collect::<HashMap<_, _>>();
         for comb in words.keys().combinations(2)
         {
             if let Some(error) = word_comp_settings.word_compatibility_issue(&words[comb[0]], &words[comb[1]])
@@ -319,6 +1006,21 @@ impl<CharT, StrT> PlaygroundComponent<CharT, StrT>
         word_data
     }
 
+    // repeated answers can be anywhere on the grid, so they are kept apart from the compatibility errors of the neighbours
+    fn calculate_duplicate_data(words: &WordsType<CharT, StrT>, duplicate_settings: &DuplicateSettings) -> DuplicateDataType
+    {
+        let mut duplicate_data: DuplicateDataType = HashMap::new();
+        for comb in words.keys().combinations(2)
+        {
+            if let Some(issue) = duplicate_issue(words[comb[0]].value.as_ref(), words[comb[1]].value.as_ref(), duplicate_settings)
+            {
+                duplicate_data.entry(*comb[0]).or_default().push((issue, *comb[1]));
+                duplicate_data.entry(*comb[1]).or_default().push((issue, *comb[0]));
+            }
+        }
+        duplicate_data
+    }
+
     fn calculate_cell_data(word_data: &WordDataType, words: &WordsType<CharT, StrT>) -> CellDataType
     {
         let mut cell_data: CellDataType = HashMap::new();
@@ -485,21 +1187,23 @@ impl<CharT, StrT> PlaygroundComponent<CharT, StrT>
             .collect()
     }
 
-    fn generate_cell_html(cell_data: &CellDataType, words: &WordsType<CharT, StrT>, ctx: &Context<Self>) -> Vec<VNode>
+    fn generate_cell_html(cell_data: &CellDataType, asymmetric_cells: &HashSet<Position>, found_words: &HashSet<WordId>, words: &WordsType<CharT, StrT>, ctx: &Context<Self>) -> Vec<VNode>
     {
         cell_data.iter().map(|(pos, (words_and_indexes, compatibility_errors))|
         {
             let characters = words_and_indexes.iter().map(|(w_id, i)| words[w_id].value.as_ref()[*i].clone()).collect::<HashSet<_>>();
             let character = (characters.len() == 1).then_some(characters.into_iter().next().unwrap());
-            let word_ids = words_and_indexes.iter().map(|(w_id, _)| w_id.id()).collect_vec();
+            let word_ids = words_and_indexes.iter().map(|(w_id, _)| *w_id).collect_vec();
             let selected = words_and_indexes.iter().any(|(w_id, _)| words[w_id].state == PlaygroundWordState::Selected);
+            let locked = words_and_indexes.iter().any(|(w_id, _)| words[w_id].locked);
+            let found = words_and_indexes.iter().any(|(w_id, _)| found_words.contains(w_id));
 
             let state = if selected { PlaygroundWordState::Selected } else { PlaygroundWordState::Normal };
 
 
             html!
             {
-                <PlaygroundCellComponent<CharT> character={character} word_ids={word_ids} position={pos.clone()} state={state} 
+                <PlaygroundCellComponent<CharT> character={character} word_ids={word_ids} position={pos.clone()} state={state} asymmetric={asymmetric_cells.contains(pos)} locked={locked} found={found}
                     // on_invert_word_direction=
                     // {
                     //     let ctx_link = ctx.link().clone();
@@ -537,11 +1241,10 @@ impl<CharT, StrT> PlaygroundComponent<CharT, StrT>
     {
         between_cell_data.iter().map(|((pos, dir), (word_ids, compatibility_errors))|
         {
-            let word_ids_nums = word_ids.iter().map(|w_id| w_id.id()).collect_vec();
             let draggable = word_ids.iter().any(|id| words[id].state == PlaygroundWordState::Selected);
             html!
             {
-                <PlaygroundBetweenCellComponent position={pos.clone()} direction={dir.clone()} word_ids={word_ids_nums} draggable={draggable}
+                <PlaygroundBetweenCellComponent position={pos.clone()} direction={dir.clone()} word_ids={word_ids.clone()} draggable={draggable}
                     on_select=
                     { 
                         let ctx_link = ctx.link().clone();
@@ -558,7 +1261,7 @@ impl<CharT, StrT> PlaygroundComponent<CharT, StrT>
         }).collect_vec()
     }
 
-    fn generate_word_html(word_data: &WordDataType, words: &WordsType<CharT, StrT>) -> Vec<VNode>
+    fn generate_word_html(word_data: &WordDataType, duplicate_data: &DuplicateDataType, grouped_words: &HashSet<WordId>, current_found: Option<WordId>, words: &WordsType<CharT, StrT>) -> Vec<VNode>
     {
         word_data.iter().map(|(w_id, errors)| 
         {
@@ -571,7 +1274,7 @@ impl<CharT, StrT> PlaygroundComponent<CharT, StrT>
 
             html!
             {
-                <PlaygroundWordComponent position={w.position.clone()} width={width} height={height} id={w_id.id()} error_exists={!errors.is_empty()} state={w.state.clone()}/>
+                <PlaygroundWordComponent position={w.position.clone()} width={width} height={height} id={*w_id} error_exists={!errors.is_empty() || duplicate_data.contains_key(w_id)} state={w.state.clone()} grouped={grouped_words.contains(w_id)} current_found={current_found == Some(*w_id)}/>
             }
         }).collect_vec()
     }
@@ -586,8 +1289,59 @@ impl<CharT, StrT> PlaygroundComponent<CharT, StrT>
         }).collect_vec()
     }
 
+    fn generate_slot_html(slots: &SlotsType<CharT>, slot_drawing: &Option<(WordId, Position, Position)>, cell_data: &CellDataType, ctx: &Context<Self>) -> Vec<VNode>
+    {
+        let mut slot_cell_data: HashMap<Position, Vec<WordId>> = HashMap::new();
+        for (s_id, slot) in slots.iter()
+        {
+            for i in 0..slot.value.len()
+            {
+                slot_cell_data.entry(cell_at(&slot.position, &slot.direction, i)).or_default().push(*s_id);
+            }
+        }
+
+        // cells already covered by words show their letters, only the free ones are drawn
+        let cell_html = slot_cell_data.into_iter().filter(|(pos, _)| !cell_data.contains_key(pos)).map(|(pos, slot_ids)|
+        {
+            html!
+            {
+                <PlaygroundSlotCellComponent position={pos} slot_ids={slot_ids.clone()}
+                    on_remove=
+                    {
+                        let ctx_link = ctx.link().clone();
+                        Callback::from(move |_| ctx_link.send_message(PlaygroundComponentMessage::MessageBatch(slot_ids.iter().map(|s_id| PlaygroundComponentMessage::RemoveSlot(*s_id)).collect_vec())))
+                    }
+                />
+            }
+        });
+
+        let drawing_slot = slot_drawing.as_ref().and_then(|(s_id, start, end)| Self::slot_from_cells(start, end).map(|slot| (*s_id, slot, PlaygroundWordState::Selected)));
+
+        let outline_html = slots.iter()
+            .map(|(s_id, slot)| (*s_id, slot.clone(), PlaygroundWordState::Normal))
+            .chain(drawing_slot)
+            .map(|(s_id, slot, state)|
+        {
+            let (width, height) = match &slot.direction
+            {
+                Direction::Right => (slot.value.len(), 1),
+                Direction::Down => (1, slot.value.len()),
+            };
+
+            html!
+            {
+                <PlaygroundWordComponent position={slot.position.clone()} width={width} height={height} id={s_id} error_exists={false} state={state}/>
+            }
+        });
+
+        cell_html.chain(outline_html).collect_vec()
+    }
+
     fn recalculate_main_drawing_data(&mut self, ctx: &Context<Self>)
     {
+        self.found = self.calculate_found();
+        self.current_found = self.current_found.min(self.found.len().saturating_sub(1));
+
         let word_data = PlaygroundComponent::calculate_word_data(&self.words, &self.word_compatibility_settings);
 
         let cell_data = PlaygroundComponent::calculate_cell_data(&word_data, &self.words);
@@ -598,113 +1352,108 @@ impl<CharT, StrT> PlaygroundComponent<CharT, StrT>
 
 
 
-        let cell_html = PlaygroundComponent::generate_cell_html(&cell_data, &self.words, ctx).into_iter();
+        let asymmetric_cells = self.calculate_asymmetric_cells(ctx, &cell_data);
+
+        let found_words = self.found.iter().copied().collect::<HashSet<_>>();
+
+        let cell_html = PlaygroundComponent::generate_cell_html(&cell_data, &asymmetric_cells, &found_words, &self.words, ctx).into_iter();
 
         let between_cell_html = PlaygroundComponent::generate_between_cell_html(&between_cell_data, &self.words, ctx).into_iter();
 
-        let word_html = PlaygroundComponent::generate_word_html(&word_data, &self.words).into_iter();
+        let grouped_words = ctx.props().groups.iter().flat_map(|g| g.words.iter().copied()).collect::<HashSet<_>>();
+
+        let duplicate_data = PlaygroundComponent::calculate_duplicate_data(&self.words, &ctx.props().duplicate_settings);
+
+        let word_html = PlaygroundComponent::generate_word_html(&word_data, &duplicate_data, &grouped_words, self.found.get(self.current_found).copied(), &self.words).into_iter();
 
         let between_word_html = PlaygroundComponent::generate_between_word_html(&between_word_data, &self.words).into_iter();
 
-        self.html = cell_html.chain(between_cell_html).chain(word_html).chain(between_word_html).collect();
+        let slot_html = PlaygroundComponent::generate_slot_html(&self.slots, &self.slot_drawing, &cell_data, ctx).into_iter();
+
+        self.minimap_words = word_data.iter().map(|(w_id, errors)| 
+        {
+            let w = &self.words[w_id];
+            PlaygroundMinimapWord { position: w.position.clone(), direction: w.direction.clone(), length: w.value.as_ref().len(), error_exists: !errors.is_empty() || duplicate_data.contains_key(w_id) }
+        }).collect();
+        self.minimap_error_cells = cell_data.iter()
+            .filter(|(_, (words_and_indexes, _))| words_and_indexes.iter().map(|(w_id, i)| &self.words[w_id].value.as_ref()[*i]).unique().count() > 1)
+            .map(|(pos, _)| pos.clone())
+            .collect();
+
+        self.html = slot_html.chain(cell_html).chain(between_cell_html).chain(word_html).chain(between_word_html).collect();
 
     }
 
     fn recalculate_dragging_drawing_data(&mut self, ctx: &Context<Self>)
     {
-        let dragging_words = self.dragging_words.iter().map(|w| (PlaygroundWordId::new(), PlaygroundWord::from_placed_word(w.clone()))).collect();
+        let dragging_words = self.dragging_words.iter().map(|w| (w.id, PlaygroundWord::from_placed_word(w.word.clone()))).collect();
 
         let word_data = PlaygroundComponent::calculate_word_data(&dragging_words, &self.word_compatibility_settings);
 
         let cell_data = PlaygroundComponent::calculate_cell_data(&word_data, &dragging_words);
 
-        let cell_html = PlaygroundComponent::generate_cell_html(&cell_data, &dragging_words, ctx);
+        let cell_html = PlaygroundComponent::generate_cell_html(&cell_data, &HashSet::new(), &HashSet::new(), &dragging_words, ctx);
 
         self.dragging_html = cell_html;
     }
 
-    fn recalculate_drawing_data(&mut self, ctx: &Context<Self>)
+    fn recalculate_preview_drawing_data(&mut self, ctx: &Context<Self>)
     {
-        self.recalculate_main_drawing_data(ctx);
-        self.recalculate_dragging_drawing_data(ctx);
-    }
-}
+        let preview_words = self.transform_preview
+            .and_then(|transform| self.transformed_selection(transform).ok())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|w| (w.id, PlaygroundWord::from_placed_word(w.word)))
+            .collect();
 
-#[derive(Serialize, Deserialize, Debug)]
-struct DragDataType<CharT, StrT>
-where 
-    CharT: CrosswordChar,
-    StrT: CrosswordString<CharT>,
-{
-    dragging_mouse_offset_x: f32,
-    dragging_mouse_offset_y: f32,
-    data: Vec<PlacedWord<CharT, StrT>>
-}
+        let word_data = PlaygroundComponent::calculate_word_data(&preview_words, &self.word_compatibility_settings);
 
-fn encode_uppercase(s: &str) -> String
-{
-    s.chars()
-        .chunk_by(|ch| ch.is_ascii_uppercase()).into_iter()
-        .map(|(is_upper, ch)| 
-    {
-        let s = ch.collect::<String>();
-        if is_upper { "^[".to_owned() + &s + "]^" } else { s }
-    }).fold(String::default(), |a, b| a + &b)
-}
+        let cell_data = PlaygroundComponent::calculate_cell_data(&word_data, &preview_words);
 
-fn decode_uppercase(mut s: &str) -> String
-{
-    let mut current_case = false;
-    let mut answer = String::default();
+        self.preview_html = PlaygroundComponent::generate_cell_html(&cell_data, &HashSet::new(), &HashSet::new(), &preview_words, ctx);
+    }
 
-    while let Some(ind) = 
+    fn recalculate_drawing_data(&mut self, ctx: &Context<Self>)
     {
-        if !current_case 
-        { 
-            s.find("^[")
-        }
-        else
-        {
-            s.find("]^")
-        }
+        self.recalculate_main_drawing_data(ctx);
+        self.recalculate_dragging_drawing_data(ctx);
+        self.recalculate_preview_drawing_data(ctx);
     }
+
+    // the words in the order of the words prop, the new ones at the end
+    fn document_words(&self, ctx: &Context<Self>) -> Vec<DocumentWord<CharT, StrT>>
     {
-        answer += &(if !current_case { s[..ind].to_owned() } else { s[..ind].to_ascii_uppercase() });
-        current_case = !current_case;
-        s = &s[ind + 2..]
+        let order = ctx.props().words.iter().enumerate().map(|(i, w)| (w.id, i)).collect::<HashMap<_, _>>();
+        self.words.iter()
+            .map(|(w_id, w)| w.to_document_word(*w_id))
+            .sorted_by_key(|w| (order.get(&w.id).copied().unwrap_or(usize::MAX), w.id))
+            .collect()
     }
 
-    if s != ""
+    fn notify_words_change(&self, ctx: &Context<Self>)
     {
-        answer += s;
+        let words = self.document_words(ctx);
+        if words != ctx.props().words
+        {
+            ctx.props().on_words_change.emit(words);
+        }
     }
-
-    answer
 }
 
-fn get_drag_data<CharT, StrT>(data_transfer: &DataTransfer) -> Option<DragDataType<CharT, StrT>>
-where 
-    CharT: CrosswordChar + DeserializeOwned,
-    StrT: CrosswordString<CharT> + DeserializeOwned,
+fn client_to_cell(playground: &HtmlElement, client_x: i32, client_y: i32, zoom: f32, style_settings: &StyleSettings) -> Position
 {
-    data_transfer.types().iter()
-        .map(|v| v.as_string().unwrap())
-        .filter(|v| v.starts_with("application/x.word-data-"))
-        .filter_map(|data| serde_json::from_str::<DragDataType<CharT, StrT>>(&decode_uppercase(&data.replace("application/x.word-data-", ""))).ok())
-        .next()
-}
+    let playground_bounding_box = playground.get_bounding_client_rect();
+    let cell_size = style_settings.playground_style_settings.cell_size as f32;
+    let gap = style_settings.playground_style_settings.gap as f32;
 
-fn set_drag_data<CharT, StrT>(data_transfer: &DataTransfer, drag_data: &DragDataType<CharT, StrT>)
-where 
-    CharT: CrosswordChar + Serialize,
-    StrT: CrosswordString<CharT> + Serialize,
-{
-    data_transfer.set_data(&format!("application/x.word-data-{}", encode_uppercase(&serde_json::to_string(drag_data).unwrap())), "").unwrap();
+    let x = (client_x as f32 - playground_bounding_box.left() as f32) / zoom;
+    let y = (client_y as f32 - playground_bounding_box.top() as f32) / zoom;
+    Position { x: (x / (cell_size + gap)).floor() as i16, y: (y / (cell_size + gap)).floor() as i16 }
 }
 
 impl<CharT, StrT> Component for PlaygroundComponent<CharT, StrT>
     where 
-        CharT: CrosswordChar + ToHtml + 'static,
+        CharT: CrosswordChar + Display + 'static,
         StrT: CrosswordString<CharT> + 'static,
         CharT: Serialize + DeserializeOwned,
         StrT: Serialize + DeserializeOwned,
@@ -717,8 +1466,15 @@ impl<CharT, StrT> Component for PlaygroundComponent<CharT, StrT>
         ctx.props().link.borrow_mut().replace(ctx.link().clone());
         let mut this = PlaygroundComponent
         {
-            words: ctx.props().words.iter().cloned().map(|w| (PlaygroundWordId::new(), PlaygroundWord::from_placed_word(w))).collect(),
+            words: ctx.props().words.iter().cloned().map(|w| (w.id, PlaygroundWord::from_document_word(w))).collect(),
             word_compatibility_settings: ctx.props().word_compatibility_settings.clone(),
+            slots: HashMap::default(),
+            slot_drawing: None,
+            transform_preview: None,
+            transform_undo: Vec::default(),
+            search: None,
+            found: Vec::default(),
+            current_found: 0,
             transform_x: 0f32,
             transform_y: 0f32,
             transform_zoom: 0.3f32,
@@ -732,8 +1488,16 @@ impl<CharT, StrT> Component for PlaygroundComponent<CharT, StrT>
 
             currently_dragging: false,
 
+            touch_pointers: HashMap::default(),
+            pointer_gesture: PointerGesture::None,
+            press_counter: 0,
+
             html: Vec::default(),
             dragging_html: Vec::default(),
+            preview_html: Vec::default(),
+            minimap_words: Vec::default(),
+            minimap_error_cells: Vec::default(),
+            wrapper_node_ref: NodeRef::default(),
             playground_node_ref: NodeRef::default(),
             dragging_node_ref: NodeRef::default(),
             dragging_image_ref: NodeRef::default(),
@@ -747,12 +1511,17 @@ impl<CharT, StrT> Component for PlaygroundComponent<CharT, StrT>
         if self.apply_message(ctx, msg)
         {
             self.recalculate_drawing_data(ctx);
+            self.notify_words_change(ctx);
         }
         true
     }
 
     fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool 
     {
+        if ctx.props().symmetry != old_props.symmetry || ctx.props().symmetry_center != old_props.symmetry_center || ctx.props().groups != old_props.groups || ctx.props().duplicate_settings != old_props.duplicate_settings
+        {
+            self.recalculate_main_drawing_data(ctx);
+        }
         if ctx.props().words != old_props.words
         {
             ctx.link().send_message(PlaygroundComponentMessage::SetWords(ctx.props().words.clone()));
@@ -763,6 +1532,9 @@ impl<CharT, StrT> Component for PlaygroundComponent<CharT, StrT>
 
     fn view(&self, ctx: &Context<Self>) -> Html
     {
+        let mode = ctx.props().mode;
+        let style_settings = ctx.link().context::<StyleSettings>(Callback::noop()).unwrap().0;
+
         html! 
         {
             <div class={classes!("playground-wrapper",
@@ -773,39 +1545,138 @@ impl<CharT, StrT> Component for PlaygroundComponent<CharT, StrT>
                     overflow: hidden;
                     position: relative;
                     user-select: none;
+                    outline: none;
+                    touch-action: none;
                 )
             )} 
+            ref={ self.wrapper_node_ref.clone() }
+            tabindex="0"
+            onpointerdown=
+            {
+                let ctx_link = ctx.link().clone();
+                let wrapper_node_ref = self.wrapper_node_ref.clone();
+                Callback::from(move |event: PointerEvent| 
+                {
+                    if event.pointer_type() == "mouse" { return; }
+                    if let Some(wrapper) = wrapper_node_ref.cast::<Element>()
+                    {
+                        let _ = wrapper.set_pointer_capture(event.pointer_id());
+                    }
+                    ctx_link.send_message(PlaygroundComponentMessage::PointerDown(event.pointer_id(), event.client_x() as f32, event.client_y() as f32));
+                })
+            }
+            onpointermove=
+            {
+                let ctx_link = ctx.link().clone();
+                Callback::from(move |event: PointerEvent| 
+                {
+                    if event.pointer_type() == "mouse" { return; }
+                    ctx_link.send_message(PlaygroundComponentMessage::PointerMove(event.pointer_id(), event.client_x() as f32, event.client_y() as f32));
+                })
+            }
+            onpointerup=
+            {
+                let ctx_link = ctx.link().clone();
+                Callback::from(move |event: PointerEvent| if event.pointer_type() != "mouse" { ctx_link.send_message(PlaygroundComponentMessage::PointerUp(event.pointer_id())) })
+            }
+            onpointercancel=
+            {
+                let ctx_link = ctx.link().clone();
+                Callback::from(move |event: PointerEvent| if event.pointer_type() != "mouse" { ctx_link.send_message(PlaygroundComponentMessage::PointerUp(event.pointer_id())) })
+            }
+            oncopy=
+            {
+                let selected_words = self.words.iter().filter(|(_, w)| w.state == PlaygroundWordState::Selected).map(|(w_id, w)| w.to_document_word(*w_id)).collect_vec();
+                Callback::from(move |event: Event| 
+                {
+                    if selected_words.is_empty() { return; }
+                    if let Some(clipboard_data) = event.dyn_ref::<ClipboardEvent>().and_then(ClipboardEvent::clipboard_data)
+                    {
+                        event.prevent_default();
+                        clipboard_data.set_data("text/plain", &serde_json::to_string(&selected_words).unwrap()).unwrap();
+                    }
+                })
+            }
+            onpaste=
+            {
+                let ctx_link = ctx.link().clone();
+                Callback::from(move |event: Event| 
+                {
+                    let Some(clipboard_data) = event.dyn_ref::<ClipboardEvent>().and_then(ClipboardEvent::clipboard_data) else { return; };
+                    // the words copied with their ids, or just placed words from somewhere else
+                    let text = clipboard_data.get_data("text/plain").unwrap_or_default();
+                    let words = serde_json::from_str::<Vec<DocumentWord<CharT, StrT>>>(&text)
+                        .or_else(|_| serde_json::from_str::<Vec<PlacedWord<CharT, StrT>>>(&text).map(|words| words.into_iter().map(DocumentWord::from_placed_word).collect()));
+                    if let Ok(words) = words
+                    {
+                        event.prevent_default();
+                        ctx_link.send_message(PlaygroundComponentMessage::MessageBatch(vec![PlaygroundComponentMessage::DeselectAll, PlaygroundComponentMessage::PasteWords(words)]));
+                    }
+                })
+            }
             onwheel=
             {
                 let ctx_link = ctx.link().clone();
-                let playground_node_ref = self.playground_node_ref.clone();
                 Callback::from(move |event: WheelEvent| 
                 {
                     event.stop_propagation();
                     event.prevent_default();
 
-                    let playground_element = playground_node_ref.cast::<HtmlElement>().unwrap();
-                    let playground_bounding_box = playground_element.get_bounding_client_rect();
-
                     let zoom = 1.001f32.powf(-event.delta_y() as f32);
-                    ctx_link.send_message(PlaygroundComponentMessage::Scroll((event.client_x() as f32 - playground_bounding_box.left() as f32) * (1f32 - zoom), (event.client_y() as f32 - playground_bounding_box.top() as f32) * (1f32 - zoom)));
-                    ctx_link.send_message(PlaygroundComponentMessage::Zoom(zoom));
+                    ctx_link.send_message(PlaygroundComponentMessage::ZoomAt(event.client_x() as f32, event.client_y() as f32, zoom));
+                })
+            }
+            onmousedown=
+            {
+                let ctx_link = ctx.link().clone();
+                let playground_node_ref = self.playground_node_ref.clone();
+                let zoom = self.transform_zoom;
+                let style_settings = style_settings.clone();
+                Callback::from(move |event: MouseEvent| 
+                {
+                    if mode == PlaygroundMode::DrawSlots && event.button() == 0
+                    {
+                        let playground = playground_node_ref.cast::<HtmlElement>().unwrap();
+                        ctx_link.send_message(PlaygroundComponentMessage::StartSlotDrawing(client_to_cell(&playground, event.client_x(), event.client_y(), zoom, &style_settings)));
+                    }
                 })
             }
             onmousemove=
             {
                 let ctx_link = ctx.link().clone();
+                let playground_node_ref = self.playground_node_ref.clone();
+                let zoom = self.transform_zoom;
+                let style_settings = style_settings.clone();
                 Callback::from(move |event: MouseEvent| 
                 {
                     event.stop_propagation();
                     if event.buttons() == 1
                     {
-                        ctx_link.send_message(PlaygroundComponentMessage::Scroll(event.movement_x() as f32, event.movement_y() as f32)); 
+                        if mode == PlaygroundMode::DrawSlots
+                        {
+                            let playground = playground_node_ref.cast::<HtmlElement>().unwrap();
+                            ctx_link.send_message(PlaygroundComponentMessage::UpdateSlotDrawing(client_to_cell(&playground, event.client_x(), event.client_y(), zoom, &style_settings)));
+                        }
+                        else
+                        {
+                            ctx_link.send_message(PlaygroundComponentMessage::Scroll(event.movement_x() as f32, event.movement_y() as f32)); 
+                        }
                     }
 
                     
                 })
             }
+            onmouseup=
+            {
+                let ctx_link = ctx.link().clone();
+                Callback::from(move |_: MouseEvent| 
+                {
+                    if mode == PlaygroundMode::DrawSlots
+                    {
+                        ctx_link.send_message(PlaygroundComponentMessage::EndSlotDrawing);
+                    }
+                })
+            }
             ondragstart=
             {
                 let ctx_link = ctx.link().clone();
@@ -814,11 +1685,17 @@ impl<CharT, StrT> Component for PlaygroundComponent<CharT, StrT>
                 //let dragging_node_element = self.dragging_node_ref.cast::<Element>().unwrap();
                 let dragging_image_ref = self.dragging_image_ref.clone();
                 let zoom = self.transform_zoom;
+                let touch_active = !self.touch_pointers.is_empty();
                 Callback::from(move |event: DragEvent| 
                 {
-                    //event.prevent_default();
+                    // touch and pen move the words with pointer events
+                    if touch_active
+                    {
+                        event.prevent_default();
+                        return;
+                    }
                     let ctx_link = ctx_link.clone();
-                    let words = selected_word_ids_and_words.iter().map(|(_, w)| w.w.clone()).collect_vec();
+                    let words = selected_word_ids_and_words.iter().map(|(id, w)| DocumentWord::new(*id, w.w.clone())).collect_vec();
                     let ids = selected_word_ids_and_words.iter().map(|(id, _)| *id).collect_vec();
 
                     let playground = playground_node_ref.cast::<HtmlElement>().unwrap();
@@ -859,6 +1736,7 @@ impl<CharT, StrT> Component for PlaygroundComponent<CharT, StrT>
                     log!("end");
                     if let Some(data_transfer) = event.data_transfer()
                     {
+                        clear_drag_data(&data_transfer);
                         data_transfer.clear_data().unwrap();
                     }
                     ctx_link.send_message(PlaygroundComponentMessage::MessageBatch(vec![PlaygroundComponentMessage::SetDragging(false), PlaygroundComponentMessage::EndDragging]));
@@ -872,12 +1750,8 @@ impl<CharT, StrT> Component for PlaygroundComponent<CharT, StrT>
                     log!("dragenter");
                     if let Some(data_transfer) = event.data_transfer()
                     {
-                        if let Some(drag_data) = get_drag_data(&data_transfer)
+                        if let Some(drag_data) = get_drag_data::<CharT, StrT>(&data_transfer)
                         {
-                            set_drag_data(&data_transfer, &drag_data);
-
-                            let ctx_link = ctx_link.clone();
-
                             ctx_link.send_message(PlaygroundComponentMessage::SetDraggingOffset(drag_data.dragging_mouse_offset_x, drag_data.dragging_mouse_offset_y));
                             ctx_link.send_message(PlaygroundComponentMessage::MessageBatch(vec![PlaygroundComponentMessage::SetDragging(true), PlaygroundComponentMessage::StartDragging(drag_data.data)]));
                         }
@@ -914,8 +1788,17 @@ impl<CharT, StrT> Component for PlaygroundComponent<CharT, StrT>
             ondrop=
             {
                 let ctx_link = ctx.link().clone();
+                let nothing_to_drop = self.dragging_words.is_empty();
                 Callback::from(move |event: DragEvent|
                 {
+                    // the preview wasn't shown, the words can still be read from the drag data itself
+                    if nothing_to_drop
+                    {
+                        if let Some(drag_data) = event.data_transfer().and_then(|data_transfer| get_dropped_drag_data::<CharT, StrT>(&data_transfer))
+                        {
+                            ctx_link.send_message(PlaygroundComponentMessage::StartDragging(drag_data.data));
+                        }
+                    }
                     ctx_link.send_message(PlaygroundComponentMessage::MessageBatch(vec![PlaygroundComponentMessage::DropDragging, PlaygroundComponentMessage::SetDragging(false), PlaygroundComponentMessage::EndDragging]));
                 })
             }>
@@ -937,6 +1820,21 @@ impl<CharT, StrT> Component for PlaygroundComponent<CharT, StrT>
 
                 { for self.html.iter().cloned() }
 
+                    <div class={classes!("transform-preview", 
+                        css!
+                        (
+                            position: absolute;
+                            left: 0;
+                            top: 0;
+                            opacity: 0.5;
+                            pointer-events: none;
+                        )
+                    )}>
+
+                    { for self.preview_html.iter().cloned() }
+
+                    </div>
+
                     <div ref={ self.dragging_node_ref.clone() } class={classes!("dragging-image", 
                         css!
                         (
@@ -959,6 +1857,8 @@ impl<CharT, StrT> Component for PlaygroundComponent<CharT, StrT>
                     )}/> 
 
                 </div>
+                <PlaygroundMinimapComponent words={self.minimap_words.clone()} error_cells={self.minimap_error_cells.clone()} viewport={self.viewport()}
+                    on_click={ctx.link().callback(|(x, y)| PlaygroundComponentMessage::CenterOn(x, y))}/>
             </div>
         }
     }
@@ -0,0 +1,162 @@
+use common::{document::CrosswordDocument, print::{clue_text, ClueLine, CluePage, PageSize, PrintLayout, COLUMN_GAP, HEADER_HEIGHT, LINE_HEIGHT, MARGIN}};
+use crossword_generator::word::Direction;
+use stylist::{css, yew::styled_component};
+use web_sys::HtmlSelectElement;
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::utils::api::get_json;
+
+#[derive(Properties, PartialEq)]
+pub struct PrintPageProperties
+{
+    pub crossword_id: String,
+}
+
+fn grid_html(layout: &PrintLayout, cell_size: f32, with_answers: bool) -> Html
+{
+    html!
+    {
+        <div class={css!( position: relative; margin: 0 auto; )} style={format!("width: {}mm; height: {}mm;", cell_size * layout.width as f32, cell_size * layout.height as f32)}>
+        {
+            for layout.cells.iter().map(|cell| html!
+            {
+                <div class={css!( position: absolute; box-sizing: border-box; border: 0.3mm solid black; display: flex; align-items: center; justify-content: center; )}
+                    style={format!("left: {x}mm; top: {y}mm; width: {s}mm; height: {s}mm; font-size: {f}mm;", x = cell.position.x as f32 * cell_size, y = cell.position.y as f32 * cell_size, s = cell_size, f = cell_size * 0.55)}>
+                    if let Some(number) = cell.number
+                    {
+                        <span class={css!( position: absolute; top: 0.3mm; left: 0.6mm; line-height: 1; )} style={format!("font-size: {}mm;", cell_size * 0.28)}>{ number }</span>
+                    }
+                    if with_answers
+                    {
+                        { cell.tile.to_string() }
+                    }
+                </div>
+            })
+        }
+        </div>
+    }
+}
+
+fn clue_columns_html(page: &CluePage) -> Html
+{
+    html!
+    {
+        <div class={css!( display: flex; )} style={format!("gap: {}mm; font-size: {}mm; line-height: {}mm;", COLUMN_GAP, LINE_HEIGHT * 0.7, LINE_HEIGHT)}>
+        {
+            for page.columns.iter().map(|column| html!
+            {
+                <div class={css!( flex: 1; )}>
+                {
+                    for column.iter().map(|line| match line
+                    {
+                        ClueLine::Heading(direction) => html! { <h3 class={css!( margin: 0; )} style={format!("height: {}mm; line-height: {}mm;", LINE_HEIGHT * 2.0, LINE_HEIGHT * 2.0)}>{ if *direction == Direction::Right { "Across" } else { "Down" } }</h3> },
+                        ClueLine::Clue(c) => html! { <div>{ clue_text(c) }</div> },
+                    })
+                }
+                </div>
+            })
+        }
+        </div>
+    }
+}
+
+// one sheet of the paper, on the screen it looks like the printed one
+fn sheet_html(page_size: PageSize, content: Html) -> Html
+{
+    let (width, height) = page_size.size();
+    html!
+    {
+        <div class={classes!("print-sheet", css!( box-sizing: border-box; background-color: white; color: black; font-family: serif; overflow: hidden; margin: 0 auto 8mm; box-shadow: 0 0 4px gray; ))}
+            style={format!("width: {}mm; height: {}mm; padding: {}mm;", width, height, MARGIN)}>
+            { content }
+        </div>
+    }
+}
+
+#[styled_component]
+pub fn PrintPage(PrintPageProperties{crossword_id}: &PrintPageProperties) -> Html
+{
+    let document = use_state(|| None::<Result<CrosswordDocument, String>>);
+    let page_size = use_state(PageSize::default);
+    let answer_key = use_state(|| true);
+
+    {
+        let document = document.clone();
+        use_effect_with(crossword_id.clone(), move |crossword_id|
+        {
+            let url = format!("/api/crosswords/{}", crossword_id);
+            spawn_local(async move { document.set(Some(get_json(&url).await.map_err(|e| e.to_string()))); });
+        });
+    }
+
+    let on_page_size_change =
+    {
+        let page_size = page_size.clone();
+        Callback::from(move |event: Event|
+        {
+            if let Some(size) = PageSize::from_name(&event.target_dyn_into::<HtmlSelectElement>().unwrap().value()) { page_size.set(size); }
+        })
+    };
+
+    let layout = match &*document
+    {
+        None => return html! { <p>{"Loading..."}</p> },
+        Some(Err(error)) => return html! { <p>{ error.clone() }</p> },
+        Some(Ok(document)) => PrintLayout::new(document),
+    };
+
+    let cell_size = layout.cell_size(*page_size);
+    let clue_pages = layout.clue_pages(*page_size);
+    let title = if layout.title.is_empty() { "Untitled".to_owned() } else { layout.title.clone() };
+    let header = |suffix: &str| html!
+    {
+        <div style={format!("height: {}mm;", HEADER_HEIGHT)}>
+            <h1 class={css!( margin: 0; font-size: 7mm; )}>{ format!("{}{}", title, suffix) }</h1>
+            if !layout.author.is_empty()
+            {
+                <p class={css!( margin: 1mm 0 0; font-size: 4mm; )}>{ format!("by {}", layout.author) }</p>
+            }
+        </div>
+    };
+
+    let pages_html = clue_pages.iter().enumerate().map(|(i, page)| sheet_html(*page_size, html!
+    {
+        <>
+            if i == 0
+            {
+                { header("") }
+                { grid_html(&layout, cell_size, false) }
+                <div style={format!("height: {}mm;", LINE_HEIGHT)}/>
+            }
+            { clue_columns_html(page) }
+        </>
+    }));
+
+    let (width, height) = page_size.size();
+    html!
+    {
+        <div class={classes!("print", css!( font-family: sans-serif; color: white; ))}>
+            // the size of the printed page follows the selected one, the controls are only on the screen
+            <style>{ format!("@page {{ size: {}mm {}mm; margin: 0; }} @media print {{ body {{ background: white !important; }} .print-controls {{ display: none !important; }} .print-sheet {{ margin: 0 !important; box-shadow: none !important; break-after: page; }} }}", width, height) }</style>
+            <div class={classes!("print-controls", css!( display: flex; gap: 8px; align-items: center; margin-bottom: 8px; ))}>
+                <select onchange={on_page_size_change}>
+                {
+                    for PageSize::all().into_iter().map(|s| html! { <option value={s.name()} selected={s == *page_size}>{ s.name() }</option> })
+                }
+                </select>
+                <label>
+                    <input type="checkbox" checked={*answer_key}
+                        onchange={ let answer_key = answer_key.clone(); Callback::from(move |_: Event| answer_key.set(!*answer_key)) }/>
+                    {"Answer key"}
+                </label>
+                <button onclick={Callback::from(|_: MouseEvent| { let _ = gloo_utils::window().print(); })}>{"Print"}</button>
+                <a href={format!("/api/crosswords/{}/export.pdf?size={}&answers={}", crossword_id, page_size.name(), *answer_key)} download="">{"Download PDF"}</a>
+            </div>
+            { for pages_html }
+            if *answer_key
+            {
+                { sheet_html(*page_size, html! { <>{ header(" - answers") }{ grid_html(&layout, cell_size, true) }</> }) }
+            }
+        </div>
+    }
+}
@@ -0,0 +1,313 @@
+use std::rc::Rc;
+
+use common::{alphabet::Tile, room::{ActionOutcome, JoinRoomRequest, JoinRoomResponse, Player, PlayerAction, PlayerActionRequest, PlayerId, PlayerToken, RoomEvent, RoomMode}, solve::{entries_from_cells, Entries, Puzzle}};
+use crossword_generator::word::{Direction, Position};
+use gloo_net::http::Request;
+use gloo_timers::callback::Interval;
+use stylist::{css, yew::styled_component};
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{EventSource, HtmlInputElement, MessageEvent};
+use yew::{platform::spawn_local, prelude::*};
+use yew_router::prelude::Link;
+
+use crate::{components::solve_grid_component::SolveGridComponent, utils::{api::{post_json, send_json}, user::user_id}, Route};
+
+#[derive(Default, Clone, PartialEq)]
+struct RoomState
+{
+    player_id: Option<PlayerId>,
+    token: Option<PlayerToken>,
+    mode: RoomMode,
+    puzzle: Option<Puzzle>,
+    players: Vec<Player>,
+    entries: Entries,
+    wrong_cells: Vec<Position>,
+    message: String,
+}
+
+enum RoomStateAction
+{
+    Joined(JoinRoomResponse),
+    Event(RoomEvent),
+    SetCell(Position, Option<Tile>),
+    Outcome(ActionOutcome),
+    Error(String),
+}
+
+impl RoomState
+{
+    fn player_mut(&mut self, player_id: &PlayerId) -> Option<&mut Player>
+    {
+        self.players.iter_mut().find(|p| p.id == *player_id)
+    }
+
+    fn set_cell(&mut self, position: Position, tile: Option<Tile>)
+    {
+        self.wrong_cells.retain(|pos| *pos != position);
+        match tile
+        {
+            Some(tile) => { self.entries.insert(position, tile); }
+            None => { self.entries.remove(&position); }
+        }
+    }
+}
+
+impl Reducible for RoomState
+{
+    type Action = RoomStateAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self>
+    {
+        let mut state = (*self).clone();
+        match action
+        {
+            RoomStateAction::Joined(JoinRoomResponse { player_id, token, room }) =>
+            {
+                state.player_id = Some(player_id);
+                state.token = Some(token);
+                state.mode = room.mode;
+                state.puzzle = Some(room.puzzle);
+                state.players = room.players;
+                state.entries = entries_from_cells(room.entries);
+                state.message.clear();
+            }
+            RoomStateAction::Event(event) => match event
+            {
+                RoomEvent::PlayerJoined { player } => match state.player_mut(&player.id)
+                {
+                    Some(p) => *p = player,
+                    None => state.players.push(player),
+                },
+                RoomEvent::PlayerLeft { player_id } => state.players.retain(|p| p.id != player_id),
+                // own changes are already applied
+                RoomEvent::CellChanged { player_id, position, tile } => if state.player_id != Some(player_id) { state.set_cell(position, tile) },
+                RoomEvent::CursorMoved { player_id, position } => if let Some(p) = state.player_mut(&player_id) { p.cursor = Some(position) },
+                RoomEvent::ProgressChanged { player_id, progress } => if let Some(p) = state.player_mut(&player_id) { p.progress = progress },
+                RoomEvent::Incorrect { player_id } => if state.player_id == Some(player_id) { state.message = "The grid is full, but something is wrong".to_owned() },
+                RoomEvent::Solved { player_id, time_ms } =>
+                {
+                    if let Some(p) = state.player_mut(&player_id) { p.finish_time_ms = Some(time_ms); }
+                    if state.player_id == Some(player_id) { state.message = format!("Solved in {}", format_time(time_ms)); }
+                }
+            },
+            RoomStateAction::SetCell(position, tile) => state.set_cell(position, tile),
+            RoomStateAction::Outcome(outcome) => match outcome
+            {
+                ActionOutcome::Done => {}
+                ActionOutcome::Checked { wrong } =>
+                {
+                    state.message = if wrong.is_empty() { "Everything filled is right".to_owned() } else { format!("{} wrong cells", wrong.len()) };
+                    state.wrong_cells = wrong;
+                }
+                ActionOutcome::Revealed { position, tile } => state.set_cell(position, Some(tile)),
+            },
+            RoomStateAction::Error(message) => state.message = message,
+        }
+        state.into()
+    }
+}
+
+pub fn format_time(time_ms: u64) -> String
+{
+    let seconds = time_ms / 1000;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+// the server drops the players that stay silent for longer
+const HEARTBEAT_MS: u32 = 30_000;
+
+fn send_action(code: &str, (player_id, token): (PlayerId, PlayerToken), action: PlayerAction, state: UseReducerHandle<RoomState>)
+{
+    let url = format!("/api/rooms/{}/players/{}", code, player_id);
+    let request = PlayerActionRequest { token, action };
+    spawn_local(async move
+    {
+        let outcome = async { send_json(Request::post(&url), &request).await?.json::<ActionOutcome>().await };
+        match outcome.await
+        {
+            Ok(outcome) => state.dispatch(RoomStateAction::Outcome(outcome)),
+            Err(error) => state.dispatch(RoomStateAction::Error(error.to_string())),
+        }
+    });
+}
+
+#[derive(Properties, PartialEq)]
+pub struct RoomPageProperties
+{
+    pub code: String,
+}
+
+#[styled_component]
+pub fn RoomPage(RoomPageProperties{code}: &RoomPageProperties) -> Html
+{
+    let state = use_reducer(RoomState::default);
+    let name = use_state(String::new);
+    let cursor = use_state(|| None::<Position>);
+
+    {
+        let state = state.clone();
+        let code = code.clone();
+        use_effect_with(state.player_id.zip(state.token), move |player|
+        {
+            let player = *player;
+            let event_source = player.and_then(|_| EventSource::new(&format!("/api/rooms/{}/events", code)).ok());
+            let on_message = event_source.as_ref().map(|event_source|
+            {
+                let state = state.clone();
+                let on_message = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent|
+                {
+                    if let Some(event) = event.data().as_string().and_then(|data| serde_json::from_str::<RoomEvent>(&data).ok())
+                    {
+                        state.dispatch(RoomStateAction::Event(event));
+                    }
+                });
+                event_source.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+                on_message
+            });
+            let heartbeat = player.map(|player|
+            {
+                let state = state.clone();
+                let code = code.clone();
+                Interval::new(HEARTBEAT_MS, move || send_action(&code, player, PlayerAction::Heartbeat, state.clone()))
+            });
+
+            move ||
+            {
+                if let Some(event_source) = event_source { event_source.close(); }
+                drop(on_message);
+                drop(heartbeat);
+                if let Some(player) = player { send_action(&code, player, PlayerAction::Leave, state); }
+            }
+        });
+    }
+
+    let on_join =
+    {
+        let state = state.clone();
+        let name = name.clone();
+        let code = code.clone();
+        Callback::from(move |event: SubmitEvent|
+        {
+            event.prevent_default();
+            let state = state.clone();
+            let url = format!("/api/rooms/{}/join", code);
+            let request = JoinRoomRequest { name: (*name).clone(), user_id: user_id() };
+            spawn_local(async move
+            {
+                match post_json::<_, JoinRoomResponse>(&url, &request).await
+                {
+                    Ok(response) => state.dispatch(RoomStateAction::Joined(response)),
+                    Err(error) => state.dispatch(RoomStateAction::Error(error.to_string())),
+                }
+            });
+        })
+    };
+
+    let (Some(player_id), Some(token), Some(puzzle)) = (state.player_id, state.token, state.puzzle.clone()) else
+    {
+        return html!
+        {
+            <form class={classes!("room-join", css!( display: flex; flex-direction: column; gap: 8px; max-width: 300px; ))} onsubmit={on_join}>
+                <h2>{ format!("Room {}", code) }</h2>
+                <input type="text" placeholder="Your name" value={(*name).clone()}
+                    oninput={ let name = name.clone(); Callback::from(move |event: InputEvent| name.set(event.target_unchecked_into::<HtmlInputElement>().value())) }/>
+                <button type="submit">{"Join"}</button>
+                <p>{ state.message.clone() }</p>
+            </form>
+        };
+    };
+
+    let on_input =
+    {
+        let state = state.clone();
+        let code = code.clone();
+        Callback::from(move |(position, tile): (Position, Option<Tile>)|
+        {
+            state.dispatch(RoomStateAction::SetCell(position.clone(), tile.clone()));
+            send_action(&code, (player_id, token), PlayerAction::SetCell { position, tile }, state.clone());
+        })
+    };
+
+    let on_cursor_move =
+    {
+        let state = state.clone();
+        let code = code.clone();
+        let cursor = cursor.clone();
+        Callback::from(move |position: Position| 
+        {
+            cursor.set(Some(position.clone()));
+            send_action(&code, (player_id, token), PlayerAction::MoveCursor { position }, state.clone());
+        })
+    };
+
+    let on_check =
+    {
+        let state = state.clone();
+        let code = code.clone();
+        Callback::from(move |_: MouseEvent| send_action(&code, (player_id, token), PlayerAction::Check, state.clone()))
+    };
+
+    let on_reveal =
+    {
+        let state = state.clone();
+        let code = code.clone();
+        let cursor = cursor.clone();
+        Callback::from(move |_: MouseEvent| if let Some(position) = (*cursor).clone() { send_action(&code, (player_id, token), PlayerAction::Reveal { position }, state.clone()) })
+    };
+
+    let finished = state.players.iter().any(|p| p.id == player_id && p.finish_time_ms.is_some());
+
+    // the cursors of the others only make sense on the shared grid
+    let cursors = match state.mode
+    {
+        RoomMode::Coop => state.players.iter().filter(|p| p.id != player_id).filter_map(|p| p.cursor.clone().map(|cursor| (cursor, p.color.clone()))).collect(),
+        RoomMode::Race => Vec::new(),
+    };
+
+    let clue_list = |direction: Direction, title: &str| html!
+    {
+        <div class={css!( flex: 1; )}>
+            <h3>{ title }</h3>
+            <ol class={css!( list-style: none; padding: 0; )}>
+            {
+                for puzzle.clues.iter().filter(|c| c.word.direction == direction).map(|c| html!
+                {
+                    <li>{ format!("{}. {} ({})", c.word.number, c.clue, c.word.length) }</li>
+                })
+            }
+            </ol>
+        </div>
+    };
+
+    let players_html = state.players.iter().map(|p| html!
+    {
+        <li class={css!( display: flex; align-items: center; gap: 8px; )}>
+            <span class={css!( width: 12px; height: 12px; border-radius: 50%; )} style={format!("background-color: {};", p.color)}/>
+            <span>{ if p.id == player_id { format!("{} (you)", p.name) } else { p.name.clone() } }</span>
+            <progress max="100" value={format!("{}", (p.progress * 100.0).round())}/>
+            <span>{ p.finish_time_ms.map(format_time).unwrap_or_default() }</span>
+        </li>
+    });
+
+    html!
+    {
+        <div class={classes!("room", css!( display: flex; flex-direction: column; gap: 16px; color: white; font-family: sans-serif; ))}>
+            <h2>{ format!("{} - room {} ({})", if puzzle.title.is_empty() { "Untitled" } else { &puzzle.title }, code, match state.mode { RoomMode::Coop => "co-op", RoomMode::Race => "race" }) }</h2>
+            <ul class={css!( list-style: none; padding: 0; margin: 0; )}>{ for players_html }</ul>
+            <div class={css!( display: flex; gap: 8px; align-items: center; )}>
+                <button onclick={on_check} disabled={finished}>{"Check"}</button>
+                <button onclick={on_reveal} disabled={finished || cursor.is_none()}>{"Reveal cell"}</button>
+                <span>{ state.message.clone() }</span>
+                if finished
+                {
+                    <Link<Route> to={Route::RevisionLeaderboard { id: puzzle.crossword_id.to_string(), revision: puzzle.revision }}>{"Leaderboard"}</Link<Route>>
+                }
+            </div>
+            <div class={css!( display: flex; gap: 32px; align-items: flex-start; )}>
+                <SolveGridComponent puzzle={puzzle.clone()} entries={state.entries.clone()} wrong_cells={state.wrong_cells.clone()} {cursors} {on_input} {on_cursor_move}/>
+                { clue_list(Direction::Right, "Across") }
+                { clue_list(Direction::Down, "Down") }
+            </div>
+        </div>
+    }
+}
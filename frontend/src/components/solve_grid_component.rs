@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+use common::{alphabet::Tile, grid::cell_at, solve::{Entries, Puzzle}};
+use crossword_generator::word::{Direction, Position};
+use stylist::{css, yew::styled_component};
+use yew::prelude::*;
+
+use crate::utils::settings::{StyleSettings, Theme};
+
+const SOLVE_CELL_SIZE: i16 = 40;
+
+#[derive(Properties, PartialEq)]
+pub struct SolveGridComponentProperties
+{
+    pub puzzle: Puzzle,
+    pub entries: Entries,
+    // the cursors of the other players with their colors
+    #[prop_or_default]
+    pub cursors: Vec<(Position, String)>,
+    // the cells a check found wrong
+    #[prop_or_default]
+    pub wrong_cells: Vec<Position>,
+    #[prop_or(Callback::noop())]
+    pub on_input: Callback<(Position, Option<Tile>)>,
+    #[prop_or(Callback::noop())]
+    pub on_cursor_move: Callback<Position>,
+}
+
+fn next_cell(cells: &[Position], from: &Position, dx: i16, dy: i16) -> Option<Position>
+{
+    let next = Position { x: from.x + dx, y: from.y + dy };
+    cells.contains(&next).then_some(next)
+}
+
+#[styled_component]
+pub fn SolveGridComponent(SolveGridComponentProperties{puzzle, entries, cursors, wrong_cells, on_input, on_cursor_move}: &SolveGridComponentProperties) -> Html
+{
+    let StyleSettings { word_style_settings: _, playground_style_settings } = use_context::<StyleSettings>().expect("No style provided");
+    let Theme { color_dark, color_normal, color_light, color_error_dark, color_error_normal: _, color_error_light, color_locked: _, color_grouped: _ } = playground_style_settings.theme;
+
+    let selected = use_state(|| None::<Position>);
+    let direction = use_state(|| Direction::Right);
+
+    let min_x = puzzle.cells.iter().map(|pos| pos.x).min().unwrap_or_default();
+    let min_y = puzzle.cells.iter().map(|pos| pos.y).min().unwrap_or_default();
+    let numbers = puzzle.clues.iter().map(|c| (c.word.position.clone(), c.word.number)).collect::<HashMap<_, _>>();
+
+    // the cells of the word under the cursor in the current direction
+    let current_word = selected.as_ref().and_then(|sel| puzzle.clues.iter()
+        .filter(|c| c.word.direction == *direction)
+        .find(|c| (0..c.word.length).any(|i| cell_at(&c.word.position, &c.word.direction, i) == *sel)))
+        .map(|c| (0..c.word.length).map(|i| cell_at(&c.word.position, &c.word.direction, i)).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    let select =
+    {
+        let selected = selected.clone();
+        let direction = direction.clone();
+        let on_cursor_move = on_cursor_move.clone();
+        move |pos: Position|
+        {
+            if selected.as_ref() == Some(&pos)
+            {
+                direction.set(if *direction == Direction::Right { Direction::Down } else { Direction::Right });
+            }
+            selected.set(Some(pos.clone()));
+            on_cursor_move.emit(pos);
+        }
+    };
+
+    let onkeydown =
+    {
+        let selected = selected.clone();
+        let direction = direction.clone();
+        let cells = puzzle.cells.clone();
+        let alphabet = puzzle.alphabet.clone();
+        let on_input = on_input.clone();
+        let on_cursor_move = on_cursor_move.clone();
+        Callback::from(move |event: KeyboardEvent|
+        {
+            let Some(sel) = (*selected).clone() else { return; };
+            let (dx, dy) = if *direction == Direction::Right { (1, 0) } else { (0, 1) };
+            let move_to = |pos: Option<Position>| if let Some(pos) = pos
+            {
+                selected.set(Some(pos.clone()));
+                on_cursor_move.emit(pos);
+            };
+
+            match event.key().as_str()
+            {
+                "ArrowLeft" => move_to(next_cell(&cells, &sel, -1, 0)),
+                "ArrowRight" => move_to(next_cell(&cells, &sel, 1, 0)),
+                "ArrowUp" => move_to(next_cell(&cells, &sel, 0, -1)),
+                "ArrowDown" => move_to(next_cell(&cells, &sel, 0, 1)),
+                "Backspace" | "Delete" =>
+                {
+                    on_input.emit((sel.clone(), None));
+                    if event.key() == "Backspace" { move_to(next_cell(&cells, &sel, -dx, -dy)); }
+                }
+                key => match alphabet.segment(key).as_deref()
+                {
+                    Ok([tile]) =>
+                    {
+                        on_input.emit((sel.clone(), Some(tile.clone())));
+                        move_to(next_cell(&cells, &sel, dx, dy));
+                    }
+                    _ => return,
+                }
+            }
+            event.prevent_default();
+        })
+    };
+
+    let cells_html = puzzle.cells.iter().map(|pos|
+    {
+        let is_selected = selected.as_ref() == Some(pos);
+        let is_wrong = wrong_cells.contains(pos);
+        let background_color = match (is_selected, is_wrong)
+        {
+            (true, true) => color_error_dark,
+            (true, false) => color_dark,
+            (false, true) => color_error_light,
+            (false, false) => if current_word.contains(pos) { color_normal } else { color_light },
+        };
+        let cursor_shadows = cursors.iter().filter(|(cursor, _)| cursor == pos).enumerate()
+            .map(|(i, (_, color))| format!("inset 0 0 0 {}px {}", 3 * (i + 1), color))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        html!
+        {
+            <div class={classes!("solve-cell", css!
+                (
+                    position: absolute;
+                    box-sizing: border-box;
+                    width: ${SOLVE_CELL_SIZE}px;
+                    height: ${SOLVE_CELL_SIZE}px;
+                    border: 1px solid black;
+                    display: flex;
+                    align-items: center;
+                    justify-content: center;
+                    font-size: 22px;
+                    font-family: sans-serif;
+                    cursor: pointer;
+                ))}
+                style={format!("left: {}px; top: {}px; background-color: {}; box-shadow: {};", (pos.x - min_x) * SOLVE_CELL_SIZE, (pos.y - min_y) * SOLVE_CELL_SIZE, background_color, if cursor_shadows.is_empty() { "none".to_owned() } else { cursor_shadows })}
+                onclick={ let select = select.clone(); let pos = pos.clone(); Callback::from(move |_: MouseEvent| select(pos.clone())) }>
+                if let Some(number) = numbers.get(pos)
+                {
+                    <span class={css!( position: absolute; top: 1px; left: 3px; font-size: 10px; )}>{ number }</span>
+                }
+                { entries.get(pos).map(ToString::to_string).unwrap_or_default() }
+            </div>
+        }
+    });
+
+    let width = puzzle.cells.iter().map(|pos| pos.x - min_x + 1).max().unwrap_or_default() * SOLVE_CELL_SIZE;
+    let height = puzzle.cells.iter().map(|pos| pos.y - min_y + 1).max().unwrap_or_default() * SOLVE_CELL_SIZE;
+
+    html!
+    {
+        <div class={classes!("solve-grid", css!( position: relative; outline: none; ))} style={format!("width: {}px; height: {}px;", width, height)} tabindex="0" {onkeydown}>
+            { for cells_html }
+        </div>
+    }
+}
@@ -0,0 +1,40 @@
+use common::room::{CreateRoomResponse, SolveRoomRequest};
+use yew::{platform::spawn_local, prelude::*};
+use yew_router::prelude::use_navigator;
+
+use crate::{utils::api::post_json, Route};
+
+#[derive(Properties, PartialEq)]
+pub struct SolvePageProperties
+{
+    pub crossword_id: String,
+    pub revision: u32,
+}
+
+// a solve link opens the race room of the published version, so even a single solver gets the time recorded
+#[function_component]
+pub fn SolvePage(SolvePageProperties{crossword_id, revision}: &SolvePageProperties) -> Html
+{
+    let navigator = use_navigator();
+    let error = use_state(String::new);
+
+    {
+        let error = error.clone();
+        use_effect_with((crossword_id.clone(), *revision), move |(crossword_id, revision)|
+        {
+            let crossword_id = crossword_id.parse();
+            let revision = *revision;
+            spawn_local(async move
+            {
+                let Ok(crossword_id) = crossword_id else { error.set("The link is broken".to_owned()); return; };
+                match post_json::<_, CreateRoomResponse>("/api/rooms/solve", &SolveRoomRequest { crossword_id, revision }).await
+                {
+                    Ok(CreateRoomResponse { code }) => if let Some(navigator) = navigator { navigator.replace(&Route::Room { code }) },
+                    Err(e) => error.set(e.to_string()),
+                }
+            });
+        });
+    }
+
+    html! { <p>{ if error.is_empty() { "Opening the puzzle...".to_owned() } else { (*error).clone() } }</p> }
+}
@@ -0,0 +1,54 @@
+use common::stats::UserStats;
+use stylist::{css, yew::styled_component};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::{components::room_page::format_time, utils::{api::get_json, user::user_id}};
+
+#[styled_component]
+pub fn StatsPage() -> Html
+{
+    let stats = use_state(|| None::<Result<UserStats, String>>);
+
+    {
+        let stats = stats.clone();
+        use_effect_with((), move |_|
+        {
+            let url = format!("/api/users/{}/stats", user_id());
+            spawn_local(async move { stats.set(Some(get_json(&url).await.map_err(|e| e.to_string()))); });
+        });
+    }
+
+    let body = match &*stats
+    {
+        None => html! { <p>{"Loading..."}</p> },
+        Some(Err(error)) => html! { <p>{ error.clone() }</p> },
+        Some(Ok(stats)) => html!
+        {
+            <>
+                <p>{ format!("Solved {} of {} attempts, {} without reveals", stats.solved, stats.attempts, stats.solved_without_reveals) }</p>
+                <p>{ format!("Current streak: {} days, longest: {} days", stats.current_streak, stats.longest_streak) }</p>
+                <table class={css!( border-collapse: collapse; td, th { padding: 4px 12px; text-align: left; } )}>
+                    <tr><th>{"Grid size"}</th><th>{"Solved"}</th><th>{"Average time"}</th></tr>
+                    {
+                        for stats.by_size.iter().map(|s| html!
+                        {
+                            <tr>
+                                <td>{ if s.max_size == usize::MAX { format!("{}+ cells", s.min_size) } else { format!("{}-{} cells", s.min_size, s.max_size) } }</td>
+                                <td>{ s.solved }</td>
+                                <td>{ format_time(s.average_time_ms) }</td>
+                            </tr>
+                        })
+                    }
+                </table>
+            </>
+        },
+    };
+
+    html!
+    {
+        <div class={classes!("stats", css!( color: white; font-family: sans-serif; ))}>
+            <h2>{"Your solve stats"}</h2>
+            { body }
+        </div>
+    }
+}
@@ -0,0 +1,136 @@
+use common::{alphabet::tiles_to_string, document::{CrosswordDocument, CrosswordId}, version::{DocumentDiff, VersionInfo}};
+use gloo_net::http::Request;
+use stylist::{css, yew::styled_component};
+use wasm_bindgen::JsValue;
+use web_sys::js_sys::Date;
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::utils::api::{get_json, send_json};
+
+#[derive(Properties, PartialEq)]
+pub struct VersionHistoryComponentProperties
+{
+    pub crossword_id: CrosswordId,
+    // changed by the parent when a version is added, so the list is fetched again
+    #[prop_or_default]
+    pub refresh: u32,
+    #[prop_or(Callback::noop())]
+    pub on_restore: Callback<CrosswordDocument>,
+}
+
+fn format_date(ms: u64) -> String
+{
+    Date::new(&JsValue::from_f64(ms as f64)).to_locale_string("default", &JsValue::UNDEFINED).into()
+}
+
+fn diff_lines(diff: &DocumentDiff) -> Vec<String>
+{
+    let mut lines = Vec::new();
+    if let Some((old, new)) = &diff.title { lines.push(format!("title: \"{}\" → \"{}\"", old, new)); }
+    if let Some((old, new)) = &diff.author { lines.push(format!("author: \"{}\" → \"{}\"", old, new)); }
+    lines.extend(diff.added.iter().map(|w| format!("+ {} at ({}, {})", tiles_to_string(&w.value), w.position.x, w.position.y)));
+    lines.extend(diff.removed.iter().map(|w| format!("- {} at ({}, {})", tiles_to_string(&w.value), w.position.x, w.position.y)));
+    lines.extend(diff.changed.iter().map(|c| format!("~ {} at ({}, {}) → {} at ({}, {})", tiles_to_string(&c.old.value), c.old.position.x, c.old.position.y, tiles_to_string(&c.new.value), c.new.position.x, c.new.position.y)));
+    lines.extend(diff.clues.iter().map(|c| format!("clue: \"{}\" → \"{}\"", c.old, c.new)));
+    if lines.is_empty() { lines.push("no changes".to_owned()); }
+    lines
+}
+
+#[styled_component]
+pub fn VersionHistoryComponent(VersionHistoryComponentProperties{crossword_id, refresh, on_restore}: &VersionHistoryComponentProperties) -> Html
+{
+    let versions = use_state(Vec::<VersionInfo>::new);
+    // the version whose changes up to the draft are shown
+    let diff = use_state(|| None::<(u32, DocumentDiff)>);
+    let error = use_state(String::new);
+
+    {
+        let versions = versions.clone();
+        let error = error.clone();
+        use_effect_with((*crossword_id, *refresh), move |(crossword_id, _)|
+        {
+            let url = format!("/api/crosswords/{}/versions", crossword_id);
+            spawn_local(async move
+            {
+                match get_json(&url).await
+                {
+                    Ok(list) => versions.set(list),
+                    Err(e) => error.set(e.to_string()),
+                }
+            });
+        });
+    }
+
+    let show_diff = |number: u32|
+    {
+        let diff = diff.clone();
+        let error = error.clone();
+        let url = format!("/api/crosswords/{}/versions/{}/diff", crossword_id, number);
+        Callback::from(move |_: MouseEvent|
+        {
+            let diff = diff.clone();
+            let error = error.clone();
+            let url = url.clone();
+            spawn_local(async move
+            {
+                match get_json(&url).await
+                {
+                    Ok(d) => diff.set(Some((number, d))),
+                    Err(e) => error.set(e.to_string()),
+                }
+            });
+        })
+    };
+
+    let restore = |number: u32|
+    {
+        let on_restore = on_restore.clone();
+        let error = error.clone();
+        let url = format!("/api/crosswords/{}/versions/{}/restore", crossword_id, number);
+        Callback::from(move |_: MouseEvent|
+        {
+            let on_restore = on_restore.clone();
+            let error = error.clone();
+            let url = url.clone();
+            spawn_local(async move
+            {
+                match async { send_json(Request::post(&url), &()).await?.json::<CrosswordDocument>().await }.await
+                {
+                    Ok(document) => on_restore.emit(document),
+                    Err(e) => error.set(e.to_string()),
+                }
+            });
+        })
+    };
+
+    html!
+    {
+        <div class={classes!("version-history", css!( margin-top: 8px; ))}>
+            <b>{"Versions"}</b>
+            if versions.is_empty()
+            {
+                <p>{"No versions yet"}</p>
+            }
+            <ul class={css!( list-style: none; padding: 0; margin: 4px 0; )}>
+            {
+                for versions.iter().rev().map(|v| html!
+                {
+                    <li class={css!( display: flex; gap: 6px; align-items: center; )}>
+                        <span>{ format!("v{} {}{}", v.number, format_date(v.created_at), if v.published { " (published)" } else { "" }) }</span>
+                        <button onclick={show_diff(v.number)}>{"Changes"}</button>
+                        <button onclick={restore(v.number)}>{"Restore"}</button>
+                    </li>
+                })
+            }
+            </ul>
+            if let Some((number, d)) = &*diff
+            {
+                <div class={css!( font-family: monospace; font-size: 12px; )}>
+                    <p>{ format!("From v{} to the draft:", number) }</p>
+                    { for diff_lines(d).into_iter().map(|line| html! { <div>{ line }</div> }) }
+                </div>
+            }
+            <p>{ (*error).clone() }</p>
+        </div>
+    }
+}
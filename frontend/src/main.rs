@@ -1,32 +1,522 @@
 mod components;
 mod utils;
 
-use std::default;
+use std::{collections::BTreeMap, default};
 
-use components::{playground_component::PlaygroundComponent, word_component::WordComponent};
-use crossword_generator::{crossword::{Crossword, WordCompatibilitySettings}, placed_word::PlacedWord, word::{Direction, Position, Word}};
+use common::{alphabet::{tiles_to_string, Alphabet, Tile}, analysis::{cells_center_x2, Symmetry}, document::{CrosswordDocument, CrosswordId, CrosswordWord, Difficulty, WordGroup, WordId}, duplicates::DuplicateSettings, export::{export_text, TextExportOptions}, fill::FillError, gallery::parse_tags, grid::cell_words, room::{CreateRoomRequest, CreateRoomResponse, RoomMode}, search::{SearchError, SearchMode}, transform::{Transform, TransformError}, validation::{compatibility_settings, SaveResponse, ValidationError}, version::{NewVersionRequest, VersionInfo}};
+use components::{analysis_component::AnalysisComponent, embed_page::EmbedPage, gallery_page::GalleryPage, import_dialog_component::ImportDialogComponent, leaderboard_page::LeaderboardPage, playground_component::{PlaygroundComponent, PlaygroundComponentMessage, PlaygroundMode}, print_page::PrintPage, room_page::RoomPage, solve_page::SolvePage, stats_page::StatsPage, version_history_component::VersionHistoryComponent, word_component::WordComponent};
+use crossword_generator::{crossword::Crossword, placed_word::PlacedWord, word::{Direction, Position, Word}};
 use stylist::{css, global_style, yew::Global, Style};
-use utils::{settings::StyleSettings, weak_component_link::WeakComponentLink};
-use web_sys::HtmlInputElement;
-use yew::prelude::*;
+use gloo_net::http::Request;
+use gloo_timers::callback::Timeout;
+use utils::{api::{post_json, send_json}, clipboard::copy_text, dictionary::load_dictionary, settings::StyleSettings, weak_component_link::WeakComponentLink};
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::{platform::spawn_local, prelude::*};
+use yew_router::prelude::*;
+
+#[derive(Clone, Routable, PartialEq)]
+pub enum Route
+{
+    #[at("/")]
+    Editor,
+    #[at("/rooms/:code")]
+    Room { code: String },
+    #[at("/crosswords/:id/leaderboard")]
+    Leaderboard { id: String },
+    #[at("/crosswords/:id/leaderboard/:revision")]
+    RevisionLeaderboard { id: String, revision: u32 },
+    #[at("/crosswords/:id/print")]
+    Print { id: String },
+    #[at("/stats")]
+    Stats,
+    #[at("/embed/:id")]
+    Embed { id: String },
+    #[at("/gallery")]
+    Gallery,
+    #[at("/solve/:id/:revision")]
+    Solve { id: String, revision: u32 },
+    #[not_found]
+    #[at("/404")]
+    NotFound,
+}
+
+fn switch(route: Route) -> Html
+{
+    match route
+    {
+        Route::Editor => html! { <App/> },
+        Route::Room { code } => html! { <RoomPage code={code}/> },
+        Route::Leaderboard { id } => html! { <LeaderboardPage crossword_id={id}/> },
+        Route::RevisionLeaderboard { id, revision } => html! { <LeaderboardPage crossword_id={id} revision={revision}/> },
+        Route::Print { id } => html! { <PrintPage crossword_id={id}/> },
+        Route::Stats => html! { <StatsPage/> },
+        Route::Embed { id } => html! { <EmbedPage crossword_id={id}/> },
+        Route::Gallery => html! { <GalleryPage/> },
+        Route::Solve { id, revision } => html! { <SolvePage crossword_id={id} {revision}/> },
+        Route::NotFound => html! { <p>{"Page not found"}</p> },
+    }
+}
+
+// the crosswords saved once are updated in place, the new ones get an id from the server
+async fn save_document(id: Option<CrosswordId>, document: &CrosswordDocument) -> Result<SaveResponse, gloo_net::Error>
+{
+    match id
+    {
+        Some(id) => send_json(Request::put(&format!("/api/crosswords/{}", id)), document).await?.json().await,
+        None => post_json("/api/crosswords", document).await,
+    }
+}
 
 #[function_component]
 fn App() -> Html 
 {
 
-    let mut settings = WordCompatibilitySettings::default();
-    settings.corner_by_corner = false;
-    let cw = use_state(|| vec![
-        PlacedWord::<char, Vec<char>>::new( "hello".chars().collect(), Position { x: 0, y: 0 }, Direction::Right),
-        PlacedWord::<char, Vec<char>>::new( "local".chars().collect(), Position { x: 1, y: 0 }, Direction::Down),
-        PlacedWord::<char, Vec<char>>::new( "at".chars().collect(), Position { x: 3, y: 3 }, Direction::Right),
-        PlacedWord::<char, Vec<char>>::new( "table".chars().collect(), Position { x: 5, y: 3 }, Direction::Right),
-        PlacedWord::<char, Vec<char>>::new( "sezam".chars().collect(), Position { x: 10, y: -2 }, Direction::Down),
-        PlacedWord::<char, Vec<char>>::new( "sezon".chars().collect(), Position { x: 12, y: -2 }, Direction::Down),
-        PlacedWord::<char, Vec<char>>::new( "abcde".chars().collect(), Position { x: 9, y: 0 }, Direction::Right),
-    ]);
+    let settings = compatibility_settings();
+    let alphabet = use_state(Alphabet::english);
+    let input_error = use_state(String::new);
+
+    let cw = use_state(|| 
+    {
+        let tiles = |s: &str| Alphabet::english().segment(s).unwrap();
+        vec![
+            CrosswordWord::from_placed_word(PlacedWord::new( tiles("hello"), Position { x: 0, y: 0 }, Direction::Right)),
+            CrosswordWord::from_placed_word(PlacedWord::new( tiles("local"), Position { x: 1, y: 0 }, Direction::Down)),
+            CrosswordWord::from_placed_word(PlacedWord::new( tiles("at"), Position { x: 3, y: 3 }, Direction::Right)),
+            CrosswordWord::from_placed_word(PlacedWord::new( tiles("table"), Position { x: 5, y: 3 }, Direction::Right)),
+            CrosswordWord::from_placed_word(PlacedWord::new( tiles("sezam"), Position { x: 10, y: -2 }, Direction::Down)),
+            CrosswordWord::from_placed_word(PlacedWord::new( tiles("sezon"), Position { x: 12, y: -2 }, Direction::Down)),
+            CrosswordWord::from_placed_word(PlacedWord::new( tiles("abcde"), Position { x: 9, y: 0 }, Direction::Right)),
+        ]
+    });
+
+    let cw_link = use_state(|| WeakComponentLink::<PlaygroundComponent<Tile, Vec<Tile>>>::default());
+
+    let title = use_state(String::new);
+    let author = use_state(String::new);
+    let clues = use_state(BTreeMap::<WordId, String>::new);
+    let groups = use_state(Vec::<WordGroup>::new);
+    let tags = use_state(Vec::<String>::new);
+    let difficulty = use_state(|| None::<Difficulty>);
+    let duplicate_settings = use_state(DuplicateSettings::default);
+    let group_name = use_state(String::new);
+    let crossword_id = use_state(|| None::<CrosswordId>);
+    let save_status = use_state(String::new);
+    // what the server found wrong in the last saved draft
+    let validation_errors = use_state(Vec::<ValidationError>::new);
+    let published_revision = use_state(|| None::<u32>);
+    let versions_refresh = use_state(|| 0u32);
+    let navigator = use_navigator();
+
+    let document = CrosswordDocument
+    {
+        title: (*title).clone(),
+        author: (*author).clone(),
+        alphabet: (*alphabet).clone(),
+        // clues of the removed words aren't kept
+        clues: clues.iter().filter(|(id, _)| cw.iter().any(|w| w.id == **id)).map(|(id, clue)| (*id, clue.clone())).collect(),
+        // the same for the removed words of the groups
+        groups: groups.iter().cloned().map(|mut g| 
+        {
+            g.words.retain(|id| cw.iter().any(|w| w.id == *id));
+            g
+        }).filter(|g| !g.words.is_empty()).collect(),
+        words: (*cw).clone(),
+        tags: (*tags).clone(),
+        difficulty: *difficulty,
+        // estimated again by the server on every save
+        difficulty_score: None,
+        duplicate_settings: *duplicate_settings,
+    };
+
+    let on_save = 
+    {
+        let document = document.clone();
+        let crossword_id = crossword_id.clone();
+        let save_status = save_status.clone();
+        let validation_errors = validation_errors.clone();
+        Callback::from(move |_: MouseEvent| 
+        {
+            let document = document.clone();
+            let crossword_id = crossword_id.clone();
+            let save_status = save_status.clone();
+            let validation_errors = validation_errors.clone();
+            spawn_local(async move 
+            {
+                match save_document(*crossword_id, &document).await
+                {
+                    Ok(SaveResponse { id, errors }) => 
+                    { 
+                        crossword_id.set(Some(id)); 
+                        validation_errors.set(errors);
+                        save_status.set("Saved".to_owned()); 
+                    }
+                    Err(error) => save_status.set(format!("Couldn't save: {}", error)),
+                }
+            });
+        })
+    };
+
+    // once the crossword has an id, the draft is saved a second after the last change
+    {
+        let crossword_id = *crossword_id;
+        let save_status = save_status.clone();
+        let validation_errors = validation_errors.clone();
+        use_effect_with(document.clone(), move |document|
+        {
+            let document = document.clone();
+            let timeout = crossword_id.map(|id| Timeout::new(1000, move || spawn_local(async move 
+            {
+                match save_document(Some(id), &document).await
+                {
+                    Ok(SaveResponse { errors, .. }) => validation_errors.set(errors),
+                    Err(error) => save_status.set(format!("Couldn't autosave: {}", error)),
+                }
+            })));
+            move || drop(timeout)
+        });
+    }
+
+    let add_version = |publish: bool|
+    {
+        let document = document.clone();
+        let crossword_id = crossword_id.clone();
+        let save_status = save_status.clone();
+        let published_revision = published_revision.clone();
+        let versions_refresh = versions_refresh.clone();
+        let validation_errors = validation_errors.clone();
+        Callback::from(move |_: MouseEvent| 
+        {
+            let document = document.clone();
+            let crossword_id = crossword_id.clone();
+            let save_status = save_status.clone();
+            let published_revision = published_revision.clone();
+            let versions_refresh = versions_refresh.clone();
+            let validation_errors = validation_errors.clone();
+            spawn_local(async move 
+            {
+                let version = async
+                {
+                    let SaveResponse { id, errors } = save_document(*crossword_id, &document).await?;
+                    crossword_id.set(Some(id));
+                    let invalid = publish && !errors.is_empty();
+                    validation_errors.set(errors);
+                    if invalid { return Ok(None); }
+                    post_json::<_, VersionInfo>(&format!("/api/crosswords/{}/versions", id), &NewVersionRequest { publish }).await.map(Some)
+                };
+                match version.await
+                {
+                    Ok(None) => save_status.set("Fix the problems below before publishing".to_owned()),
+                    Ok(Some(version)) => 
+                    {
+                        if version.published { published_revision.set(Some(version.number)); }
+                        versions_refresh.set(*versions_refresh + 1);
+                        save_status.set(format!("{} version {}", if version.published { "Published" } else { "Saved" }, version.number));
+                    }
+                    Err(error) => save_status.set(format!("Couldn't save the version: {}", error)),
+                }
+            });
+        })
+    };
+
+    // rooms always solve a published version, never the draft
+    let start_room = |mode: RoomMode|
+    {
+        let crossword_id = *crossword_id;
+        let published_revision = *published_revision;
+        let save_status = save_status.clone();
+        let navigator = navigator.clone();
+        Callback::from(move |_: MouseEvent| 
+        {
+            let (Some(id), Some(revision)) = (crossword_id, published_revision) else 
+            { 
+                save_status.set("Publish the crossword first".to_owned()); 
+                return; 
+            };
+            let save_status = save_status.clone();
+            let navigator = navigator.clone();
+            spawn_local(async move 
+            {
+                match post_json::<_, CreateRoomResponse>("/api/rooms", &CreateRoomRequest { crossword_id: id, revision, mode }).await
+                {
+                    Ok(CreateRoomResponse { code }) => if let Some(navigator) = navigator { navigator.push(&Route::Room { code }) },
+                    Err(error) => save_status.set(format!("Couldn't start the room: {}", error)),
+                }
+            });
+        })
+    };
+
+    let on_restore = 
+    {
+        let title = title.clone();
+        let author = author.clone();
+        let alphabet = alphabet.clone();
+        let cw = cw.clone();
+        let clues = clues.clone();
+        let groups = groups.clone();
+        let tags = tags.clone();
+        let difficulty = difficulty.clone();
+        let duplicate_settings = duplicate_settings.clone();
+        Callback::from(move |document: CrosswordDocument| 
+        {
+            groups.set(document.groups);
+            tags.set(document.tags);
+            difficulty.set(document.difficulty);
+            duplicate_settings.set(document.duplicate_settings);
+            title.set(document.title);
+            author.set(document.author);
+            alphabet.set(document.alphabet);
+            cw.set(document.words);
+            clues.set(document.clues);
+        })
+    };
+
+    let show_import = use_state(|| false);
+
+    let on_copy_as_text = 
+    {
+        let document = document.clone();
+        let save_status = save_status.clone();
+        Callback::from(move |_: MouseEvent| 
+        {
+            let copied = copy_text(export_text(&document, &TextExportOptions::default()));
+            save_status.set(if copied { "Copied as text".to_owned() } else { "Couldn't copy".to_owned() });
+        })
+    };
+
+    let on_import = 
+    {
+        let cw = cw.clone();
+        let show_import = show_import.clone();
+        Callback::from(move |words: Vec<CrosswordWord>| 
+        {
+            cw.set(words);
+            show_import.set(false);
+        })
+    };
+
+    let on_groups_change = 
+    {
+        let groups = groups.clone();
+        Callback::from(move |new_groups: Vec<WordGroup>| groups.set(new_groups))
+    };
+
+    let on_words_change = 
+    {
+        let cw = cw.clone();
+        Callback::from(move |words: Vec<CrosswordWord>| cw.set(words))
+    };
+
+    let on_alphabet_change = 
+    {
+        let alphabet = alphabet.clone();
+        Callback::from(move |event: Event| 
+        {
+            let name = event.target_dyn_into::<HtmlSelectElement>().unwrap().value();
+            if let Some(new_alphabet) = Alphabet::predefined().into_iter().find(|a| a.name == name)
+            {
+                alphabet.set(new_alphabet);
+            }
+        })
+    };
+
+    let playground_mode = use_state(|| PlaygroundMode::Edit);
+    let fill_status = use_state(String::new);
+    let symmetry = use_state(|| None::<Symmetry>);
+    // doubled like in the playground
+    let symmetry_center = use_state(|| (0i16, 0i16));
+
+    let grid_center = 
+    {
+        let cells = cell_words(&cw);
+        cells_center_x2(cells.keys())
+    };
+
+    let on_symmetry_change = 
+    {
+        let symmetry = symmetry.clone();
+        let symmetry_center = symmetry_center.clone();
+        Callback::from(move |event: Event| 
+        {
+            let new_symmetry = match event.target_dyn_into::<HtmlSelectElement>().unwrap().value().as_str()
+            {
+                "rotational" => Some(Symmetry::Rotational),
+                "horizontal" => Some(Symmetry::Horizontal),
+                "vertical" => Some(Symmetry::Vertical),
+                _ => None,
+            };
+            // turning the symmetry on starts from the center of the grid
+            if symmetry.is_none() { symmetry_center.set(grid_center); }
+            symmetry.set(new_symmetry);
+        })
+    };
+
+    let on_symmetry_center_change = |axis: usize|
+    {
+        let symmetry_center = symmetry_center.clone();
+        Callback::from(move |event: Event| 
+        {
+            let Ok(value) = event.target_dyn_into::<HtmlInputElement>().unwrap().value().parse::<f32>() else { return; };
+            let mut center = *symmetry_center;
+            let doubled = (value * 2.0).round() as i16;
+            if axis == 0 { center.0 = doubled; } else { center.1 = doubled; }
+            symmetry_center.set(center);
+        })
+    };
+
+    let on_toggle_slot_drawing = 
+    {
+        let playground_mode = playground_mode.clone();
+        Callback::from(move |_: MouseEvent| playground_mode.set(if *playground_mode == PlaygroundMode::DrawSlots { PlaygroundMode::Edit } else { PlaygroundMode::DrawSlots }))
+    };
+
+    let on_fill_slots = 
+    {
+        let cw_link = cw_link.clone();
+        let fill_status = fill_status.clone();
+        let alphabet = alphabet.clone();
+        Callback::from(move |_: MouseEvent| 
+        {
+            let cw_link = (*cw_link).clone();
+            let fill_status = fill_status.clone();
+            let alphabet = (*alphabet).clone();
+            fill_status.set("Filling...".to_owned());
+            spawn_local(async move 
+            {
+                match load_dictionary(alphabet.locale).await
+                {
+                    Ok(Some(dictionary)) => if let Some(link) = cw_link.borrow().as_ref()
+                    {
+                        link.send_message(PlaygroundComponentMessage::FillSlots(dictionary.into_iter().filter_map(|w| alphabet.segment(&w).ok()).collect()));
+                    },
+                    Ok(None) => fill_status.set(format!("There is no dictionary for the {} alphabet yet, the slots can't be filled", alphabet.name)),
+                    Err(error) => fill_status.set(format!("Couldn't load the dictionary: {}", error)),
+                }
+            });
+        })
+    };
 
-    let cw_link = use_state(|| WeakComponentLink::<PlaygroundComponent<char, Vec<char>>>::default());
+    let on_clear_slots = 
+    {
+        let cw_link = cw_link.clone();
+        Callback::from(move |_: MouseEvent| if let Some(link) = cw_link.borrow().as_ref() { link.send_message(PlaygroundComponentMessage::ClearSlots) })
+    };
+
+    let send_to_playground = |message: PlaygroundComponentMessage<Tile, Vec<Tile>>|
+    {
+        let cw_link = cw_link.clone();
+        Callback::from(move |_: MouseEvent| if let Some(link) = cw_link.borrow().as_ref() { link.send_message(message.clone()) })
+    };
+
+    let on_duplicate_setting_change = |set: fn(&mut DuplicateSettings, bool)|
+    {
+        let duplicate_settings = duplicate_settings.clone();
+        Callback::from(move |event: Event| 
+        {
+            let mut settings = *duplicate_settings;
+            set(&mut settings, event.target_dyn_into::<HtmlInputElement>().unwrap().checked());
+            duplicate_settings.set(settings);
+        })
+    };
+
+    let on_root_length_change = 
+    {
+        let duplicate_settings = duplicate_settings.clone();
+        Callback::from(move |event: Event| 
+        {
+            if let Ok(root_length) = event.target_dyn_into::<HtmlInputElement>().unwrap().value().trim().parse::<usize>()
+            {
+                duplicate_settings.set(DuplicateSettings { root_length: root_length.max(1), ..*duplicate_settings });
+            }
+        })
+    };
+
+    let search_query = use_state(String::new);
+    let search_mode = use_state(SearchMode::default);
+    let search_status = use_state(String::new);
+
+    let find = 
+    {
+        let cw_link = (*cw_link).clone();
+        move |query: String, mode: SearchMode| if let Some(link) = cw_link.borrow().as_ref() { link.send_message(PlaygroundComponentMessage::Find(query, mode)) }
+    };
+
+    let on_search_input = 
+    {
+        let search_query = search_query.clone();
+        let search_mode = search_mode.clone();
+        let find = find.clone();
+        Callback::from(move |event: InputEvent| 
+        {
+            let query = event.target_unchecked_into::<HtmlInputElement>().value();
+            search_query.set(query.clone());
+            find(query, *search_mode);
+        })
+    };
+
+    let on_search_mode_change = 
+    {
+        let search_query = search_query.clone();
+        let search_mode = search_mode.clone();
+        let find = find.clone();
+        Callback::from(move |event: Event| 
+        {
+            let mode = match event.target_dyn_into::<HtmlSelectElement>().unwrap().value().as_str()
+            {
+                "regex" => SearchMode::Regex,
+                "wildcard" => SearchMode::Wildcard,
+                _ => SearchMode::Substring,
+            };
+            search_mode.set(mode);
+            find((*search_query).clone(), mode);
+        })
+    };
+
+    let on_find = 
+    {
+        let search_status = search_status.clone();
+        Callback::from(move |result: Result<(usize, usize), SearchError>| search_status.set(match result
+        {
+            Ok((_, 0)) => "No matches".to_owned(),
+            Ok((current, count)) => format!("{} of {}", current, count),
+            Err(error) => error.to_string(),
+        }))
+    };
+
+    let translate_offset = use_state(|| (0i16, 0i16));
+    let transform_status = use_state(String::new);
+
+    let on_translate_offset_change = |axis: usize|
+    {
+        let translate_offset = translate_offset.clone();
+        Callback::from(move |event: Event| 
+        {
+            let Ok(value) = event.target_dyn_into::<HtmlInputElement>().unwrap().value().parse::<i16>() else { return; };
+            let mut offset = *translate_offset;
+            if axis == 0 { offset.0 = value; } else { offset.1 = value; }
+            translate_offset.set(offset);
+        })
+    };
+
+    let on_transform = 
+    {
+        let transform_status = transform_status.clone();
+        Callback::from(move |result: Result<(), TransformError>| transform_status.set(match result
+        {
+            Ok(()) => String::new(),
+            Err(error) => format!("Can't transform: {}", error),
+        }))
+    };
+
+    let on_fill = 
+    {
+        let fill_status = fill_status.clone();
+        Callback::from(move |result: Result<(), FillError>| fill_status.set(match result
+        {
+            Ok(()) => "All slots are filled".to_owned(),
+            Err(FillError::NoCandidates(i)) => format!("No dictionary word fits slot {}", i + 1),
+            Err(FillError::Unsatisfiable) => "The slots can't be filled with this dictionary".to_owned(),
+        }))
+    };
 
 
     let w = Word::new("Helloworld".chars().collect(), None);
@@ -39,19 +529,29 @@ fn App() -> Html
 
     let placed_words_html = (0..(*cw).len()).map(|i|
     html! {
-        <form class={classes!(css!( display: flex; flex-wrap: wrap; ))}>
-            <input type="text" value={(*cw)[i].value.iter().cloned().collect::<String>()}
+        <form key={(*cw)[i].id.to_string()} class={classes!(css!( display: flex; flex-wrap: wrap; ))}>
+            <input type="text" disabled={(*cw)[i].locked} value={tiles_to_string(&(*cw)[i].value)}
             onchange={
                 let cw = cw.clone();
+                let alphabet = alphabet.clone();
+                let input_error = input_error.clone();
                 Callback::from(move |event: Event| 
                 {
-                    let mut cw_clone = (*cw).clone();
-                    cw_clone[i].value = event.target_dyn_into::<HtmlInputElement>().unwrap().value().chars().collect();
-                    cw.set(cw_clone);
+                    match alphabet.segment(&event.target_dyn_into::<HtmlInputElement>().unwrap().value())
+                    {
+                        Ok(tiles) =>
+                        {
+                            let mut cw_clone = (*cw).clone();
+                            cw_clone[i].value = tiles;
+                            cw.set(cw_clone);
+                            input_error.set(String::new());
+                        }
+                        Err(error) => input_error.set(error.to_string()),
+                    }
                 })
             }
             />
-            <input type="number" value={format!("{}", (*cw)[i].position.x)}
+            <input type="number" disabled={(*cw)[i].locked} value={format!("{}", (*cw)[i].position.x)}
             onchange={
                 let cw = cw.clone();
                 Callback::from(move |event: Event| 
@@ -61,7 +561,7 @@ fn App() -> Html
                     cw.set(cw_clone);
                 })
             }/>
-            <input type="number" value={format!("{}", (*cw)[i].position.y)}
+            <input type="number" disabled={(*cw)[i].locked} value={format!("{}", (*cw)[i].position.y)}
             onchange={
                 let cw = cw.clone();
                 Callback::from(move |event: Event| 
@@ -71,7 +571,7 @@ fn App() -> Html
                     cw.set(cw_clone);
                 })
             }/>
-            <input type="radio" name="direction" checked={(*cw)[i].direction == Direction::Right}
+            <input type="radio" name="direction" disabled={(*cw)[i].locked} checked={(*cw)[i].direction == Direction::Right}
             onchange={
                 let cw = cw.clone();
                 Callback::from(move |_| 
@@ -81,7 +581,7 @@ fn App() -> Html
                     cw.set(cw_clone);
                 })
             }/>
-            <input type="radio" name="direction" checked={(*cw)[i].direction == Direction::Down}
+            <input type="radio" name="direction" disabled={(*cw)[i].locked} checked={(*cw)[i].direction == Direction::Down}
             onchange={
                 let cw = cw.clone();
                 Callback::from(move |_| 
@@ -91,9 +591,210 @@ fn App() -> Html
                     cw.set(cw_clone);
                 })
             }/>
+            <label>
+                <input type="checkbox" checked={(*cw)[i].locked}
+                onchange={
+                    let cw = cw.clone();
+                    Callback::from(move |event: Event| 
+                    {
+                        let mut cw_clone = (*cw).clone();
+                        cw_clone[i].locked = event.target_dyn_into::<HtmlInputElement>().unwrap().checked();
+                        cw.set(cw_clone);
+                    })
+                }/>
+                {"Locked"}
+            </label>
+            <input type="text" placeholder="Clue" value={clues.get(&(*cw)[i].id).cloned().unwrap_or_default()}
+            onchange={
+                let clues = clues.clone();
+                let id = (*cw)[i].id;
+                Callback::from(move |event: Event| 
+                {
+                    let mut clues_clone = (*clues).clone();
+                    clues_clone.insert(id, event.target_dyn_into::<HtmlInputElement>().unwrap().value());
+                    clues.set(clues_clone);
+                })
+            }/>
         </form>
     });
 
+    html! {
+        <div class={classes!("web-layout", 
+            css!
+            (
+                display: grid;
+                grid-template-areas: ${"\'sidebar playground\'"};
+                grid-template-columns: 400px auto;
+                box-sizing: border-box;
+                height: 600px;   
+            )
+        )}>
+            <div class={classes!("sidebar", css!( grid-area: sidebar; ))}>
+                <p>{"Hello from this side"}</p>
+                <div class={classes!("document-tools", css!( display: flex; gap: 8px; flex-wrap: wrap; margin-bottom: 8px; ))}>
+                    <input type="text" placeholder="Title" value={(*title).clone()}
+                        onchange={ let title = title.clone(); Callback::from(move |event: Event| title.set(event.target_dyn_into::<HtmlInputElement>().unwrap().value())) }/>
+                    <input type="text" placeholder="Author" value={(*author).clone()}
+                        onchange={ let author = author.clone(); Callback::from(move |event: Event| author.set(event.target_dyn_into::<HtmlInputElement>().unwrap().value())) }/>
+                    <input type="text" placeholder="Tags, with commas" value={tags.join(", ")}
+                        onchange={ let tags = tags.clone(); Callback::from(move |event: Event| tags.set(parse_tags(&event.target_dyn_into::<HtmlInputElement>().unwrap().value()))) }/>
+                    <select onchange={ let difficulty = difficulty.clone(); Callback::from(move |event: Event| difficulty.set(Difficulty::from_name(&event.target_dyn_into::<HtmlSelectElement>().unwrap().value()))) }>
+                        <option value="" selected={difficulty.is_none()}>{"No difficulty"}</option>
+                        { for Difficulty::all().into_iter().map(|d| html! { <option value={d.name()} selected={*difficulty == Some(d)}>{ d.name() }</option> }) }
+                    </select>
+                    <button onclick={on_save}>{"Save"}</button>
+                    <button onclick={ let show_import = show_import.clone(); Callback::from(move |_: MouseEvent| show_import.set(true)) }>{"Import text"}</button>
+                    <button onclick={on_copy_as_text}>{"Copy as text"}</button>
+                    <button onclick={add_version(false)}>{"Save version"}</button>
+                    <button onclick={add_version(true)}>{"Publish"}</button>
+                    <button onclick={start_room(RoomMode::Coop)}>{"Start co-op room"}</button>
+                    <button onclick={start_room(RoomMode::Race)}>{"Start race room"}</button>
+                    <span>{ (*save_status).clone() }</span>
+                    <Link<Route> to={Route::Stats}>{"My stats"}</Link<Route>>
+                    <Link<Route> to={Route::Gallery}>{"Gallery"}</Link<Route>>
+                    if let Some(id) = *crossword_id
+                    {
+                        <Link<Route> to={Route::Leaderboard { id: id.to_string() }}>{"Leaderboard"}</Link<Route>>
+                        <Link<Route> to={Route::Print { id: id.to_string() }}>{"Print"}</Link<Route>>
+                    }
+                    if let (Some(id), Some(revision)) = (*crossword_id, *published_revision)
+                    {
+                        <Link<Route> to={Route::Solve { id: id.to_string(), revision }}>{"Solve link"}</Link<Route>>
+                        <Link<Route> to={Route::Embed { id: id.to_string() }}>{"Embed"}</Link<Route>>
+                    }
+                </div>
+                if let Some(id) = *crossword_id
+                {
+                    <VersionHistoryComponent crossword_id={id} refresh={*versions_refresh} {on_restore}/>
+                }
+                if *show_import
+                {
+                    <ImportDialogComponent alphabet={(*alphabet).clone()} {on_import} on_close={ let show_import = show_import.clone(); Callback::from(move |_| show_import.set(false)) }/>
+                }
+                <select onchange={on_alphabet_change}>
+                { 
+                    for Alphabet::predefined().into_iter().map(|a| html! 
+                    {
+                        <option value={a.name.clone()} selected={a.name == alphabet.name}>{ a.name.clone() }</option>
+                    })
+                }
+                </select>
+                <div class={classes!("slot-tools", css!( display: flex; gap: 8px; align-items: center; flex-wrap: wrap; ))}>
+                    <button onclick={on_toggle_slot_drawing}>{ if *playground_mode == PlaygroundMode::DrawSlots { "Stop drawing slots" } else { "Draw slots" } }</button>
+                    <button onclick={on_fill_slots}>{"Fill slots"}</button>
+                    <button onclick={on_clear_slots}>{"Clear slots"}</button>
+                    <span>{ (*fill_status).clone() }</span>
+                </div>
+                <div class={classes!("symmetry-tools", css!( display: flex; gap: 8px; align-items: center; margin-top: 8px; ))}>
+                    <select onchange={on_symmetry_change}>
+                        <option value="none" selected={symmetry.is_none()}>{"No symmetry"}</option>
+                        <option value="rotational" selected={*symmetry == Some(Symmetry::Rotational)}>{"Rotational"}</option>
+                        <option value="horizontal" selected={*symmetry == Some(Symmetry::Horizontal)}>{"Horizontal mirror"}</option>
+                        <option value="vertical" selected={*symmetry == Some(Symmetry::Vertical)}>{"Vertical mirror"}</option>
+                    </select>
+                    if symmetry.is_some()
+                    {
+                        <span>{"Center"}</span>
+                        <input type="number" step="0.5" class={css!( width: 60px; )} value={format!("{}", symmetry_center.0 as f32 / 2.0)} onchange={on_symmetry_center_change(0)}/>
+                        <input type="number" step="0.5" class={css!( width: 60px; )} value={format!("{}", symmetry_center.1 as f32 / 2.0)} onchange={on_symmetry_center_change(1)}/>
+                        <button onclick={ let symmetry_center = symmetry_center.clone(); Callback::from(move |_: MouseEvent| symmetry_center.set(grid_center)) }>{"Grid center"}</button>
+                    }
+                </div>
+                <div class={classes!("transform-tools", css!( display: flex; gap: 8px; align-items: center; flex-wrap: wrap; margin-top: 8px; ))}>
+                    <button onclick={send_to_playground(PlaygroundComponentMessage::PreviewTransform(Some(Transform::Transpose)))}>{"Transpose"}</button>
+                    <button onclick={send_to_playground(PlaygroundComponentMessage::PreviewTransform(Some(Transform::MirrorHorizontal)))}>{"Mirror ↔"}</button>
+                    <button onclick={send_to_playground(PlaygroundComponentMessage::PreviewTransform(Some(Transform::MirrorVertical)))}>{"Mirror ↕"}</button>
+                    <input type="number" class={css!( width: 50px; )} value={format!("{}", translate_offset.0)} onchange={on_translate_offset_change(0)}/>
+                    <input type="number" class={css!( width: 50px; )} value={format!("{}", translate_offset.1)} onchange={on_translate_offset_change(1)}/>
+                    <button onclick={send_to_playground(PlaygroundComponentMessage::PreviewTransform(Some(Transform::Translate(translate_offset.0, translate_offset.1))))}>{"Translate"}</button>
+                    <button onclick={send_to_playground(PlaygroundComponentMessage::ApplyTransform)}>{"Apply"}</button>
+                    <button onclick={send_to_playground(PlaygroundComponentMessage::PreviewTransform(None))}>{"Cancel"}</button>
+                    <button onclick={send_to_playground(PlaygroundComponentMessage::UndoTransform)}>{"Undo transform"}</button>
+                    <span>{ (*transform_status).clone() }</span>
+                </div>
+                <div class={classes!("search-tools", css!( display: flex; gap: 8px; align-items: center; flex-wrap: wrap; margin-top: 8px; ))}>
+                    <input type="search" placeholder="Find words" class={css!( width: 120px; )} value={(*search_query).clone()} oninput={on_search_input}/>
+                    <select onchange={on_search_mode_change}>
+                        <option value="substring" selected={*search_mode == SearchMode::Substring}>{"Contains"}</option>
+                        <option value="regex" selected={*search_mode == SearchMode::Regex}>{"Regex"}</option>
+                        <option value="wildcard" selected={*search_mode == SearchMode::Wildcard}>{"Pattern (? and *)"}</option>
+                    </select>
+                    <button onclick={send_to_playground(PlaygroundComponentMessage::FindPrevious)}>{"Previous"}</button>
+                    <button onclick={send_to_playground(PlaygroundComponentMessage::FindNext)}>{"Next"}</button>
+                    <button onclick={send_to_playground(PlaygroundComponentMessage::SelectFound)}>{"Select all matches"}</button>
+                    if !search_query.is_empty()
+                    {
+                        <span>{ (*search_status).clone() }</span>
+                    }
+                </div>
+                <div class={classes!("duplicate-tools", css!( display: flex; gap: 8px; align-items: center; flex-wrap: wrap; margin-top: 8px; ))}>
+                    <span>{"Flag answers that"}</span>
+                    <label>
+                        <input type="checkbox" checked={duplicate_settings.same_answer} onchange={on_duplicate_setting_change(|s, value| s.same_answer = value)}/>
+                        {"repeat"}
+                    </label>
+                    <label>
+                        <input type="checkbox" checked={duplicate_settings.contained_answer} onchange={on_duplicate_setting_change(|s, value| s.contained_answer = value)}/>
+                        {"contain another"}
+                    </label>
+                    <label>
+                        <input type="checkbox" checked={duplicate_settings.shared_root} onchange={on_duplicate_setting_change(|s, value| s.shared_root = value)}/>
+                        {"share the first"}
+                    </label>
+                    <input type="number" min="1" class={css!( width: 48px; )} value={duplicate_settings.root_length.to_string()} onchange={on_root_length_change}/>
+                    <span>{"letters"}</span>
+                </div>
+                <div class={classes!("lock-tools", css!( display: flex; gap: 8px; align-items: center; flex-wrap: wrap; margin-top: 8px; ))}>
+                    <button onclick={send_to_playground(PlaygroundComponentMessage::LockSelected)}>{"Lock selection"}</button>
+                    <button onclick={send_to_playground(PlaygroundComponentMessage::UnlockAll)}>{"Unlock all"}</button>
+                    <input type="text" placeholder="Group name" class={css!( width: 100px; )} value={(*group_name).clone()}
+                        onchange={ let group_name = group_name.clone(); Callback::from(move |event: Event| group_name.set(event.target_dyn_into::<HtmlInputElement>().unwrap().value())) }/>
+                    <button onclick={send_to_playground(PlaygroundComponentMessage::GroupSelected((*group_name).clone()))}>{"Group selection"}</button>
+                </div>
+                <ul class={classes!("groups", css!( list-style: none; padding: 0; margin: 4px 0; ))}>
+                {
+                    for document.groups.iter().map(|g| html!
+                    {
+                        <li class={css!( display: flex; gap: 6px; align-items: center; )}>
+                            <span>{ format!("{} ({} words)", g.name, g.words.len()) }</span>
+                            if let Some(first) = g.words.first()
+                            {
+                                <button onclick={send_to_playground(PlaygroundComponentMessage::MessageBatch(vec![PlaygroundComponentMessage::DeselectAll, PlaygroundComponentMessage::SelectWord(*first)]))}>{"Select"}</button>
+                            }
+                            <button onclick=
+                            {
+                                let groups = groups.clone();
+                                let name = g.name.clone();
+                                Callback::from(move |_: MouseEvent| groups.set(groups.iter().filter(|g| g.name != name).cloned().collect()))
+                            }>{"Ungroup"}</button>
+                        </li>
+                    })
+                }
+                </ul>
+                <div class={classes!("view-tools", css!( display: flex; gap: 8px; margin-top: 8px; ))}>
+                    <button onclick={send_to_playground(PlaygroundComponentMessage::ZoomToFit)}>{"Zoom to fit"}</button>
+                    <button onclick={send_to_playground(PlaygroundComponentMessage::ZoomToSelection)}>{"Zoom to selection"}</button>
+                </div>
+                <WordComponent word={w} link={(*w_link).clone()}/>
+                <WordComponent word={w2} link={(*w_link2).clone()}/>
+
+                { for placed_words_html }
+                <p>{ (*input_error).clone() }</p>
+                <AnalysisComponent document={document.clone()}/>
+                <ul class={classes!("validation-errors", css!( color: rgb(255, 150, 150); padding-left: 16px; ))}>
+                    { for validation_errors.iter().map(|error| html! { <li>{ error.message(&document) }</li> }) }
+                </ul>
+            </div>
+            <div class={classes!("playground-area", css!( grid-area: playground; ))}>
+                <PlaygroundComponent<Tile, Vec<Tile>> words={(*cw).clone()} word_compatibility_settings={settings} duplicate_settings={*duplicate_settings} locale={alphabet.locale} link={(*cw_link).clone()} mode={*playground_mode} {on_fill} {on_transform} {on_find} {on_words_change} groups={document.groups.clone()} {on_groups_change} symmetry={*symmetry} symmetry_center={*symmetry_center}/>
+            </div>
+        </div>
+    }
+}
+
+#[function_component]
+fn Root() -> Html 
+{
     let style_settings = use_state(|| StyleSettings::new()); 
 
     html! {
@@ -115,32 +816,14 @@ fn App() -> Html
             }
         )}/>
         <ContextProvider<StyleSettings> context={(*style_settings).clone()}>
-            <div class={classes!("web-layout", 
-                css!
-                (
-                    display: grid;
-                    grid-template-areas: ${"\'sidebar playground\'"};
-                    grid-template-columns: 400px auto;
-                    box-sizing: border-box;
-                    height: 600px;   
-                )
-            )}>
-                <div class={classes!("sidebar", css!( grid-area: sidebar; ))}>
-                    <p>{"Hello from this side"}</p>
-                    <WordComponent word={w} link={(*w_link).clone()}/>
-                    <WordComponent word={w2} link={(*w_link2).clone()}/>
-
-                    { for placed_words_html }
-                </div>
-                <div class={classes!("playground-area", css!( grid-area: playground; ))}>
-                    <PlaygroundComponent<char, Vec<char>> words={(*cw).clone()} word_compatibility_settings={settings} link={(*cw_link).clone()}/>
-                </div>
-            </div>
+            <BrowserRouter>
+                <Switch<Route> render={switch}/>
+            </BrowserRouter>
         </ContextProvider<StyleSettings>>
     </>
     }
 }
 
 fn main() {
-    yew::Renderer::<App>::new().render();
-}
\ No newline at end of file
+    yew::Renderer::<Root>::new().render();
+}
@@ -0,0 +1,24 @@
+use gloo_net::http::{Request, RequestBuilder, Response};
+use serde::{de::DeserializeOwned, Serialize};
+
+// the backend answers errors with a plain text message
+async fn check(response: Response) -> Result<Response, gloo_net::Error>
+{
+    if response.ok() { Ok(response) }
+    else { Err(gloo_net::Error::GlooError(response.text().await.unwrap_or_else(|_| response.status_text()))) }
+}
+
+pub async fn get_json<R: DeserializeOwned>(url: &str) -> Result<R, gloo_net::Error>
+{
+    check(Request::get(url).send().await?).await?.json().await
+}
+
+pub async fn send_json<T: Serialize>(request: RequestBuilder, body: &T) -> Result<Response, gloo_net::Error>
+{
+    check(request.json(body)?.send().await?).await
+}
+
+pub async fn post_json<T: Serialize, R: DeserializeOwned>(url: &str, body: &T) -> Result<R, gloo_net::Error>
+{
+    send_json(Request::post(url), body).await?.json().await
+}
@@ -0,0 +1,22 @@
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{ClipboardEvent, Event, HtmlDocument};
+
+// puts the text in the clipboard through a copy event, like the copying of the words in the playground
+pub fn copy_text(text: String) -> bool
+{
+    let document = gloo_utils::document();
+    let on_copy = Closure::<dyn FnMut(Event)>::new(move |event: Event|
+    {
+        if let Some(clipboard_data) = event.dyn_ref::<ClipboardEvent>().and_then(ClipboardEvent::clipboard_data)
+        {
+            let _ = clipboard_data.set_data("text/plain", &text);
+            event.prevent_default();
+        }
+    });
+
+    let _ = document.add_event_listener_with_callback("copy", on_copy.as_ref().unchecked_ref());
+    // the copy event is sent before exec_command returns
+    let copied = document.dyn_ref::<HtmlDocument>().is_some_and(|d| d.exec_command("copy").unwrap_or_default());
+    let _ = document.remove_event_listener_with_callback("copy", on_copy.as_ref().unchecked_ref());
+    copied
+}
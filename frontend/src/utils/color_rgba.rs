@@ -21,6 +21,15 @@ impl ColorRGBA
     {
         ColorRGBA { r: r, g: g, b: b, a: 255}
     }
+
+    // "rrggbb" or "rrggbbaa", with or without the '#'
+    pub fn from_hex(hex: &str) -> Option<ColorRGBA>
+    {
+        let hex = hex.trim_start_matches('#');
+        if !matches!(hex.len(), 6 | 8) || !hex.is_ascii() { return None; }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        Some(ColorRGBA { r: channel(0)?, g: channel(2)?, b: channel(4)?, a: if hex.len() == 8 { channel(6)? } else { 255 } })
+    }
 }
 
 impl fmt::Display for ColorRGBA
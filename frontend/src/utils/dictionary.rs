@@ -0,0 +1,11 @@
+use common::{alphabet::Locale, dictionary::{dictionary_url, parse_dictionary}};
+use gloo_net::http::Request;
+
+// none when there is no dictionary for the locale, like the server's Dictionaries::read
+pub async fn load_dictionary(locale: Locale) -> Result<Option<Vec<String>>, gloo_net::Error>
+{
+    let response = Request::get(&dictionary_url(locale)).send().await?;
+    if response.status() == 404 { return Ok(None); }
+    if !response.ok() { return Err(gloo_net::Error::GlooError(format!("{} {}", response.status(), response.status_text()))); }
+    Ok(Some(parse_dictionary(&response.text().await?)))
+}
@@ -0,0 +1,89 @@
+use common::document::DocumentWord;
+use crossword_generator::{traits::{CrosswordChar, CrosswordString}};
+use gloo_storage::{LocalStorage, Storage};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use web_sys::{js_sys::{Date, Math}, DataTransfer};
+
+// during dragenter and dragover only the type names of the drag data are readable, so the type carries
+// a short token and the words themselves are kept in the local storage, which every tab of the origin can read
+const DRAG_TYPE_PREFIX: &str = "application/x.crossword-drag-";
+const DRAG_STORAGE_PREFIX: &str = "crossword-drag-";
+// the full payload is also attached as drag data, it's readable on drop even if the storage isn't available
+const DRAG_PAYLOAD_TYPE: &str = "application/x.crossword-payload";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DragDataType<CharT, StrT>
+where 
+    CharT: CrosswordChar,
+    StrT: CrosswordString<CharT>,
+{
+    pub dragging_mouse_offset_x: f32,
+    pub dragging_mouse_offset_y: f32,
+    pub data: Vec<DocumentWord<CharT, StrT>>
+}
+
+fn new_drag_token() -> String
+{
+    format!("{:08x}{:08x}", (Math::random() * u32::MAX as f64) as u32, Date::now() as u64 as u32)
+}
+
+fn drag_token(data_transfer: &DataTransfer) -> Option<String>
+{
+    data_transfer.types().iter()
+        .filter_map(|v| v.as_string())
+        .find_map(|v| v.strip_prefix(DRAG_TYPE_PREFIX).filter(|token| !token.is_empty() && token.chars().all(|ch| ch.is_ascii_hexdigit())).map(str::to_owned))
+}
+
+fn remove_stale_drag_data()
+{
+    let storage = LocalStorage::raw();
+    let keys = (0..LocalStorage::length()).filter_map(|i| storage.key(i).ok().flatten()).filter(|key| key.starts_with(DRAG_STORAGE_PREFIX)).collect::<Vec<_>>();
+    for key in keys
+    {
+        LocalStorage::delete(key);
+    }
+}
+
+pub fn get_drag_data<CharT, StrT>(data_transfer: &DataTransfer) -> Option<DragDataType<CharT, StrT>>
+where 
+    CharT: CrosswordChar + DeserializeOwned,
+    StrT: CrosswordString<CharT> + DeserializeOwned,
+{
+    let token = drag_token(data_transfer)?;
+    LocalStorage::get(format!("{}{}", DRAG_STORAGE_PREFIX, token)).ok()
+}
+
+// only works on drop, when the drag data itself is readable
+pub fn get_dropped_drag_data<CharT, StrT>(data_transfer: &DataTransfer) -> Option<DragDataType<CharT, StrT>>
+where 
+    CharT: CrosswordChar + DeserializeOwned,
+    StrT: CrosswordString<CharT> + DeserializeOwned,
+{
+    get_drag_data(data_transfer).or_else(|| serde_json::from_str(&data_transfer.get_data(DRAG_PAYLOAD_TYPE).ok()?).ok())
+}
+
+pub fn set_drag_data<CharT, StrT>(data_transfer: &DataTransfer, drag_data: &DragDataType<CharT, StrT>)
+where 
+    CharT: CrosswordChar + Serialize,
+    StrT: CrosswordString<CharT> + Serialize,
+{
+    // only one drag can happen at a time, whatever is left from the previous ones is garbage
+    remove_stale_drag_data();
+
+    let token = new_drag_token();
+    let payload = serde_json::to_string(drag_data).unwrap();
+    if let Err(error) = LocalStorage::raw().set_item(&format!("{}{}", DRAG_STORAGE_PREFIX, token), &payload)
+    {
+        gloo_console::error!("Couldn't store the drag data", error);
+    }
+    data_transfer.set_data(&format!("{}{}", DRAG_TYPE_PREFIX, token), "").unwrap();
+    data_transfer.set_data(DRAG_PAYLOAD_TYPE, &payload).unwrap();
+}
+
+pub fn clear_drag_data(data_transfer: &DataTransfer)
+{
+    if let Some(token) = drag_token(data_transfer)
+    {
+        LocalStorage::delete(format!("{}{}", DRAG_STORAGE_PREFIX, token));
+    }
+}
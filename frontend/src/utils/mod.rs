@@ -1,3 +1,8 @@
 pub mod weak_component_link;
 pub mod color_rgba;
-pub mod settings;
\ No newline at end of file
+pub mod settings;
+pub mod dictionary;
+pub mod drag_transfer;
+pub mod api;
+pub mod user;
+pub mod clipboard;
\ No newline at end of file
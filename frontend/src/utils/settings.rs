@@ -12,6 +12,8 @@ pub struct Theme
     pub color_error_dark: ColorRGBA,
     pub color_error_normal: ColorRGBA,
     pub color_error_light: ColorRGBA,   
+    pub color_locked: ColorRGBA,
+    pub color_grouped: ColorRGBA,
 }
 
 impl Theme
@@ -26,6 +28,8 @@ impl Theme
             color_error_dark: ColorRGBA::opaque(255, 84, 84),
             color_error_normal: ColorRGBA::opaque(255, 125, 125),
             color_error_light: ColorRGBA::opaque(255, 156, 156),
+            color_locked: ColorRGBA::opaque(110, 130, 160),
+            color_grouped: ColorRGBA::opaque(90, 170, 110),
         }
     }
 }
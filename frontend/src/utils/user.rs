@@ -0,0 +1,15 @@
+use common::stats::UserId;
+use gloo_storage::{LocalStorage, Storage};
+
+const USER_ID_KEY: &str = "crossword-user-id";
+
+// the solve stats are kept per browser, the id is made on the first visit
+pub fn user_id() -> UserId
+{
+    LocalStorage::get::<UserId>(USER_ID_KEY).unwrap_or_else(|_|
+    {
+        let id = UserId::new();
+        let _ = LocalStorage::set(USER_ID_KEY, id);
+        id
+    })
+}